@@ -1,8 +1,10 @@
 use agsol_gold_contract::frontend::*;
 use agsol_gold_contract::pda::*;
+use agsol_gold_contract::solana_program::clock::UnixTimestamp;
 use agsol_gold_contract::solana_program::pubkey::Pubkey;
 use agsol_gold_contract::state::*;
 use agsol_gold_contract::utils::{unpad_id, unpuff_metadata};
+use agsol_gold_contract::BID_LOG_PAGE_LENGTH;
 use agsol_gold_contract::ID as GOLD_ID;
 use agsol_token_metadata::state::Metadata;
 use agsol_token_metadata::ID as META_ID;
@@ -38,15 +40,22 @@ pub async fn get_auctions(
                 if root_state.state.status.is_filtered {
                     None
                 } else {
-                    Some(get_auction_base(&id, &root_state.state))
+                    // The amounts of the auction cannot be converted without
+                    // the decimals of its bid mint
+                    let base = get_bid_mint(&mut client, &root_state.state)
+                        .await
+                        .map(|bid_mint| {
+                            get_auction_base(&id, &root_state.state, bid_mint.as_ref())
+                        });
+                    Some(base)
                 }
             } else {
                 None
             }
         })
-        .collect::<Vec<FrontendAuctionBase>>()
+        .collect::<Vec<Result<FrontendAuctionBase, anyhow::Error>>>()
         .await;
-    Ok(base_vec)
+    base_vec.into_iter().collect()
 }
 
 pub async fn get_auction(
@@ -98,7 +107,8 @@ pub async fn get_auction(
         }
     };
 
-    let base = get_auction_base(auction_id, &root_state);
+    let bid_mint = get_bid_mint(client, &root_state).await?;
+    let base = get_auction_base(auction_id, &root_state, bid_mint.as_ref());
 
     let socials: Vec<SocialsString> = root_state.description.socials.into();
     let config = FrontendAuctionConfigExtra {
@@ -115,18 +125,85 @@ pub async fn get_auction(
             .number_of_cycles
             .unwrap_or_default(),
         start_time: Some(root_state.start_time),
-        min_bid: Some(to_sol(root_state.auction_config.minimum_bid_amount)),
+        min_bid: Some(from_bid_units(
+            root_state.auction_config.minimum_bid_amount,
+            bid_mint.as_ref(),
+        )),
+        bid_mint: bid_mint.clone(),
+        reserve_price: root_state
+            .auction_config
+            .reserve_price
+            .filter(|reserve_price| !reserve_price.is_hidden)
+            .map(|reserve_price| from_bid_units(reserve_price.amount, bid_mint.as_ref())),
+        is_reserve_price_hidden: root_state
+            .auction_config
+            .reserve_price
+            .map(|reserve_price| reserve_price.is_hidden),
+        min_bid_increment: root_state
+            .auction_config
+            .min_bid_increment
+            .map(|bid_increment| from_bid_units(bid_increment.amount, bid_mint.as_ref())),
+        min_bid_increment_bps: root_state
+            .auction_config
+            .min_bid_increment
+            .map(|bid_increment| bid_increment.basis_points),
+        buy_now_price: root_state
+            .auction_config
+            .buy_now_price
+            .map(|buy_now_price| from_bid_units(buy_now_price, bid_mint.as_ref())),
+        dutch_price_curve: match root_state.auction_config.kind {
+            AuctionKind::Dutch(price_curve) => Some(FrontendDutchPriceCurve {
+                start_price: from_bid_units(price_curve.start_price, bid_mint.as_ref()),
+                floor_price: from_bid_units(price_curve.floor_price, bid_mint.as_ref()),
+            }),
+            _ => None,
+        },
+        reveal_period: match root_state.auction_config.kind {
+            AuctionKind::Sealed(sealed_bid_config) => Some(sealed_bid_config.reveal_period),
+            _ => None,
+        },
+        second_price_settlement: Some(root_state.auction_config.second_price_settlement),
+        winners_per_cycle: Some(root_state.auction_config.winners_per_cycle),
+        referral_share_bps: Some(root_state.auction_config.referral_share_bps),
+        beneficiaries: Some(
+            root_state
+                .beneficiaries
+                .contents()
+                .iter()
+                .map(|beneficiary| FrontendBeneficiary {
+                    pubkey: beneficiary.pubkey.to_string(),
+                    share_bps: beneficiary.share_bps,
+                })
+                .collect(),
+        ),
+        all_or_nothing: Some(root_state.auction_config.all_or_nothing),
+        finish_on_goal: Some(root_state.auction_config.finish_on_goal),
+        vesting_schedule: root_state
+            .vesting_schedule
+            .map(FrontendVestingSchedule::from),
     };
 
+    let (protocol_fee_state_pubkey, _) =
+        Pubkey::find_program_address(&protocol_fee_state_seeds(), &GOLD_ID);
+    let protocol_fee_state: ProtocolFeeState = client
+        .get_and_deserialize_account_data(&protocol_fee_state_pubkey)
+        .await
+        .unwrap_or_default();
+
     Ok(FrontendAuction {
         base,
         config,
-        available_treasury_amount: to_sol(root_state.available_funds),
+        available_treasury_amount: from_bid_units(root_state.available_funds, bid_mint.as_ref()),
+        escrowed_treasury_amount: from_bid_units(root_state.escrowed_funds, bid_mint.as_ref()),
         current_cycle: root_state.status.current_auction_cycle,
         is_finished: root_state.status.is_finished,
         is_frozen: root_state.status.is_frozen,
+        is_paused: root_state.status.paused_at.is_some(),
         is_filtered: root_state.status.is_filtered,
         root_state_pubkey: root_state_pubkey.to_string(),
+        pending_owner_pubkey: root_state.pending_owner.map(|owner| owner.to_string()),
+        beneficiary_pubkey: root_state.beneficiary.to_string(),
+        protocol_fee_bps: protocol_fee_state.effective_fee(&root_state),
     })
 }
 
@@ -143,6 +220,17 @@ pub async fn get_auction_cycle_state(
     let cycle_state: AuctionCycleState = client
         .get_and_deserialize_account_data(&cycle_state_pubkey)
         .await?;
+    let root_state: AuctionRootState = client
+        .get_and_deserialize_account_data(root_state_pubkey)
+        .await?;
+    let bid_mint = get_bid_mint(client, &root_state).await?;
+
+    let is_reserve_met = cycle_state
+        .bid_history
+        .get_last_element()
+        .map_or(false, |top_bid| {
+            root_state.auction_config.is_reserve_met(top_bid.bid_amount)
+        });
 
     let bid_history: Vec<BidData> = cycle_state.bid_history.into();
     let bids = bid_history
@@ -150,30 +238,164 @@ pub async fn get_auction_cycle_state(
         .rev()
         .map(|bid| FrontendBid {
             bidder_pubkey: bid.bidder_pubkey.to_string(),
-            amount: to_sol(bid.bid_amount),
+            amount: from_bid_units(bid.bid_amount, bid_mint.as_ref()),
+            memo: bid.memo.map(String::from),
         })
         .collect::<Vec<FrontendBid>>();
     Ok(FrontendCycle {
         bids,
         end_timestamp: cycle_state.end_time,
+        is_reserve_met,
+    })
+}
+
+/// Returns a page of the full bid log of an auction cycle.
+pub async fn get_cycle_bid_log(
+    client: &mut RpcClient,
+    root_state_pubkey: &Pubkey,
+    cycle_num: u64,
+    page: u64,
+) -> Result<FrontendBidLogPage, anyhow::Error> {
+    anyhow::ensure!(cycle_num > 0);
+    let (cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(root_state_pubkey, &cycle_num.to_le_bytes()),
+        &GOLD_ID,
+    );
+    let cycle_state: AuctionCycleState = client
+        .get_and_deserialize_account_data(&cycle_state_pubkey)
+        .await?;
+    let root_state: AuctionRootState = client
+        .get_and_deserialize_account_data(root_state_pubkey)
+        .await?;
+    let bid_mint = get_bid_mint(client, &root_state).await?;
+
+    let page_count =
+        (cycle_state.bid_count + BID_LOG_PAGE_LENGTH as u64 - 1) / BID_LOG_PAGE_LENGTH as u64;
+    anyhow::ensure!(page < page_count.max(1), "bid log has {} pages", page_count);
+
+    let bids = if cycle_state.bid_count == 0 {
+        Vec::new()
+    } else {
+        let (bid_log_page_pubkey, _) = Pubkey::find_program_address(
+            &bid_log_page_seeds(
+                root_state_pubkey,
+                &cycle_num.to_le_bytes(),
+                &page.to_le_bytes(),
+            ),
+            &GOLD_ID,
+        );
+        let bid_log_page: BidLogPage = client
+            .get_and_deserialize_account_data(&bid_log_page_pubkey)
+            .await?;
+        let bids: Vec<BidData> = bid_log_page.bids.into();
+        bids.into_iter()
+            .map(|bid| FrontendBid {
+                bidder_pubkey: bid.bidder_pubkey.to_string(),
+                amount: from_bid_units(bid.bid_amount, bid_mint.as_ref()),
+                memo: bid.memo.map(String::from),
+            })
+            .collect()
+    };
+
+    Ok(FrontendBidLogPage {
+        bids,
+        bid_count: cycle_state.bid_count,
+        page_count,
     })
 }
 
-fn get_auction_base(auction_id: &AuctionId, root_state: &AuctionRootState) -> FrontendAuctionBase {
+/// Returns the on-chain state of an auction cycle, e.g. to get exact bid
+/// amounts.
+pub async fn get_raw_auction_cycle_state(
+    client: &mut RpcClient,
+    auction_id: &AuctionId,
+    cycle_num: u64,
+) -> Result<AuctionCycleState, anyhow::Error> {
+    anyhow::ensure!(cycle_num > 0);
+    let (root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(auction_id), &GOLD_ID);
+    let (cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(&root_state_pubkey, &cycle_num.to_le_bytes()),
+        &GOLD_ID,
+    );
+    let cycle_state = client
+        .get_and_deserialize_account_data(&cycle_state_pubkey)
+        .await?;
+    Ok(cycle_state)
+}
+
+/// Returns the price of a Dutch auction cycle at the given timestamp.
+///
+/// Returns `None` if the auction is not a Dutch auction.
+pub async fn get_current_price(
+    client: &mut RpcClient,
+    root_state_pubkey: &Pubkey,
+    cycle_num: u64,
+    timestamp: UnixTimestamp,
+) -> Result<Option<Scalar>, anyhow::Error> {
+    anyhow::ensure!(cycle_num > 0);
+    let (cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(root_state_pubkey, &cycle_num.to_le_bytes()),
+        &GOLD_ID,
+    );
+    let cycle_state: AuctionCycleState = client
+        .get_and_deserialize_account_data(&cycle_state_pubkey)
+        .await?;
+    let root_state: AuctionRootState = client
+        .get_and_deserialize_account_data(root_state_pubkey)
+        .await?;
+    let bid_mint = get_bid_mint(client, &root_state).await?;
+
+    Ok(root_state
+        .auction_config
+        .current_price(cycle_state.end_time, timestamp)
+        .map(|price| from_bid_units(price, bid_mint.as_ref())))
+}
+
+fn get_auction_base(
+    auction_id: &AuctionId,
+    root_state: &AuctionRootState,
+    bid_mint: Option<&FrontendBidMint>,
+) -> FrontendAuctionBase {
     let base_config = FrontendAuctionBaseConfig {
         id: unpad_id(auction_id),
         name: unpad_id(&root_state.auction_name),
         owner_pubkey: root_state.auction_owner.to_string(),
-        goal_treasury_amount: root_state.description.goal_treasury_amount.map(to_sol),
+        goal_treasury_amount: root_state
+            .description
+            .goal_treasury_amount
+            .map(|goal| from_bid_units(goal, bid_mint)),
     };
 
     FrontendAuctionBase {
         config: base_config,
-        all_time_treasury_amount: to_sol(root_state.all_time_treasury),
+        all_time_treasury_amount: from_bid_units(root_state.all_time_treasury, bid_mint),
         is_verified: root_state.status.is_verified,
     }
 }
 
+async fn get_bid_mint(
+    client: &mut RpcClient,
+    root_state: &AuctionRootState,
+) -> Result<Option<FrontendBidMint>, anyhow::Error> {
+    let bid_mint = match root_state.auction_config.bid_mint {
+        Some(bid_mint) => bid_mint,
+        None => return Ok(None),
+    };
+    let mint_data = client
+        .get_and_deserialize_parsed_account_data::<TokenAccount>(&bid_mint)
+        .await;
+
+    match mint_data {
+        Ok(TokenAccount::Mint(mint)) => Ok(Some(FrontendBidMint {
+            mint: bid_mint.to_string(),
+            decimals: mint.decimals,
+        })),
+        Ok(_) => bail!("not a mint account"),
+        Err(e) => bail!("{}", e),
+    }
+}
+
 async fn get_root_state(
     client: &mut RpcClient,
     id: &AuctionId,
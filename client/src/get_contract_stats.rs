@@ -0,0 +1,17 @@
+use agsol_gold_contract::frontend::FrontendContractStats;
+use agsol_gold_contract::pda::contract_bank_seeds;
+use agsol_gold_contract::solana_program::pubkey::Pubkey;
+use agsol_gold_contract::state::ContractBankState;
+use agsol_gold_contract::ID as GOLD_ID;
+use agsol_wasm_client::RpcClient;
+
+pub async fn get_contract_stats(
+    client: &mut RpcClient,
+) -> Result<FrontendContractStats, anyhow::Error> {
+    let (contract_bank_pubkey, _) = Pubkey::find_program_address(&contract_bank_seeds(), &GOLD_ID);
+    let contract_bank_state: ContractBankState = client
+        .get_and_deserialize_account_data(&contract_bank_pubkey)
+        .await?;
+
+    Ok(FrontendContractStats::from(contract_bank_state.stats))
+}
@@ -4,6 +4,7 @@
 
 mod auction_exists;
 mod get_auction;
+mod get_contract_stats;
 
 use agsol_gold_contract::frontend::*;
 use agsol_gold_contract::instruction::factory::*;
@@ -61,6 +62,46 @@ pub async fn get_auction_cycle_wasm(
     JsValue::from_serde(&auction_cycle).map_err(|e| JsValue::from(e.to_string()))
 }
 
+#[wasm_bindgen(js_name = "getCurrentPriceWasm")]
+pub async fn get_current_price_wasm(
+    root_state_pubkey: Pubkey,
+    cycle_num: u64,
+    timestamp: i64,
+) -> Result<JsValue, JsValue> {
+    let mut client = RpcClient::new_with_config(NET, RPC_CONFIG);
+    let current_price =
+        get_auction::get_current_price(&mut client, &root_state_pubkey, cycle_num, timestamp)
+            .await
+            .map_err(|e| JsValue::from(e.to_string()))?;
+
+    JsValue::from_serde(&current_price).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "getCycleBidLogWasm")]
+pub async fn get_cycle_bid_log_wasm(
+    root_state_pubkey: Pubkey,
+    cycle_num: u64,
+    page: u64,
+) -> Result<JsValue, JsValue> {
+    let mut client = RpcClient::new_with_config(NET, RPC_CONFIG);
+    let bid_log_page =
+        get_auction::get_cycle_bid_log(&mut client, &root_state_pubkey, cycle_num, page)
+            .await
+            .map_err(|e| JsValue::from(e.to_string()))?;
+
+    JsValue::from_serde(&bid_log_page).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "getContractStatsWasm")]
+pub async fn get_contract_stats_wasm() -> Result<JsValue, JsValue> {
+    let mut client = RpcClient::new_with_config(NET, RPC_CONFIG);
+    let contract_stats = get_contract_stats::get_contract_stats(&mut client)
+        .await
+        .map_err(|e| JsValue::from(e.to_string()))?;
+
+    JsValue::from_serde(&contract_stats).map_err(|e| JsValue::from(e.to_string()))
+}
+
 #[wasm_bindgen(js_name = "auctionExistsWasm")]
 pub async fn auction_exists_wasm(auction_id: String) -> Result<bool, JsValue> {
     let mut client = RpcClient::new_with_config(NET, RPC_CONFIG);
@@ -97,11 +138,102 @@ pub async fn place_bid_wasm(args: JsValue) -> Result<JsValue, JsValue> {
     let frontend_args: FrontendPlaceBidArgs = args
         .into_serde()
         .map_err(|e| JsValue::from(e.to_string()))?;
-    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let mut args: PlaceBidArgs = frontend_args.try_into().map_err(JsValue::from)?;
+
+    // Guard the bid against the top bid the bidder has seen
+    let mut client = RpcClient::new_with_config(NET, RPC_CONFIG);
+    let cycle_state =
+        get_auction::get_raw_auction_cycle_state(&mut client, &args.auction_id, args.cycle_number)
+            .await
+            .map_err(|e| JsValue::from(e.to_string()))?;
+    args.expected_top_bid = Some(
+        cycle_state
+            .bid_history
+            .get_last_element()
+            .map_or(0, |top_bid| top_bid.bid_amount),
+    );
+    args.bid_log_page = cycle_state.bid_log_page();
+
     let instruction = place_bid(&args);
     JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
 }
 
+#[wasm_bindgen(js_name = "commitBidWasm")]
+pub async fn commit_bid_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendCommitBidArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = commit_bid(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "revealBidWasm")]
+pub async fn reveal_bid_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendRevealBidArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let mut args: RevealBidArgs = frontend_args.try_into().map_err(JsValue::from)?;
+
+    let mut client = RpcClient::new_with_config(NET, RPC_CONFIG);
+    let cycle_state =
+        get_auction::get_raw_auction_cycle_state(&mut client, &args.auction_id, args.cycle_number)
+            .await
+            .map_err(|e| JsValue::from(e.to_string()))?;
+    args.bid_log_page = cycle_state.bid_log_page();
+
+    let instruction = reveal_bid(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "placeProxyBidWasm")]
+pub async fn place_proxy_bid_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendPlaceProxyBidArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let mut args: PlaceProxyBidArgs = frontend_args.try_into().map_err(JsValue::from)?;
+
+    let mut client = RpcClient::new_with_config(NET, RPC_CONFIG);
+    let cycle_state =
+        get_auction::get_raw_auction_cycle_state(&mut client, &args.auction_id, args.cycle_number)
+            .await
+            .map_err(|e| JsValue::from(e.to_string()))?;
+    args.bid_log_page = cycle_state.bid_log_page();
+
+    let instruction = place_proxy_bid(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "withdrawRefundWasm")]
+pub async fn withdraw_refund_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendWithdrawRefundArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = withdraw_refund(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "refundFailedGoalWasm")]
+pub async fn refund_failed_goal_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendRefundFailedGoalArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = refund_failed_goal(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "claimReferralRewardsWasm")]
+pub async fn claim_referral_rewards_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendClaimReferralRewardsArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = claim_referral_rewards(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
 #[wasm_bindgen(js_name = "claimRewardsWasm")]
 pub async fn claim_rewards_wasm(args: JsValue) -> Result<JsValue, JsValue> {
     let frontend_args: FrontendClaimRewardsArgs = args
@@ -122,6 +254,46 @@ pub async fn modify_auction_wasm(args: JsValue) -> Result<JsValue, JsValue> {
     JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
 }
 
+#[wasm_bindgen(js_name = "pauseAuctionWasm")]
+pub async fn pause_auction_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendPauseAuctionArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = pause_auction(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "resumeAuctionWasm")]
+pub async fn resume_auction_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendResumeAuctionArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = resume_auction(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "proposeOwnerTransferWasm")]
+pub async fn propose_owner_transfer_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendProposeOwnerTransferArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = propose_owner_transfer(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "acceptOwnerTransferWasm")]
+pub async fn accept_owner_transfer_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendAcceptOwnerTransferArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = accept_owner_transfer(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
+
 #[wasm_bindgen(js_name = "deleteAuctionWasm")]
 pub async fn delete_auction_wasm(args: JsValue) -> Result<JsValue, JsValue> {
     let frontend_args: FrontendDeleteAuctionArgs = args
@@ -131,3 +303,13 @@ pub async fn delete_auction_wasm(args: JsValue) -> Result<JsValue, JsValue> {
     let instruction = delete_all(args);
     JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
 }
+
+#[wasm_bindgen(js_name = "migrateAuctionWasm")]
+pub async fn migrate_auction_wasm(args: JsValue) -> Result<JsValue, JsValue> {
+    let frontend_args: FrontendMigrateAuctionArgs = args
+        .into_serde()
+        .map_err(|e| JsValue::from(e.to_string()))?;
+    let args = frontend_args.try_into().map_err(JsValue::from)?;
+    let instruction = migrate_auction(&args);
+    JsValue::from_serde(&instruction).map_err(|e| JsValue::from(e.to_string()))
+}
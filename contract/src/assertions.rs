@@ -12,8 +12,8 @@ use spl_token::ID as TOKEN_ID;
 
 use agsol_token_metadata::ID as META_ID;
 
-use crate::state::{AuctionCycleState, AuctionRootState};
-use crate::AuctionContractError;
+use crate::state::{AuctionCycleState, AuctionKind, AuctionRootState, Beneficiary, BidIncrement};
+use crate::{AuctionContractError, MAX_BASIS_POINTS};
 
 // ************************ Accounts assertions ************************ //
 
@@ -146,6 +146,8 @@ pub fn checked_debit_account(
 pub enum AuctionInteraction {
     Bid,
     BidInactive,
+    CommitBid,
+    RevealBid,
     CloseCycle,
 }
 
@@ -161,19 +163,55 @@ pub fn check_status(
     if root_state.status.is_finished {
         return Err(AuctionContractError::AuctionEnded);
     }
+    if root_state.status.paused_at.is_some() {
+        return Err(AuctionContractError::AuctionPaused);
+    }
+    // sealed-bid auctions only accept committed bids
+    let is_sealed = matches!(root_state.auction_config.kind, AuctionKind::Sealed(_));
+    let is_sealed_interaction = matches!(
+        interaction_type,
+        AuctionInteraction::CommitBid | AuctionInteraction::RevealBid
+    );
+    let is_open_bid = matches!(
+        interaction_type,
+        AuctionInteraction::Bid | AuctionInteraction::BidInactive
+    );
+    if (is_sealed && is_open_bid) || (!is_sealed && is_sealed_interaction) {
+        return Err(AuctionContractError::UnsupportedAuctionKind);
+    }
     match interaction_type {
-        AuctionInteraction::Bid => {
+        AuctionInteraction::Bid | AuctionInteraction::CommitBid => {
             if current_timestamp >= cycle_state.end_time
                 || current_timestamp < root_state.start_time
             {
                 return Err(AuctionContractError::AuctionCycleEnded);
             }
+            // the first accepted bid of a Dutch auction cycle settles it
+            if let AuctionKind::Dutch(_) = root_state.auction_config.kind {
+                if !cycle_state.bid_history.is_empty() {
+                    return Err(AuctionContractError::AuctionCycleEnded);
+                }
+            }
         }
         AuctionInteraction::BidInactive => return Ok(()),
-        AuctionInteraction::CloseCycle => {
+        AuctionInteraction::RevealBid => {
             if current_timestamp < cycle_state.end_time {
                 return Err(AuctionContractError::AuctionIsInProgress);
             }
+            let cycle_close_time = root_state
+                .auction_config
+                .cycle_close_time(cycle_state.end_time);
+            if current_timestamp >= cycle_close_time {
+                return Err(AuctionContractError::AuctionCycleEnded);
+            }
+        }
+        AuctionInteraction::CloseCycle => {
+            let cycle_close_time = root_state
+                .auction_config
+                .cycle_close_time(cycle_state.end_time);
+            if current_timestamp < cycle_close_time {
+                return Err(AuctionContractError::AuctionIsInProgress);
+            }
         }
     }
 
@@ -183,20 +221,80 @@ pub fn check_status(
 pub fn check_bid_amount(
     root_state: &AuctionRootState,
     cycle_state: &AuctionCycleState,
+    bidder_pubkey: &Pubkey,
     bid_amount: u64,
+    current_timestamp: UnixTimestamp,
 ) -> Result<(), AuctionContractError> {
     if bid_amount < root_state.auction_config.minimum_bid_amount {
         return Err(AuctionContractError::InvalidBidAmount);
     }
-    if let Some(most_recent_bid) = cycle_state.bid_history.get_last_element() {
-        if bid_amount <= most_recent_bid.bid_amount {
+    if let Some(current_price) = root_state
+        .auction_config
+        .current_price(cycle_state.end_time, current_timestamp)
+    {
+        if bid_amount < current_price {
+            return Err(AuctionContractError::InvalidBidAmount);
+        }
+        return Ok(());
+    }
+    let auction_config = &root_state.auction_config;
+    if let Some(bid_to_outbid) =
+        cycle_state.bid_to_outbid(bidder_pubkey, auction_config.winners_per_cycle)
+    {
+        // bids reaching the buy-now price are exempt from the minimum increment
+        let next_valid_bid = auction_config.next_valid_bid(bid_to_outbid.bid_amount)?;
+        if bid_amount < next_valid_bid && !auction_config.is_buy_now(bid_amount) {
             return Err(AuctionContractError::InvalidBidAmount);
         }
     }
     Ok(())
 }
 
+pub fn check_bid_increment(bid_increment: &BidIncrement) -> Result<(), AuctionContractError> {
+    if bid_increment.basis_points > MAX_BASIS_POINTS {
+        return Err(AuctionContractError::InvalidBidIncrement);
+    }
+    Ok(())
+}
+
+/// Checks that the beneficiaries are distinct from each other and from the
+/// accounts receiving the remainder of the funds (i.e. the auction owner and
+/// the auction beneficiary), and that their shares are positive and sum up to
+/// at most 100%.
+pub fn check_beneficiaries(
+    beneficiaries: &[Beneficiary],
+    auction_owner: &Pubkey,
+    auction_beneficiary: &Pubkey,
+) -> Result<(), AuctionContractError> {
+    let mut total_share_bps = 0_u16;
+    for (i, beneficiary) in beneficiaries.iter().enumerate() {
+        if beneficiary.share_bps == 0
+            || &beneficiary.pubkey == auction_owner
+            || &beneficiary.pubkey == auction_beneficiary
+            || beneficiaries[..i]
+                .iter()
+                .any(|other| other.pubkey == beneficiary.pubkey)
+        {
+            return Err(AuctionContractError::InvalidBeneficiaries);
+        }
+        total_share_bps = total_share_bps
+            .checked_add(beneficiary.share_bps)
+            .filter(|total| *total <= MAX_BASIS_POINTS)
+            .ok_or(AuctionContractError::InvalidBeneficiaries)?;
+    }
+    Ok(())
+}
+
+/// Returns `true` if the current cycle is the last one, either because it is
+/// the last of a finite auction or because the funding goal is reached by an
+/// auction finishing on its goal.
 pub fn is_last_auction_cycle(root_state: &AuctionRootState) -> bool {
+    if root_state.auction_config.finish_on_goal
+        && root_state.description.goal_treasury_amount.is_some()
+        && root_state.is_goal_reached()
+    {
+        return true;
+    }
     if let Some(number_of_cycles) = root_state.auction_config.number_of_cycles {
         return root_state.status.current_auction_cycle >= number_of_cycles;
     }
@@ -42,6 +42,35 @@ pub enum AuctionContractError {
     InvalidProtocolFee = 533,         // 215
     RewardAlreadyClaimed = 534,       // 216
     UnclaimedRewards = 535,           // 217
+    BidMintMismatch = 536,            // 218
+    InvalidReservePrice = 537,        // 219
+    InvalidBidIncrement = 538,        // 21a
+    InvalidBuyNowPrice = 539,         // 21b
+    InvalidPriceCurve = 540,          // 21c
+    UnsupportedAuctionKind = 541,     // 21d
+    InvalidRevealPeriod = 542,        // 21e
+    BidCommitmentMismatch = 543,      // 21f
+    InvalidWinnersPerCycle = 544,     // 220
+    InvalidRewardRank = 545,          // 221
+    ProxyBidActive = 546,             // 222
+    NothingToWithdraw = 547,          // 223
+    StaleBid = 548,                   // 224
+    InvalidReferralShare = 549,       // 225
+    InvalidReferrer = 550,            // 226
+    AuctionPaused = 551,              // 227
+    AuctionNotPaused = 552,           // 228
+    PendingOwnerMismatch = 553,       // 229
+    InvalidBeneficiaries = 554,       // 22a
+    BeneficiaryMismatch = 555,        // 22b
+    AuctionBeneficiaryMismatch = 556, // 22c
+    InvalidFundingGoal = 557,         // 22d
+    FundingGoalNotFailed = 558,       // 22e
+    InvalidVestingSchedule = 559,     // 22f
+    PendingAdminMismatch = 560,       // 230
+    AuctionStateNotMigrated = 561,    // 231
+    UnwithdrawnDeposits = 562,        // 232
+    UnrefundedEscrow = 563,           // 233
+    UnvestedFunds = 564,              // 234
 }
 
 impl From<AuctionContractError> for ProgramError {
@@ -3,6 +3,7 @@ pub use types::*;
 
 pub type Scalar = f64;
 pub const SELLER_FEE_BASIS_POINTS: u16 = 50;
+pub const LAMPORT_DECIMALS: u8 = 9;
 const LAMPORTS: Scalar = 1e9;
 
 pub fn to_sol(amount: u64) -> Scalar {
@@ -13,12 +14,31 @@ pub fn to_lamports(amount: Scalar) -> u64 {
     (amount * LAMPORTS) as u64
 }
 
+pub fn to_base_units(amount: Scalar, decimals: u8) -> u64 {
+    (amount * (10.0 as Scalar).powi(decimals.into())) as u64
+}
+
+pub fn from_base_units(amount: u64, decimals: u8) -> Scalar {
+    amount as Scalar / (10.0 as Scalar).powi(decimals.into())
+}
+
 pub fn strip_uri(uri: &mut String) {
     if let Some(index) = uri.rfind('/') {
         uri.drain(index..);
     }
 }
 
+#[test]
+fn base_unit_conversion() {
+    assert_eq!(to_base_units(0.07, LAMPORT_DECIMALS), to_lamports(0.07));
+    assert_eq!(to_base_units(12.5, 6), 12_500_000);
+    assert_eq!(from_base_units(12_500_000, 6), 12.5);
+    assert_eq!(
+        from_base_units(70_000_000, LAMPORT_DECIMALS),
+        to_sol(70_000_000)
+    );
+}
+
 #[test]
 fn strip_uri_test() {
     let mut uri = "https://hello/this-is-a-dir/file.json".to_string();
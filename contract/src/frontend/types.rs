@@ -32,6 +32,49 @@ pub enum FrontendTokenConfig {
     },
 }
 
+/// The SPL token in which the bids of an auction are placed.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendBidMint {
+    pub mint: String,
+    pub decimals: u8,
+}
+
+/// Converts a frontend amount into lamports or into the smallest unit of the
+/// bid mint.
+pub fn to_bid_units(amount: Scalar, bid_mint: Option<&FrontendBidMint>) -> u64 {
+    bid_mint.map_or_else(
+        || to_lamports(amount),
+        |bid_mint| to_base_units(amount, bid_mint.decimals),
+    )
+}
+
+/// Converts lamports or the smallest unit of the bid mint into a frontend
+/// amount.
+pub fn from_bid_units(amount: u64, bid_mint: Option<&FrontendBidMint>) -> Scalar {
+    bid_mint.map_or_else(
+        || to_sol(amount),
+        |bid_mint| from_base_units(amount, bid_mint.decimals),
+    )
+}
+
+/// Converts the frontend representation of a minimum bid increment.
+///
+/// Returns `None` if neither the absolute nor the relative increment is set.
+pub fn to_bid_increment(
+    amount: Option<Scalar>,
+    basis_points: Option<u16>,
+    bid_mint: Option<&FrontendBidMint>,
+) -> Option<BidIncrement> {
+    if amount.is_none() && basis_points.is_none() {
+        return None;
+    }
+    Some(BidIncrement {
+        amount: amount.map_or(0, |amount| to_bid_units(amount, bid_mint)),
+        basis_points: basis_points.unwrap_or_default(),
+    })
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FrontendAuctionConfig {
@@ -52,6 +95,75 @@ pub struct FrontendAuctionConfigExtra {
     pub number_of_cycles: u64,
     pub start_time: Option<UnixTimestamp>,
     pub min_bid: Option<Scalar>,
+    pub bid_mint: Option<FrontendBidMint>,
+    pub reserve_price: Option<Scalar>,
+    pub is_reserve_price_hidden: Option<bool>,
+    pub min_bid_increment: Option<Scalar>,
+    pub min_bid_increment_bps: Option<u16>,
+    pub buy_now_price: Option<Scalar>,
+    pub dutch_price_curve: Option<FrontendDutchPriceCurve>,
+    pub reveal_period: Option<UnixTimestamp>,
+    pub second_price_settlement: Option<bool>,
+    pub winners_per_cycle: Option<u8>,
+    pub referral_share_bps: Option<u16>,
+    pub beneficiaries: Option<Vec<FrontendBeneficiary>>,
+    pub all_or_nothing: Option<bool>,
+    pub finish_on_goal: Option<bool>,
+    pub vesting_schedule: Option<FrontendVestingSchedule>,
+}
+
+/// A recipient of a share of the funds claimed from an auction.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendBeneficiary {
+    pub pubkey: String,
+    pub share_bps: u16,
+}
+
+/// Converts the frontend representation of the beneficiaries of an auction.
+pub fn to_beneficiaries(beneficiaries: Vec<FrontendBeneficiary>) -> Result<BeneficiaryVec, String> {
+    let mut vec = Vec::<Beneficiary>::with_capacity(beneficiaries.len());
+    for beneficiary in beneficiaries.into_iter() {
+        vec.push(Beneficiary {
+            pubkey: Pubkey::from_str(&beneficiary.pubkey).map_err(|e| e.to_string())?,
+            share_bps: beneficiary.share_bps,
+        });
+    }
+    Ok(vec.try_into()?)
+}
+
+/// Schedule of releasing the funds raised by an auction (periods are measured
+/// from the start of the auction in seconds).
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendVestingSchedule {
+    pub cliff_period: UnixTimestamp,
+    pub vesting_period: UnixTimestamp,
+}
+
+impl From<FrontendVestingSchedule> for VestingSchedule {
+    fn from(schedule: FrontendVestingSchedule) -> Self {
+        Self {
+            cliff_period: schedule.cliff_period,
+            vesting_period: schedule.vesting_period,
+        }
+    }
+}
+
+impl From<VestingSchedule> for FrontendVestingSchedule {
+    fn from(schedule: VestingSchedule) -> Self {
+        Self {
+            cliff_period: schedule.cliff_period,
+            vesting_period: schedule.vesting_period,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendDutchPriceCurve {
+    pub start_price: Scalar,
+    pub floor_price: Scalar,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -80,11 +192,17 @@ pub struct FrontendAuction {
     #[serde(flatten)]
     pub config: FrontendAuctionConfigExtra,
     pub available_treasury_amount: Scalar,
+    pub escrowed_treasury_amount: Scalar,
     pub current_cycle: u64,
     pub is_finished: bool,
     pub is_frozen: bool,
+    pub is_paused: bool,
     pub is_filtered: bool,
     pub root_state_pubkey: String,
+    pub pending_owner_pubkey: Option<String>,
+    pub beneficiary_pubkey: String,
+    /// Protocol fee collected on the claimed funds (in basis points).
+    pub protocol_fee_bps: u16,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -92,6 +210,17 @@ pub struct FrontendAuction {
 pub struct FrontendBid {
     pub bidder_pubkey: String,
     pub amount: Scalar,
+    pub memo: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendBidLogPage {
+    /// Bids of the page in the order of placement.
+    pub bids: Vec<FrontendBid>,
+    /// Total number of bids placed on the cycle.
+    pub bid_count: u64,
+    pub page_count: u64,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -99,6 +228,30 @@ pub struct FrontendBid {
 pub struct FrontendCycle {
     pub bids: Vec<FrontendBid>,
     pub end_timestamp: UnixTimestamp,
+    pub is_reserve_met: bool,
+}
+
+/// Cumulative protocol revenue counters of the contract (fees in SOL).
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendContractStats {
+    pub fees_collected: Scalar,
+    pub fees_withdrawn: Scalar,
+    pub auctions_created: u64,
+    pub auctions_finished: u64,
+    pub auctions_deleted: u64,
+}
+
+impl From<ContractStats> for FrontendContractStats {
+    fn from(stats: ContractStats) -> Self {
+        Self {
+            fees_collected: to_sol(stats.fees_collected),
+            fees_withdrawn: to_sol(stats.fees_withdrawn),
+            auctions_created: stats.auctions_created,
+            auctions_finished: stats.auctions_finished,
+            auctions_deleted: stats.auctions_deleted,
+        }
+    }
 }
 
 impl TryInto<InitializeAuctionArgs> for FrontendAuctionConfig {
@@ -119,6 +272,22 @@ impl TryInto<InitializeAuctionArgs> for FrontendAuctionConfig {
         };
         let auction_id = pad_to_32_bytes(&self.base.id)?;
         let auction_name = pad_to_32_bytes(&self.base.name)?;
+        let bid_mint = self.extra.bid_mint.as_ref();
+        let bid_mint_pubkey = if let Some(bid_mint) = bid_mint {
+            Some(Pubkey::from_str(&bid_mint.mint).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+        let kind = if let Some(price_curve) = self.extra.dutch_price_curve {
+            AuctionKind::Dutch(DutchPriceCurve {
+                start_price: to_bid_units(price_curve.start_price, bid_mint),
+                floor_price: to_bid_units(price_curve.floor_price, bid_mint),
+            })
+        } else if let Some(reveal_period) = self.extra.reveal_period {
+            AuctionKind::Sealed(SealedBidConfig { reveal_period })
+        } else {
+            AuctionKind::English
+        };
         let auction_config = AuctionConfig {
             cycle_period: self.extra.cycle_period,
             encore_period: self.extra.encore_period.unwrap_or_default(),
@@ -126,8 +295,28 @@ impl TryInto<InitializeAuctionArgs> for FrontendAuctionConfig {
             minimum_bid_amount: self
                 .extra
                 .min_bid
-                .map(to_lamports)
+                .map(|min_bid| to_bid_units(min_bid, bid_mint))
                 .unwrap_or_else(|| UNIVERSAL_BID_FLOOR),
+            bid_mint: bid_mint_pubkey,
+            reserve_price: self.extra.reserve_price.map(|reserve_price| ReservePrice {
+                amount: to_bid_units(reserve_price, bid_mint),
+                is_hidden: self.extra.is_reserve_price_hidden.unwrap_or_default(),
+            }),
+            min_bid_increment: to_bid_increment(
+                self.extra.min_bid_increment,
+                self.extra.min_bid_increment_bps,
+                bid_mint,
+            ),
+            buy_now_price: self
+                .extra
+                .buy_now_price
+                .map(|buy_now_price| to_bid_units(buy_now_price, bid_mint)),
+            kind,
+            second_price_settlement: self.extra.second_price_settlement.unwrap_or_default(),
+            winners_per_cycle: self.extra.winners_per_cycle.unwrap_or(1),
+            referral_share_bps: self.extra.referral_share_bps.unwrap_or_default(),
+            all_or_nothing: self.extra.all_or_nothing.unwrap_or_default(),
+            finish_on_goal: self.extra.finish_on_goal.unwrap_or_default(),
         };
         let mut socials = Vec::<SocialsString>::with_capacity(self.extra.socials.len());
         for link in self.extra.socials.into_iter() {
@@ -136,7 +325,10 @@ impl TryInto<InitializeAuctionArgs> for FrontendAuctionConfig {
         let auction_description = AuctionDescription {
             description: DescriptionString::try_from(self.extra.description)?,
             socials: socials.try_into()?,
-            goal_treasury_amount: self.base.goal_treasury_amount.map(to_lamports),
+            goal_treasury_amount: self
+                .base
+                .goal_treasury_amount
+                .map(|goal| to_bid_units(goal, bid_mint)),
         };
         let create_token_args = match self.extra.asset {
             FrontendTokenConfig::Nft {
@@ -175,6 +367,8 @@ impl TryInto<InitializeAuctionArgs> for FrontendAuctionConfig {
             }
         };
         let auction_start_timestamp = self.extra.start_time;
+        let beneficiaries = to_beneficiaries(self.extra.beneficiaries.unwrap_or_default())?;
+        let vesting_schedule = self.extra.vesting_schedule.map(VestingSchedule::from);
 
         Ok(InitializeAuctionArgs {
             auction_owner_pubkey,
@@ -184,6 +378,8 @@ impl TryInto<InitializeAuctionArgs> for FrontendAuctionConfig {
             auction_description,
             create_token_args,
             auction_start_timestamp,
+            beneficiaries,
+            vesting_schedule,
         })
     }
 }
@@ -312,6 +508,30 @@ mod test {
             number_of_cycles: 10,
             start_time: None,
             min_bid: Some(0.5),
+            bid_mint: None,
+            reserve_price: Some(2.5),
+            is_reserve_price_hidden: None,
+            min_bid_increment: Some(0.1),
+            min_bid_increment_bps: None,
+            buy_now_price: Some(10.0),
+            dutch_price_curve: Some(FrontendDutchPriceCurve {
+                start_price: 20.0,
+                floor_price: 2.5,
+            }),
+            reveal_period: None,
+            second_price_settlement: None,
+            winners_per_cycle: None,
+            referral_share_bps: None,
+            beneficiaries: Some(vec![FrontendBeneficiary {
+                pubkey: "7Z8ftDAzMvoyXnGEJye8DurzgQQXLAbYCaeeesM7UKHa".to_owned(),
+                share_bps: 2_500,
+            }]),
+            all_or_nothing: None,
+            finish_on_goal: None,
+            vesting_schedule: Some(FrontendVestingSchedule {
+                cliff_period: 3600,
+                vesting_period: 7200,
+            }),
         };
         let frontend_auction_config = FrontendAuctionConfig {
             base: base_config,
@@ -343,6 +563,26 @@ mod test {
             Some(150_000_000_000)
         );
         assert_eq!(init_args.auction_config.minimum_bid_amount, 500_000_000);
+        let reserve_price = init_args.auction_config.reserve_price.unwrap();
+        assert_eq!(reserve_price.amount, 2_500_000_000);
+        assert!(!reserve_price.is_hidden);
+        let min_bid_increment = init_args.auction_config.min_bid_increment.unwrap();
+        assert_eq!(min_bid_increment.amount, 100_000_000);
+        assert_eq!(min_bid_increment.basis_points, 0);
+        assert_eq!(init_args.auction_config.buy_now_price, Some(10_000_000_000));
+        if let AuctionKind::Dutch(price_curve) = init_args.auction_config.kind {
+            assert_eq!(price_curve.start_price, 20_000_000_000);
+            assert_eq!(price_curve.floor_price, 2_500_000_000);
+        } else {
+            panic!("should be a Dutch auction");
+        }
+        assert_eq!(
+            init_args.vesting_schedule,
+            Some(VestingSchedule {
+                cliff_period: 3600,
+                vesting_period: 7200,
+            })
+        );
         match init_args.create_token_args {
             CreateTokenArgs::Nft {
                 metadata_args,
@@ -0,0 +1,49 @@
+use super::*;
+
+pub struct ProposeContractAdminArgs {
+    pub contract_admin_pubkey: Pubkey,
+    /// The proposed admin (`None` cancels the pending proposal).
+    pub new_contract_admin_pubkey: Option<Pubkey>,
+}
+
+pub fn propose_contract_admin(args: &ProposeContractAdminArgs) -> Instruction {
+    let (contract_bank_pubkey, _) =
+        Pubkey::find_program_address(&contract_bank_seeds(), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(args.contract_admin_pubkey, true),
+        AccountMeta::new(contract_bank_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::ProposeContractAdmin {
+        new_contract_admin: args.new_contract_admin_pubkey,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+pub struct AcceptContractAdminArgs {
+    pub new_contract_admin_pubkey: Pubkey,
+}
+
+pub fn accept_contract_admin(args: &AcceptContractAdminArgs) -> Instruction {
+    let (contract_bank_pubkey, _) =
+        Pubkey::find_program_address(&contract_bank_seeds(), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(args.new_contract_admin_pubkey, true),
+        AccountMeta::new(contract_bank_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::AcceptContractAdmin;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
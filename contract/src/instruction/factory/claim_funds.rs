@@ -3,33 +3,47 @@ use super::*;
 #[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
 pub struct ClaimFundsArgs {
     pub payer_pubkey: Pubkey,
-    pub auction_owner_pubkey: Pubkey,
+    /// Account receiving the claimed funds (the auction beneficiary).
+    pub beneficiary_pubkey: Pubkey,
     #[alias([u8; 32])]
     pub auction_id: AuctionId,
     pub cycle_number: u64,
     pub amount: u64,
+    pub bid_mint: Option<Pubkey>,
+    /// Beneficiaries of the auction (in the order of the auction's
+    /// beneficiaries).
+    pub beneficiaries: Vec<Pubkey>,
 }
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct FrontendClaimFundsArgs {
     pub payer_pubkey: String,
-    pub auction_owner_pubkey: String,
+    pub beneficiary_pubkey: String,
     pub auction_id: String,
     pub cycle_number: u64,
     pub amount: Scalar,
+    pub bid_mint: Option<FrontendBidMint>,
+    pub beneficiaries: Option<Vec<String>>,
 }
 
 impl TryFrom<FrontendClaimFundsArgs> for ClaimFundsArgs {
     type Error = String;
     fn try_from(args: FrontendClaimFundsArgs) -> Result<Self, Self::Error> {
+        let bid_mint = if let Some(bid_mint) = args.bid_mint.as_ref() {
+            Some(Pubkey::from_str(&bid_mint.mint).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
         Ok(Self {
             payer_pubkey: Pubkey::from_str(&args.payer_pubkey).map_err(|e| e.to_string())?,
-            auction_owner_pubkey: Pubkey::from_str(&args.auction_owner_pubkey)
+            beneficiary_pubkey: Pubkey::from_str(&args.beneficiary_pubkey)
                 .map_err(|e| e.to_string())?,
             auction_id: pad_to_32_bytes(&args.auction_id)?,
             cycle_number: args.cycle_number,
-            amount: to_lamports(args.amount),
+            amount: to_bid_units(args.amount, args.bid_mint.as_ref()),
+            bid_mint,
+            beneficiaries: parse_beneficiaries(args.beneficiaries)?,
         })
     }
 }
@@ -50,9 +64,9 @@ pub fn claim_funds(args: &ClaimFundsArgs) -> Instruction {
     let (protocol_fee_state_pubkey, _) =
         Pubkey::find_program_address(&protocol_fee_state_seeds(), &crate::ID);
 
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(args.payer_pubkey, true),
-        AccountMeta::new(args.auction_owner_pubkey, false),
+        AccountMeta::new(args.beneficiary_pubkey, false),
         AccountMeta::new(auction_bank_pubkey, false),
         AccountMeta::new(auction_root_state_pubkey, false),
         AccountMeta::new(auction_cycle_state_pubkey, false),
@@ -60,6 +74,18 @@ pub fn claim_funds(args: &ClaimFundsArgs) -> Instruction {
         AccountMeta::new_readonly(protocol_fee_state_pubkey, false),
     ];
 
+    if let Some(bid_mint) = args.bid_mint {
+        accounts.append(&mut token_payout_accounts(
+            &args.auction_id,
+            &args.beneficiary_pubkey,
+            &bid_mint,
+        ));
+    }
+    accounts.append(&mut beneficiary_payout_accounts(
+        &args.beneficiaries,
+        args.bid_mint.as_ref(),
+    ));
+
     let instruction = AuctionInstruction::ClaimFunds {
         id: args.auction_id,
         amount: args.amount,
@@ -71,3 +97,61 @@ pub fn claim_funds(args: &ClaimFundsArgs) -> Instruction {
         data: instruction.try_to_vec().unwrap(),
     }
 }
+
+/// Accounts of the beneficiaries receiving their shares of the claimed funds.
+pub(crate) fn beneficiary_payout_accounts(
+    beneficiaries: &[Pubkey],
+    bid_mint: Option<&Pubkey>,
+) -> Vec<AccountMeta> {
+    let mut accounts = beneficiaries
+        .iter()
+        .map(|beneficiary| AccountMeta::new(*beneficiary, false))
+        .collect::<Vec<_>>();
+    if let Some(bid_mint) = bid_mint {
+        accounts.extend(beneficiaries.iter().map(|beneficiary| {
+            let (holding_pubkey, _) = Pubkey::find_program_address(
+                &token_holding_seeds(bid_mint, beneficiary),
+                &crate::ID,
+            );
+            AccountMeta::new(holding_pubkey, false)
+        }));
+    }
+    accounts
+}
+
+/// Parses the frontend representation of the beneficiaries of an auction.
+pub(crate) fn parse_beneficiaries(
+    beneficiaries: Option<Vec<String>>,
+) -> Result<Vec<Pubkey>, String> {
+    beneficiaries
+        .unwrap_or_default()
+        .iter()
+        .map(|beneficiary| Pubkey::from_str(beneficiary).map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// Accounts required to pay out SPL token bids from the auction vault.
+pub(crate) fn token_payout_accounts(
+    auction_id: &AuctionId,
+    recipient_pubkey: &Pubkey,
+    bid_mint: &Pubkey,
+) -> Vec<AccountMeta> {
+    let (contract_pda, _) = Pubkey::find_program_address(&contract_pda_seeds(), &crate::ID);
+    let (auction_vault_pubkey, _) =
+        Pubkey::find_program_address(&auction_vault_seeds(auction_id), &crate::ID);
+    let (recipient_holding_pubkey, _) =
+        Pubkey::find_program_address(&token_holding_seeds(bid_mint, recipient_pubkey), &crate::ID);
+    let (contract_vault_pubkey, _) =
+        Pubkey::find_program_address(&contract_vault_seeds(bid_mint), &crate::ID);
+
+    vec![
+        AccountMeta::new_readonly(TOKEN_ID, false),
+        AccountMeta::new_readonly(RENT_ID, false),
+        AccountMeta::new_readonly(SYS_ID, false),
+        AccountMeta::new_readonly(contract_pda, false),
+        AccountMeta::new_readonly(*bid_mint, false),
+        AccountMeta::new(auction_vault_pubkey, false),
+        AccountMeta::new(recipient_holding_pubkey, false),
+        AccountMeta::new(contract_vault_pubkey, false),
+    ]
+}
@@ -0,0 +1,38 @@
+use super::*;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct ClaimReferralRewardsArgs {
+    pub referrer_pubkey: Pubkey,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendClaimReferralRewardsArgs {
+    pub referrer_pubkey: String,
+}
+
+impl TryFrom<FrontendClaimReferralRewardsArgs> for ClaimReferralRewardsArgs {
+    type Error = String;
+    fn try_from(args: FrontendClaimReferralRewardsArgs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            referrer_pubkey: Pubkey::from_str(&args.referrer_pubkey).map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+pub fn claim_referral_rewards(args: &ClaimReferralRewardsArgs) -> Instruction {
+    let (referral_state_pubkey, _) =
+        Pubkey::find_program_address(&referral_state_seeds(&args.referrer_pubkey), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(args.referrer_pubkey, true),
+        AccountMeta::new(referral_state_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::ClaimReferralRewards;
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
@@ -7,6 +7,10 @@ pub struct ClaimRewardsArgs {
     #[alias([u8; 32])]
     pub auction_id: AuctionId,
     pub cycle_number: u64,
+    /// Rank of the winning bid (the top bid has rank 0).
+    pub rank: u8,
+    /// Number of winners per cycle in the auction.
+    pub winners_per_cycle: u8,
     pub token_type: TokenType,
     pub existing_token_mint: Option<Pubkey>,
 }
@@ -18,6 +22,8 @@ pub struct FrontendClaimRewardsArgs {
     pub top_bidder_pubkey: String,
     pub auction_id: String,
     pub cycle_number: u64,
+    pub rank: Option<u8>,
+    pub winners_per_cycle: Option<u8>,
     pub token_type: TokenType,
     pub existing_token_mint: Option<String>,
 }
@@ -36,6 +42,8 @@ impl TryFrom<FrontendClaimRewardsArgs> for ClaimRewardsArgs {
                 .map_err(|e| e.to_string())?,
             auction_id: pad_to_32_bytes(&args.auction_id)?,
             cycle_number: args.cycle_number,
+            rank: args.rank.unwrap_or(0),
+            winners_per_cycle: args.winners_per_cycle.unwrap_or(1),
             token_type: args.token_type,
             existing_token_mint,
         })
@@ -66,13 +74,11 @@ pub fn claim_rewards(args: &ClaimRewardsArgs) -> Instruction {
     let mut token_accounts = match args.token_type {
         TokenType::Nft => {
             let master_pdas = EditionPda::new(EditionType::Master, &args.auction_id);
-            let child_pdas =
-                EditionPda::new(EditionType::Child(args.cycle_number), &args.auction_id);
+            let edition =
+                edition_number(args.cycle_number, args.rank, args.winners_per_cycle).unwrap();
+            let child_pdas = EditionPda::new(EditionType::Child(edition), &args.auction_id);
 
-            let edition_div = args
-                .cycle_number
-                .checked_div(EDITION_MARKER_BIT_SIZE)
-                .unwrap();
+            let edition_div = edition.checked_div(EDITION_MARKER_BIT_SIZE).unwrap();
             let edition_string = edition_div.to_string();
             let (child_edition_marker_pubkey, _) = Pubkey::find_program_address(
                 &edition_marker_seeds(&edition_string, &master_pdas.mint),
@@ -112,6 +118,7 @@ pub fn claim_rewards(args: &ClaimRewardsArgs) -> Instruction {
     let instruction = AuctionInstruction::ClaimRewards {
         id: args.auction_id,
         cycle_number: args.cycle_number,
+        rank: args.rank,
     };
 
     Instruction {
@@ -150,4 +157,6 @@ fn deserialization_and_conversion() {
         "95B225CEtMmkRYUpg626DNqen55FgwEGbH5NKVXHUejK"
     );
     assert_eq!(args.token_type, TokenType::Token);
+    assert_eq!(args.rank, 0);
+    assert_eq!(args.winners_per_cycle, 1);
 }
@@ -8,6 +8,9 @@ pub struct CloseAuctionCycleArgs {
     pub next_cycle_num: u64,
     pub token_type: TokenType,
     pub existing_token_mint: Option<Pubkey>,
+    /// Referrers rewarded upon settling the cycle, see
+    /// [`AuctionCycleState::rewarded_referrers`](crate::state::AuctionCycleState::rewarded_referrers).
+    pub referrers: Vec<Pubkey>,
 }
 
 pub fn close_auction_cycle(args: &CloseAuctionCycleArgs) -> Instruction {
@@ -17,6 +20,10 @@ pub fn close_auction_cycle(args: &CloseAuctionCycleArgs) -> Instruction {
     let (secondary_pool_pubkey, _) =
         Pubkey::find_program_address(&secondary_pool_seeds(), &crate::ID);
 
+    let (auction_bank_pubkey, _) =
+        Pubkey::find_program_address(&auction_bank_seeds(&args.auction_id), &crate::ID);
+    let (contract_bank_pubkey, _) =
+        Pubkey::find_program_address(&contract_bank_seeds(), &crate::ID);
     let (contract_pda, _) = Pubkey::find_program_address(&contract_pda_seeds(), &crate::ID);
 
     let top_bidder = if let Some(bidder) = args.top_bidder_pubkey {
@@ -47,7 +54,9 @@ pub fn close_auction_cycle(args: &CloseAuctionCycleArgs) -> Instruction {
         AccountMeta::new(auction_root_state_pubkey, false),
         AccountMeta::new(current_auction_cycle_state_pubkey, false),
         AccountMeta::new(next_auction_cycle_state_pubkey, false),
-        AccountMeta::new_readonly(top_bidder, false),
+        AccountMeta::new(auction_bank_pubkey, false),
+        AccountMeta::new(contract_bank_pubkey, false),
+        AccountMeta::new(top_bidder, false),
         AccountMeta::new_readonly(contract_pda, false),
         AccountMeta::new_readonly(RENT_ID, false),
         AccountMeta::new_readonly(SYS_ID, false),
@@ -81,7 +90,24 @@ pub fn close_auction_cycle(args: &CloseAuctionCycleArgs) -> Instruction {
         }
     };
 
-    accounts.append(&mut token_accounts);
+    let mut referral_accounts = args
+        .referrers
+        .iter()
+        .map(|referrer| {
+            let (referral_state_pubkey, _) =
+                Pubkey::find_program_address(&referral_state_seeds(referrer), &crate::ID);
+            AccountMeta::new(referral_state_pubkey, false)
+        })
+        .collect::<Vec<_>>();
+
+    // Referral state accounts follow the master edition accounts (if any)
+    if args.token_type == TokenType::Nft {
+        accounts.append(&mut token_accounts);
+        accounts.append(&mut referral_accounts);
+    } else {
+        accounts.append(&mut referral_accounts);
+        accounts.append(&mut token_accounts);
+    }
 
     let instruction = AuctionInstruction::CloseAuctionCycle {
         id: args.auction_id,
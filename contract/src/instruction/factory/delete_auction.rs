@@ -3,26 +3,33 @@ use super::*;
 #[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
 pub struct DeleteAuctionArgs {
     pub auction_owner_pubkey: Pubkey,
-    pub top_bidder_pubkey: Option<Pubkey>,
+    /// Account receiving the remaining funds (the auction beneficiary).
+    pub beneficiary_pubkey: Pubkey,
     #[alias([u8; 32])]
     pub auction_id: AuctionId,
     pub current_auction_cycle: u64,
     pub num_of_cycles_to_delete: u64,
+    pub bid_mint: Option<Pubkey>,
+    /// Beneficiaries of the auction (in the order of the auction's
+    /// beneficiaries).
+    pub beneficiaries: Vec<Pubkey>,
 }
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct FrontendDeleteAuctionArgs {
     pub auction_owner_pubkey: String,
-    pub top_bidder_pubkey: Option<String>,
+    pub beneficiary_pubkey: String,
     pub auction_id: String,
     pub cycle_number: u64,
+    pub bid_mint: Option<String>,
+    pub beneficiaries: Option<Vec<String>>,
 }
 
 impl TryFrom<FrontendDeleteAuctionArgs> for DeleteAuctionArgs {
     type Error = String;
     fn try_from(args: FrontendDeleteAuctionArgs) -> Result<Self, Self::Error> {
-        let top_bidder_pubkey = if let Some(pubkey_string) = args.top_bidder_pubkey {
+        let bid_mint = if let Some(pubkey_string) = args.bid_mint {
             Some(Pubkey::from_str(&pubkey_string).map_err(|e| e.to_string())?)
         } else {
             None
@@ -30,10 +37,13 @@ impl TryFrom<FrontendDeleteAuctionArgs> for DeleteAuctionArgs {
         Ok(Self {
             auction_owner_pubkey: Pubkey::from_str(&args.auction_owner_pubkey)
                 .map_err(|e| e.to_string())?,
-            top_bidder_pubkey,
+            beneficiary_pubkey: Pubkey::from_str(&args.beneficiary_pubkey)
+                .map_err(|e| e.to_string())?,
             auction_id: pad_to_32_bytes(&args.auction_id)?,
             current_auction_cycle: args.cycle_number,
             num_of_cycles_to_delete: crate::RECOMMENDED_CYCLE_STATES_DELETED_PER_CALL,
+            bid_mint,
+            beneficiaries: parse_beneficiaries(args.beneficiaries)?,
         })
     }
 }
@@ -55,21 +65,15 @@ pub fn delete_auction(args: &DeleteAuctionArgs) -> Instruction {
     let (protocol_fee_state_pubkey, _) =
         Pubkey::find_program_address(&protocol_fee_state_seeds(), &crate::ID);
 
-    let top_bidder = if let Some(bidder) = args.top_bidder_pubkey {
-        bidder
-    } else {
-        Pubkey::default()
-    };
-
     let mut accounts = vec![
         AccountMeta::new(args.auction_owner_pubkey, true),
-        AccountMeta::new(top_bidder, false),
         AccountMeta::new(auction_root_state_pubkey, false),
         AccountMeta::new(auction_bank_pubkey, false),
         AccountMeta::new(contract_bank_pubkey, false),
         AccountMeta::new(auction_pool_pubkey, false),
         AccountMeta::new(secondary_pool_pubkey, false),
         AccountMeta::new_readonly(protocol_fee_state_pubkey, false),
+        AccountMeta::new(args.beneficiary_pubkey, false),
     ];
 
     let cycles_to_include = std::cmp::min(args.current_auction_cycle, args.num_of_cycles_to_delete);
@@ -84,6 +88,16 @@ pub fn delete_auction(args: &DeleteAuctionArgs) -> Instruction {
         accounts.push(AccountMeta::new(auction_cycle_state_pubkey, false));
     }
 
+    if let Some(bid_mint) = args.bid_mint {
+        let mut token_accounts =
+            token_payout_accounts(&args.auction_id, &args.beneficiary_pubkey, &bid_mint);
+        accounts.append(&mut token_accounts);
+    }
+    accounts.append(&mut beneficiary_payout_accounts(
+        &args.beneficiaries,
+        args.bid_mint.as_ref(),
+    ));
+
     let instruction = AuctionInstruction::DeleteAuction {
         id: args.auction_id,
         num_of_cycles_to_delete: args.num_of_cycles_to_delete,
@@ -122,12 +136,12 @@ mod test {
         let args = DeleteAuctionArgs {
             auction_owner_pubkey: Pubkey::from_str("7Z8ftDAzMvoyXnGEJye8DurzgQQXLAbYCaeeesM7UKHa")
                 .unwrap(),
-            top_bidder_pubkey: Some(
-                Pubkey::from_str("7Z8ftDAzMvoyXnGEJye8DurzgQQXLAbYCaeeesM7UKN1").unwrap(),
-            ),
+            beneficiary_pubkey: Pubkey::new_unique(),
             auction_id: [120; 32],
             current_auction_cycle: 80,
             num_of_cycles_to_delete: 40,
+            bid_mint: None,
+            beneficiaries: Vec::new(),
         };
 
         let instructions = delete_all(args);
@@ -139,12 +153,12 @@ mod test {
         let args = DeleteAuctionArgs {
             auction_owner_pubkey: Pubkey::from_str("7Z8ftDAzMvoyXnGEJye8DurzgQQXLAbYCaeeesM7UKHa")
                 .unwrap(),
-            top_bidder_pubkey: Some(
-                Pubkey::from_str("7Z8ftDAzMvoyXnGEJye8DurzgQQXLAbYCaeeesM7UKN1").unwrap(),
-            ),
+            beneficiary_pubkey: Pubkey::new_unique(),
             auction_id: [120; 32],
             current_auction_cycle: 33,
             num_of_cycles_to_delete: 20,
+            bid_mint: None,
+            beneficiaries: Vec::new(),
         };
         let instructions = delete_all(args);
         assert_eq!(instructions.len(), 2);
@@ -152,12 +166,12 @@ mod test {
         let args = DeleteAuctionArgs {
             auction_owner_pubkey: Pubkey::from_str("7Z8ftDAzMvoyXnGEJye8DurzgQQXLAbYCaeeesM7UKHa")
                 .unwrap(),
-            top_bidder_pubkey: Some(
-                Pubkey::from_str("7Z8ftDAzMvoyXnGEJye8DurzgQQXLAbYCaeeesM7UKN1").unwrap(),
-            ),
+            beneficiary_pubkey: Pubkey::new_unique(),
             auction_id: [120; 32],
             current_auction_cycle: 8,
             num_of_cycles_to_delete: 20,
+            bid_mint: None,
+            beneficiaries: Vec::new(),
         };
         let instructions = delete_all(args);
         assert_eq!(instructions.len(), 1);
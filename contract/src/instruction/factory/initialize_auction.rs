@@ -15,6 +15,9 @@ pub struct InitializeAuctionArgs {
     pub auction_description: AuctionDescription,
     pub create_token_args: CreateTokenArgs,
     pub auction_start_timestamp: Option<UnixTimestamp>,
+    #[alias(Vec<Beneficiary>)]
+    pub beneficiaries: BeneficiaryVec,
+    pub vesting_schedule: Option<VestingSchedule>,
 }
 
 impl InitializeAuctionArgs {
@@ -59,6 +62,8 @@ impl InitializeAuctionArgs {
             },
             create_token_args,
             auction_start_timestamp: None,
+            beneficiaries: BeneficiaryVec::new(),
+            vesting_schedule: None,
         }
     }
 }
@@ -79,6 +84,8 @@ pub fn initialize_auction(args: &InitializeAuctionArgs) -> Instruction {
         Pubkey::find_program_address(&auction_bank_seeds(&args.auction_id), &crate::ID);
 
     let (auction_pool_pubkey, _) = Pubkey::find_program_address(&auction_pool_seeds(), &crate::ID);
+    let (contract_bank_pubkey, _) =
+        Pubkey::find_program_address(&contract_bank_seeds(), &crate::ID);
 
     let (contract_pda, _) = Pubkey::find_program_address(&contract_pda_seeds(), &crate::ID);
 
@@ -88,6 +95,7 @@ pub fn initialize_auction(args: &InitializeAuctionArgs) -> Instruction {
         AccountMeta::new(auction_root_state_pubkey, false),
         AccountMeta::new(auction_cycle_state_pubkey, false),
         AccountMeta::new(auction_bank_pubkey, false),
+        AccountMeta::new(contract_bank_pubkey, false),
         AccountMeta::new_readonly(contract_pda, false),
         AccountMeta::new_readonly(RENT_ID, false),
         AccountMeta::new_readonly(SYS_ID, false),
@@ -119,6 +127,13 @@ pub fn initialize_auction(args: &InitializeAuctionArgs) -> Instruction {
 
     accounts.append(&mut token_accounts);
 
+    if let Some(bid_mint) = config_checked.bid_mint {
+        let (auction_vault_pubkey, _) =
+            Pubkey::find_program_address(&auction_vault_seeds(&args.auction_id), &crate::ID);
+        accounts.push(AccountMeta::new_readonly(bid_mint, false));
+        accounts.push(AccountMeta::new(auction_vault_pubkey, false));
+    }
+
     let instruction = AuctionInstruction::InitializeAuction {
         id: args.auction_id,
         auction_name: args.auction_name,
@@ -126,6 +141,8 @@ pub fn initialize_auction(args: &InitializeAuctionArgs) -> Instruction {
         description: args.auction_description.clone(),
         create_token_args: args.create_token_args.clone(),
         auction_start_timestamp: args.auction_start_timestamp,
+        beneficiaries: args.beneficiaries.clone(),
+        vesting_schedule: args.vesting_schedule,
     };
     // unwrap is fine because instruction is serializable
     let data = instruction.try_to_vec().unwrap();
@@ -0,0 +1,72 @@
+use super::*;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct MigrateAuctionArgs {
+    pub payer_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+    pub cycle_number: u64,
+    /// Bidder of the top bid of a legacy cycle that is not settled yet.
+    pub top_bidder_pubkey: Option<Pubkey>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendMigrateAuctionArgs {
+    pub payer_pubkey: String,
+    pub auction_id: String,
+    pub cycle_number: u64,
+    pub top_bidder_pubkey: Option<String>,
+}
+
+impl TryFrom<FrontendMigrateAuctionArgs> for MigrateAuctionArgs {
+    type Error = String;
+    fn try_from(args: FrontendMigrateAuctionArgs) -> Result<Self, Self::Error> {
+        let top_bidder_pubkey = if let Some(top_bidder) = args.top_bidder_pubkey {
+            Some(Pubkey::from_str(&top_bidder).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+        Ok(Self {
+            payer_pubkey: Pubkey::from_str(&args.payer_pubkey).map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id)?,
+            cycle_number: args.cycle_number,
+            top_bidder_pubkey,
+        })
+    }
+}
+
+pub fn migrate_auction(args: &MigrateAuctionArgs) -> Instruction {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+    let (auction_cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(&auction_root_state_pubkey, &args.cycle_number.to_le_bytes()),
+        &crate::ID,
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new(args.payer_pubkey, true),
+        AccountMeta::new(auction_root_state_pubkey, false),
+        AccountMeta::new(auction_cycle_state_pubkey, false),
+        AccountMeta::new_readonly(SYS_ID, false),
+    ];
+
+    if let Some(top_bidder_pubkey) = args.top_bidder_pubkey {
+        let (bid_deposit_pubkey, _) = Pubkey::find_program_address(
+            &bid_deposit_seeds(&auction_cycle_state_pubkey, &top_bidder_pubkey),
+            &crate::ID,
+        );
+        accounts.push(AccountMeta::new(bid_deposit_pubkey, false));
+    }
+
+    let instruction = AuctionInstruction::MigrateAuction {
+        id: args.auction_id,
+        cycle_number: args.cycle_number,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
@@ -1,36 +1,57 @@
+mod admin_transfer;
 mod admin_withdraw;
 mod claim_funds;
+mod claim_referral_rewards;
 mod claim_rewards;
 mod close_auction_cycle;
 mod delete_auction;
 mod filter_auction;
 mod initialize_auction;
 mod initialize_contract;
+mod migrate_auction;
 mod modify_auction;
+mod owner_transfer;
+mod pause_auction;
 mod place_bid;
+mod proxy_bid;
 mod reallocate_pool;
+mod refund_failed_goal;
+mod sealed_bid;
 mod set_protocol_fee;
 mod verify_auction;
+mod withdraw_refund;
 
+pub use admin_transfer::*;
 pub use admin_withdraw::*;
 pub use claim_funds::*;
+pub use claim_referral_rewards::*;
 pub use claim_rewards::*;
 pub use close_auction_cycle::*;
 pub use delete_auction::*;
 pub use filter_auction::*;
 pub use initialize_auction::*;
 pub use initialize_contract::*;
+pub use migrate_auction::*;
 pub use modify_auction::*;
+pub use owner_transfer::*;
+pub use pause_auction::*;
 pub use place_bid::*;
+pub use proxy_bid::*;
 pub use reallocate_pool::*;
+pub use refund_failed_goal::*;
+pub use sealed_bid::*;
 pub use set_protocol_fee::*;
 pub use verify_auction::*;
+pub use withdraw_refund::*;
 
 use super::AuctionInstruction;
-use crate::frontend::{to_lamports, Scalar};
+use crate::frontend::{
+    to_beneficiaries, to_bid_increment, to_bid_units, to_lamports, FrontendBeneficiary,
+    FrontendBidMint, Scalar,
+};
 use crate::pda::*;
 use crate::state::*;
-use crate::utils::pad_to_32_bytes;
+use crate::utils::{edition_number, pad_to_32_bytes};
 use agsol_borsh_schema::BorshSchema;
 use agsol_token_metadata::instruction::CreateMetadataAccountArgs;
 use agsol_token_metadata::state::EDITION_MARKER_BIT_SIZE;
@@ -5,6 +5,9 @@ pub struct ModifyAuctionArgs {
     pub auction_owner_pubkey: Pubkey,
     #[alias([u8; 32])]
     pub auction_id: AuctionId,
+    /// The current cycle of the auction, whose bids lock the minimum bid
+    /// increment of second-price auctions.
+    pub cycle_number: u64,
     pub modify_data: ModifyAuctionData,
 }
 
@@ -13,9 +16,18 @@ pub struct ModifyAuctionArgs {
 pub struct FrontendModifyAuctionArgs {
     pub auction_owner_pubkey: String,
     pub auction_id: String,
+    pub cycle_number: u64,
     pub description: Option<String>,
     pub socials: Option<Vec<String>>,
     pub encore_period: Option<UnixTimestamp>,
+    pub min_bid_increment: Option<Scalar>,
+    pub min_bid_increment_bps: Option<u16>,
+    /// Removes the minimum bid increment if set.
+    #[serde(default)]
+    pub clear_min_bid_increment: bool,
+    pub bid_mint: Option<FrontendBidMint>,
+    pub beneficiaries: Option<Vec<FrontendBeneficiary>>,
+    pub beneficiary_pubkey: Option<String>,
 }
 
 impl TryFrom<FrontendModifyAuctionArgs> for ModifyAuctionArgs {
@@ -37,14 +49,35 @@ impl TryFrom<FrontendModifyAuctionArgs> for ModifyAuctionArgs {
             None
         };
 
+        let new_min_bid_increment = if args.clear_min_bid_increment {
+            Some(None)
+        } else {
+            to_bid_increment(
+                args.min_bid_increment,
+                args.min_bid_increment_bps,
+                args.bid_mint.as_ref(),
+            )
+            .map(Some)
+        };
+
+        let new_beneficiary = if let Some(beneficiary) = args.beneficiary_pubkey {
+            Some(Pubkey::from_str(&beneficiary).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+
         Ok(Self {
             auction_owner_pubkey: Pubkey::from_str(&args.auction_owner_pubkey)
                 .map_err(|e| e.to_string())?,
             auction_id: pad_to_32_bytes(&args.auction_id).map_err(|e| e.to_string())?,
+            cycle_number: args.cycle_number,
             modify_data: ModifyAuctionData {
                 new_description,
                 new_socials,
                 new_encore_period: args.encore_period,
+                new_min_bid_increment,
+                new_beneficiaries: args.beneficiaries.map(to_beneficiaries).transpose()?,
+                new_beneficiary,
             },
         })
     }
@@ -53,10 +86,15 @@ impl TryFrom<FrontendModifyAuctionArgs> for ModifyAuctionArgs {
 pub fn modify_auction(args: &ModifyAuctionArgs) -> Instruction {
     let (auction_root_state_pubkey, _) =
         Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+    let (auction_cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(&auction_root_state_pubkey, &args.cycle_number.to_le_bytes()),
+        &crate::ID,
+    );
 
     let accounts = vec![
         AccountMeta::new(args.auction_owner_pubkey, true),
         AccountMeta::new(auction_root_state_pubkey, false),
+        AccountMeta::new_readonly(auction_cycle_state_pubkey, false),
     ];
 
     let instruction = AuctionInstruction::ModifyAuction {
@@ -76,6 +114,7 @@ fn frontend_conversion() {
     let frontend_args = FrontendModifyAuctionArgs {
         auction_owner_pubkey: Pubkey::default().to_string(),
         auction_id: "hello-auction".to_owned(),
+        cycle_number: 1,
         description: Some("This is a description".to_owned()),
         socials: Some(vec![
             "hello.com".to_owned(),
@@ -83,6 +122,12 @@ fn frontend_conversion() {
             "yello.tg".to_owned(),
         ]),
         encore_period: None,
+        min_bid_increment: None,
+        min_bid_increment_bps: Some(500),
+        clear_min_bid_increment: false,
+        bid_mint: None,
+        beneficiaries: None,
+        beneficiary_pubkey: None,
     };
 
     let args = ModifyAuctionArgs::try_from(frontend_args).unwrap();
@@ -97,4 +142,25 @@ fn frontend_conversion() {
     assert_eq!(new_socials.contents()[1].contents(), "bello.dc");
     assert_eq!(new_socials.contents()[2].contents(), "yello.tg");
     assert!(args.modify_data.new_encore_period.is_none());
+    let new_min_bid_increment = args.modify_data.new_min_bid_increment.unwrap().unwrap();
+    assert_eq!(new_min_bid_increment.amount, 0);
+    assert_eq!(new_min_bid_increment.basis_points, 500);
+
+    let frontend_args = FrontendModifyAuctionArgs {
+        auction_owner_pubkey: Pubkey::default().to_string(),
+        auction_id: "hello-auction".to_owned(),
+        cycle_number: 1,
+        description: None,
+        socials: None,
+        encore_period: None,
+        min_bid_increment: None,
+        min_bid_increment_bps: Some(500),
+        clear_min_bid_increment: true,
+        bid_mint: None,
+        beneficiaries: None,
+        beneficiary_pubkey: None,
+    };
+
+    let args = ModifyAuctionArgs::try_from(frontend_args).unwrap();
+    assert!(matches!(args.modify_data.new_min_bid_increment, Some(None)));
 }
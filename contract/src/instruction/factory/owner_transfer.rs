@@ -0,0 +1,102 @@
+use super::*;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Debug)]
+pub struct ProposeOwnerTransferArgs {
+    pub auction_owner_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+    /// The proposed owner (`None` cancels the pending proposal).
+    pub new_owner_pubkey: Option<Pubkey>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendProposeOwnerTransferArgs {
+    pub auction_owner_pubkey: String,
+    pub auction_id: String,
+    pub new_owner_pubkey: Option<String>,
+}
+
+impl TryFrom<FrontendProposeOwnerTransferArgs> for ProposeOwnerTransferArgs {
+    type Error = String;
+    fn try_from(args: FrontendProposeOwnerTransferArgs) -> Result<Self, Self::Error> {
+        let new_owner_pubkey = if let Some(new_owner) = args.new_owner_pubkey {
+            Some(Pubkey::from_str(&new_owner).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+        Ok(Self {
+            auction_owner_pubkey: Pubkey::from_str(&args.auction_owner_pubkey)
+                .map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id).map_err(|e| e.to_string())?,
+            new_owner_pubkey,
+        })
+    }
+}
+
+pub fn propose_owner_transfer(args: &ProposeOwnerTransferArgs) -> Instruction {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(args.auction_owner_pubkey, true),
+        AccountMeta::new(auction_root_state_pubkey, false),
+        AccountMeta::new_readonly(SYS_ID, false),
+    ];
+
+    let instruction = AuctionInstruction::ProposeOwnerTransfer {
+        id: args.auction_id,
+        new_owner: args.new_owner_pubkey,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Debug)]
+pub struct AcceptOwnerTransferArgs {
+    pub new_owner_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendAcceptOwnerTransferArgs {
+    pub new_owner_pubkey: String,
+    pub auction_id: String,
+}
+
+impl TryFrom<FrontendAcceptOwnerTransferArgs> for AcceptOwnerTransferArgs {
+    type Error = String;
+    fn try_from(args: FrontendAcceptOwnerTransferArgs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            new_owner_pubkey: Pubkey::from_str(&args.new_owner_pubkey)
+                .map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id).map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+pub fn accept_owner_transfer(args: &AcceptOwnerTransferArgs) -> Instruction {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(args.new_owner_pubkey, true),
+        AccountMeta::new(auction_root_state_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::AcceptOwnerTransfer {
+        id: args.auction_id,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
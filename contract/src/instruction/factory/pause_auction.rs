@@ -0,0 +1,101 @@
+use super::*;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Debug)]
+pub struct PauseAuctionArgs {
+    pub auction_owner_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendPauseAuctionArgs {
+    pub auction_owner_pubkey: String,
+    pub auction_id: String,
+}
+
+impl TryFrom<FrontendPauseAuctionArgs> for PauseAuctionArgs {
+    type Error = String;
+    fn try_from(args: FrontendPauseAuctionArgs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            auction_owner_pubkey: Pubkey::from_str(&args.auction_owner_pubkey)
+                .map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id).map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+pub fn pause_auction(args: &PauseAuctionArgs) -> Instruction {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(args.auction_owner_pubkey, true),
+        AccountMeta::new(auction_root_state_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::PauseAuction {
+        id: args.auction_id,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Debug)]
+pub struct ResumeAuctionArgs {
+    pub auction_owner_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+    /// The current cycle of the auction, whose end time is shifted by the
+    /// paused duration.
+    pub cycle_number: u64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendResumeAuctionArgs {
+    pub auction_owner_pubkey: String,
+    pub auction_id: String,
+    pub cycle_number: u64,
+}
+
+impl TryFrom<FrontendResumeAuctionArgs> for ResumeAuctionArgs {
+    type Error = String;
+    fn try_from(args: FrontendResumeAuctionArgs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            auction_owner_pubkey: Pubkey::from_str(&args.auction_owner_pubkey)
+                .map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id).map_err(|e| e.to_string())?,
+            cycle_number: args.cycle_number,
+        })
+    }
+}
+
+pub fn resume_auction(args: &ResumeAuctionArgs) -> Instruction {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+    let (auction_cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(&auction_root_state_pubkey, &args.cycle_number.to_le_bytes()),
+        &crate::ID,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(args.auction_owner_pubkey, true),
+        AccountMeta::new(auction_root_state_pubkey, false),
+        AccountMeta::new(auction_cycle_state_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::ResumeAuction {
+        id: args.auction_id,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
@@ -5,9 +5,30 @@ pub struct PlaceBidArgs {
     pub bidder_pubkey: Pubkey,
     #[alias([u8; 32])]
     pub auction_id: AuctionId,
+    /// The bid is rejected if the cycle is closed before it lands.
     pub cycle_number: u64,
-    pub top_bidder_pubkey: Option<Pubkey>,
     pub amount: u64,
+    /// Amount of the top bid (`0` if there are no bids yet) the bid is
+    /// placed against. If set, the bid is rejected if the top bid changes
+    /// before it lands.
+    pub expected_top_bid: Option<u64>,
+    /// Page of the cycle's bid log the bid is appended to, see
+    /// [`AuctionCycleState::bid_log_page`](crate::state::AuctionCycleState::bid_log_page).
+    /// The next page is also passed in case a proxy bid counters the bid.
+    pub bid_log_page: u64,
+    /// Optional message attached to the bid.
+    #[alias(Option<String>)]
+    pub memo: Option<BidMemo>,
+    /// Account that referred the bidder to the auction.
+    pub referrer: Option<Pubkey>,
+    pub bid_mint: Option<Pubkey>,
+    /// Token account the bid is transferred from if the auction accepts SPL
+    /// token bids. Defaults to the bidder's token holding account.
+    pub bidder_token_account: Option<Pubkey>,
+    /// Type of the auctioned asset if the bid settles the auction cycle
+    /// immediately (i.e. it reaches the buy-now price or the auction is a Dutch
+    /// auction).
+    pub settle_cycle: Option<TokenType>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -17,13 +38,32 @@ pub struct FrontendPlaceBidArgs {
     pub auction_id: String,
     pub cycle_number: u64,
     pub amount: Scalar,
-    pub top_bidder_pubkey: Option<String>,
+    pub memo: Option<String>,
+    pub referrer: Option<String>,
+    pub bid_mint: Option<FrontendBidMint>,
+    pub bidder_token_account: Option<String>,
+    pub settle_cycle: Option<TokenType>,
 }
 
 impl TryFrom<FrontendPlaceBidArgs> for PlaceBidArgs {
     type Error = String;
     fn try_from(args: FrontendPlaceBidArgs) -> Result<Self, Self::Error> {
-        let top_bidder_pubkey = if let Some(pubkey_string) = args.top_bidder_pubkey {
+        let bid_mint = if let Some(bid_mint) = args.bid_mint.as_ref() {
+            Some(Pubkey::from_str(&bid_mint.mint).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+        let memo = if let Some(memo) = args.memo {
+            Some(BidMemo::try_from(memo)?)
+        } else {
+            None
+        };
+        let referrer = if let Some(pubkey_string) = args.referrer {
+            Some(Pubkey::from_str(&pubkey_string).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+        let bidder_token_account = if let Some(pubkey_string) = args.bidder_token_account {
             Some(Pubkey::from_str(&pubkey_string).map_err(|e| e.to_string())?)
         } else {
             None
@@ -32,8 +72,16 @@ impl TryFrom<FrontendPlaceBidArgs> for PlaceBidArgs {
             bidder_pubkey: Pubkey::from_str(&args.bidder_pubkey).map_err(|e| e.to_string())?,
             auction_id: pad_to_32_bytes(&args.auction_id)?,
             cycle_number: args.cycle_number,
-            amount: to_lamports(args.amount),
-            top_bidder_pubkey,
+            amount: to_bid_units(args.amount, args.bid_mint.as_ref()),
+            // Frontend amounts are not exact, the top bid is fetched from the
+            // cycle state instead
+            expected_top_bid: None,
+            bid_log_page: 0,
+            memo,
+            referrer,
+            bid_mint,
+            bidder_token_account,
+            settle_cycle: args.settle_cycle,
         })
     }
 }
@@ -47,30 +95,106 @@ pub fn place_bid(args: &PlaceBidArgs) -> Instruction {
         &auction_cycle_state_seeds(&auction_root_state_pubkey, &args.cycle_number.to_le_bytes()),
         &crate::ID,
     );
+    let (bid_deposit_pubkey, _) = Pubkey::find_program_address(
+        &bid_deposit_seeds(&auction_cycle_state_pubkey, &args.bidder_pubkey),
+        &crate::ID,
+    );
+    let cycle_number_bytes = args.cycle_number.to_le_bytes();
+    let (bid_log_page_pubkey, _) = Pubkey::find_program_address(
+        &bid_log_page_seeds(
+            &auction_root_state_pubkey,
+            &cycle_number_bytes,
+            &args.bid_log_page.to_le_bytes(),
+        ),
+        &crate::ID,
+    );
+    let (next_bid_log_page_pubkey, _) = Pubkey::find_program_address(
+        &bid_log_page_seeds(
+            &auction_root_state_pubkey,
+            &cycle_number_bytes,
+            &(args.bid_log_page + 1).to_le_bytes(),
+        ),
+        &crate::ID,
+    );
     let (auction_pool_pubkey, _) = Pubkey::find_program_address(&auction_pool_seeds(), &crate::ID);
     let (secondary_pool_pubkey, _) =
         Pubkey::find_program_address(&secondary_pool_seeds(), &crate::ID);
 
-    let top_bidder = if let Some(bidder) = args.top_bidder_pubkey {
-        bidder
-    } else {
-        Pubkey::default()
-    };
-
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(args.bidder_pubkey, true),
         AccountMeta::new(auction_bank_pubkey, false),
         AccountMeta::new(auction_root_state_pubkey, false),
         AccountMeta::new(auction_cycle_state_pubkey, false),
-        AccountMeta::new(top_bidder, false),
+        AccountMeta::new(bid_deposit_pubkey, false),
         AccountMeta::new(auction_pool_pubkey, false),
         AccountMeta::new(secondary_pool_pubkey, false),
         AccountMeta::new_readonly(SYS_ID, false),
+        AccountMeta::new(bid_log_page_pubkey, false),
+        AccountMeta::new(next_bid_log_page_pubkey, false),
     ];
 
+    if let Some(bid_mint) = args.bid_mint {
+        let (auction_vault_pubkey, _) =
+            Pubkey::find_program_address(&auction_vault_seeds(&args.auction_id), &crate::ID);
+        let (bidder_holding_pubkey, _) = Pubkey::find_program_address(
+            &token_holding_seeds(&bid_mint, &args.bidder_pubkey),
+            &crate::ID,
+        );
+        accounts.extend_from_slice(&[
+            AccountMeta::new_readonly(TOKEN_ID, false),
+            AccountMeta::new_readonly(RENT_ID, false),
+            AccountMeta::new_readonly(bid_mint, false),
+            AccountMeta::new(auction_vault_pubkey, false),
+            AccountMeta::new(
+                args.bidder_token_account.unwrap_or(bidder_holding_pubkey),
+                false,
+            ),
+            AccountMeta::new(bidder_holding_pubkey, false),
+        ]);
+    }
+
+    if let Some(ref token_type) = args.settle_cycle {
+        let (next_auction_cycle_state_pubkey, _) = Pubkey::find_program_address(
+            &auction_cycle_state_seeds(
+                &auction_root_state_pubkey,
+                &(args.cycle_number + 1).to_le_bytes(),
+            ),
+            &crate::ID,
+        );
+        let (contract_pda, _) = Pubkey::find_program_address(&contract_pda_seeds(), &crate::ID);
+        let (contract_bank_pubkey, _) =
+            Pubkey::find_program_address(&contract_bank_seeds(), &crate::ID);
+        accounts.push(AccountMeta::new(next_auction_cycle_state_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(contract_pda, false));
+        accounts.push(AccountMeta::new(contract_bank_pubkey, false));
+
+        if token_type == &TokenType::Nft {
+            let master_pdas = EditionPda::new(EditionType::Master, &args.auction_id);
+            accounts.extend_from_slice(&[
+                AccountMeta::new_readonly(META_ID, false),
+                AccountMeta::new(master_pdas.edition, false),
+                AccountMeta::new(master_pdas.metadata, false),
+                AccountMeta::new_readonly(master_pdas.mint, false),
+                AccountMeta::new_readonly(master_pdas.holding, false),
+            ]);
+        }
+
+        // The referrer is only rewarded if the auction has a referral share,
+        // otherwise the account is ignored
+        if let Some(ref referrer) = args.referrer {
+            let (referral_state_pubkey, _) =
+                Pubkey::find_program_address(&referral_state_seeds(referrer), &crate::ID);
+            accounts.push(AccountMeta::new(referral_state_pubkey, false));
+        }
+    }
+
     let instruction = AuctionInstruction::Bid {
         id: args.auction_id,
         amount: args.amount,
+        expected_cycle_number: Some(args.cycle_number),
+        expected_top_bid: args.expected_top_bid,
+        memo: args.memo.clone(),
+        referrer: args.referrer,
     };
     Instruction {
         program_id: crate::ID,
@@ -78,3 +202,37 @@ pub fn place_bid(args: &PlaceBidArgs) -> Instruction {
         data: instruction.try_to_vec().unwrap(),
     }
 }
+
+#[test]
+fn frontend_conversion() {
+    let frontend_args = FrontendPlaceBidArgs {
+        bidder_pubkey: Pubkey::default().to_string(),
+        auction_id: "hello-auction".to_owned(),
+        cycle_number: 1,
+        amount: 0.5,
+        memo: Some("for the dev fund!".to_owned()),
+        referrer: None,
+        bid_mint: None,
+        bidder_token_account: None,
+        settle_cycle: None,
+    };
+
+    let args = PlaceBidArgs::try_from(frontend_args).unwrap();
+    assert_eq!(args.bidder_pubkey, Pubkey::default());
+    assert_eq!(args.auction_id, pad_to_32_bytes("hello-auction").unwrap());
+    assert_eq!(args.amount, 500_000_000);
+    assert_eq!(args.memo.unwrap().contents(), "for the dev fund!");
+
+    let frontend_args = FrontendPlaceBidArgs {
+        bidder_pubkey: Pubkey::default().to_string(),
+        auction_id: "hello-auction".to_owned(),
+        cycle_number: 1,
+        amount: 0.5,
+        memo: Some("x".repeat(crate::MAX_BID_MEMO_LEN + 1)),
+        referrer: None,
+        bid_mint: None,
+        bidder_token_account: None,
+        settle_cycle: None,
+    };
+    assert!(PlaceBidArgs::try_from(frontend_args).is_err());
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct PlaceProxyBidArgs {
+    pub bidder_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+    pub cycle_number: u64,
+    /// Maximum amount the contract may bid on behalf of the bidder.
+    pub max_amount: u64,
+    /// Page of the cycle's bid log the bids are appended to, see
+    /// [`AuctionCycleState::bid_log_page`](crate::state::AuctionCycleState::bid_log_page).
+    pub bid_log_page: u64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendPlaceProxyBidArgs {
+    pub bidder_pubkey: String,
+    pub auction_id: String,
+    pub cycle_number: u64,
+    pub max_amount: Scalar,
+}
+
+impl TryFrom<FrontendPlaceProxyBidArgs> for PlaceProxyBidArgs {
+    type Error = String;
+    fn try_from(args: FrontendPlaceProxyBidArgs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            bidder_pubkey: Pubkey::from_str(&args.bidder_pubkey).map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id)?,
+            cycle_number: args.cycle_number,
+            max_amount: to_lamports(args.max_amount),
+            bid_log_page: 0,
+        })
+    }
+}
+
+pub fn place_proxy_bid(args: &PlaceProxyBidArgs) -> Instruction {
+    let (auction_bank_pubkey, _) =
+        Pubkey::find_program_address(&auction_bank_seeds(&args.auction_id), &crate::ID);
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+    let (auction_cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(&auction_root_state_pubkey, &args.cycle_number.to_le_bytes()),
+        &crate::ID,
+    );
+    let (bid_deposit_pubkey, _) = Pubkey::find_program_address(
+        &bid_deposit_seeds(&auction_cycle_state_pubkey, &args.bidder_pubkey),
+        &crate::ID,
+    );
+    let cycle_number_bytes = args.cycle_number.to_le_bytes();
+    let (bid_log_page_pubkey, _) = Pubkey::find_program_address(
+        &bid_log_page_seeds(
+            &auction_root_state_pubkey,
+            &cycle_number_bytes,
+            &args.bid_log_page.to_le_bytes(),
+        ),
+        &crate::ID,
+    );
+    let (next_bid_log_page_pubkey, _) = Pubkey::find_program_address(
+        &bid_log_page_seeds(
+            &auction_root_state_pubkey,
+            &cycle_number_bytes,
+            &(args.bid_log_page + 1).to_le_bytes(),
+        ),
+        &crate::ID,
+    );
+    let (auction_pool_pubkey, _) = Pubkey::find_program_address(&auction_pool_seeds(), &crate::ID);
+    let (secondary_pool_pubkey, _) =
+        Pubkey::find_program_address(&secondary_pool_seeds(), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(args.bidder_pubkey, true),
+        AccountMeta::new(auction_bank_pubkey, false),
+        AccountMeta::new(auction_root_state_pubkey, false),
+        AccountMeta::new(auction_cycle_state_pubkey, false),
+        AccountMeta::new(bid_deposit_pubkey, false),
+        AccountMeta::new(auction_pool_pubkey, false),
+        AccountMeta::new(secondary_pool_pubkey, false),
+        AccountMeta::new_readonly(SYS_ID, false),
+        AccountMeta::new(bid_log_page_pubkey, false),
+        AccountMeta::new(next_bid_log_page_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::PlaceProxyBid {
+        id: args.auction_id,
+        max_amount: args.max_amount,
+    };
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
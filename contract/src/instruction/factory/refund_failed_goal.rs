@@ -0,0 +1,84 @@
+use super::*;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct RefundFailedGoalArgs {
+    pub payer_pubkey: Pubkey,
+    pub bidder_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+    pub cycle_number: u64,
+    pub bid_mint: Option<Pubkey>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendRefundFailedGoalArgs {
+    pub payer_pubkey: String,
+    pub bidder_pubkey: String,
+    pub auction_id: String,
+    pub cycle_number: u64,
+    pub bid_mint: Option<FrontendBidMint>,
+}
+
+impl TryFrom<FrontendRefundFailedGoalArgs> for RefundFailedGoalArgs {
+    type Error = String;
+    fn try_from(args: FrontendRefundFailedGoalArgs) -> Result<Self, Self::Error> {
+        let bid_mint = if let Some(bid_mint) = args.bid_mint.as_ref() {
+            Some(Pubkey::from_str(&bid_mint.mint).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+        Ok(Self {
+            payer_pubkey: Pubkey::from_str(&args.payer_pubkey).map_err(|e| e.to_string())?,
+            bidder_pubkey: Pubkey::from_str(&args.bidder_pubkey).map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id)?,
+            cycle_number: args.cycle_number,
+            bid_mint,
+        })
+    }
+}
+
+pub fn refund_failed_goal(args: &RefundFailedGoalArgs) -> Instruction {
+    let (auction_bank_pubkey, _) =
+        Pubkey::find_program_address(&auction_bank_seeds(&args.auction_id), &crate::ID);
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+    let (auction_cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(&auction_root_state_pubkey, &args.cycle_number.to_le_bytes()),
+        &crate::ID,
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new(args.payer_pubkey, true),
+        AccountMeta::new(args.bidder_pubkey, false),
+        AccountMeta::new(auction_bank_pubkey, false),
+        AccountMeta::new(auction_root_state_pubkey, false),
+        AccountMeta::new(auction_cycle_state_pubkey, false),
+    ];
+
+    if let Some(bid_mint) = args.bid_mint {
+        let (contract_pda, _) = Pubkey::find_program_address(&contract_pda_seeds(), &crate::ID);
+        let (auction_vault_pubkey, _) =
+            Pubkey::find_program_address(&auction_vault_seeds(&args.auction_id), &crate::ID);
+        let (bidder_holding_pubkey, _) = Pubkey::find_program_address(
+            &token_holding_seeds(&bid_mint, &args.bidder_pubkey),
+            &crate::ID,
+        );
+        accounts.extend_from_slice(&[
+            AccountMeta::new_readonly(TOKEN_ID, false),
+            AccountMeta::new_readonly(contract_pda, false),
+            AccountMeta::new(auction_vault_pubkey, false),
+            AccountMeta::new(bidder_holding_pubkey, false),
+        ]);
+    }
+
+    let instruction = AuctionInstruction::RefundFailedGoal {
+        id: args.auction_id,
+        cycle_number: args.cycle_number,
+    };
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct CommitBidArgs {
+    pub bidder_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+    pub cycle_number: u64,
+    pub amount: u64,
+    /// Secret salt that hides the bid amount until it is revealed.
+    pub salt: [u8; 32],
+    pub deposit: u64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendCommitBidArgs {
+    pub bidder_pubkey: String,
+    pub auction_id: String,
+    pub cycle_number: u64,
+    pub amount: Scalar,
+    pub salt: [u8; 32],
+    pub deposit: Scalar,
+}
+
+impl TryFrom<FrontendCommitBidArgs> for CommitBidArgs {
+    type Error = String;
+    fn try_from(args: FrontendCommitBidArgs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            bidder_pubkey: Pubkey::from_str(&args.bidder_pubkey).map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id)?,
+            cycle_number: args.cycle_number,
+            amount: to_lamports(args.amount),
+            salt: args.salt,
+            deposit: to_lamports(args.deposit),
+        })
+    }
+}
+
+pub fn commit_bid(args: &CommitBidArgs) -> Instruction {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+    let (auction_cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(&auction_root_state_pubkey, &args.cycle_number.to_le_bytes()),
+        &crate::ID,
+    );
+    let (bid_commitment_pubkey, _) = Pubkey::find_program_address(
+        &bid_commitment_seeds(&auction_cycle_state_pubkey, &args.bidder_pubkey),
+        &crate::ID,
+    );
+    let (auction_pool_pubkey, _) = Pubkey::find_program_address(&auction_pool_seeds(), &crate::ID);
+    let (secondary_pool_pubkey, _) =
+        Pubkey::find_program_address(&secondary_pool_seeds(), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(args.bidder_pubkey, true),
+        AccountMeta::new_readonly(auction_root_state_pubkey, false),
+        AccountMeta::new(auction_cycle_state_pubkey, false),
+        AccountMeta::new(bid_commitment_pubkey, false),
+        AccountMeta::new(auction_pool_pubkey, false),
+        AccountMeta::new(secondary_pool_pubkey, false),
+        AccountMeta::new_readonly(SYS_ID, false),
+    ];
+
+    let instruction = AuctionInstruction::CommitBid {
+        id: args.auction_id,
+        commitment: BidCommitment::hash(args.amount, &args.salt),
+        deposit: args.deposit,
+    };
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct RevealBidArgs {
+    pub bidder_pubkey: Pubkey,
+    #[alias([u8; 32])]
+    pub auction_id: AuctionId,
+    pub cycle_number: u64,
+    pub amount: u64,
+    pub salt: [u8; 32],
+    /// Page of the cycle's bid log the bid is appended to, see
+    /// [`AuctionCycleState::bid_log_page`](crate::state::AuctionCycleState::bid_log_page).
+    pub bid_log_page: u64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendRevealBidArgs {
+    pub bidder_pubkey: String,
+    pub auction_id: String,
+    pub cycle_number: u64,
+    pub amount: Scalar,
+    pub salt: [u8; 32],
+}
+
+impl TryFrom<FrontendRevealBidArgs> for RevealBidArgs {
+    type Error = String;
+    fn try_from(args: FrontendRevealBidArgs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            bidder_pubkey: Pubkey::from_str(&args.bidder_pubkey).map_err(|e| e.to_string())?,
+            auction_id: pad_to_32_bytes(&args.auction_id)?,
+            cycle_number: args.cycle_number,
+            amount: to_lamports(args.amount),
+            salt: args.salt,
+            bid_log_page: 0,
+        })
+    }
+}
+
+pub fn reveal_bid(args: &RevealBidArgs) -> Instruction {
+    let (auction_bank_pubkey, _) =
+        Pubkey::find_program_address(&auction_bank_seeds(&args.auction_id), &crate::ID);
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+    let (auction_cycle_state_pubkey, _) = Pubkey::find_program_address(
+        &auction_cycle_state_seeds(&auction_root_state_pubkey, &args.cycle_number.to_le_bytes()),
+        &crate::ID,
+    );
+    let (bid_commitment_pubkey, _) = Pubkey::find_program_address(
+        &bid_commitment_seeds(&auction_cycle_state_pubkey, &args.bidder_pubkey),
+        &crate::ID,
+    );
+    let (bid_deposit_pubkey, _) = Pubkey::find_program_address(
+        &bid_deposit_seeds(&auction_cycle_state_pubkey, &args.bidder_pubkey),
+        &crate::ID,
+    );
+    let (bid_log_page_pubkey, _) = Pubkey::find_program_address(
+        &bid_log_page_seeds(
+            &auction_root_state_pubkey,
+            &args.cycle_number.to_le_bytes(),
+            &args.bid_log_page.to_le_bytes(),
+        ),
+        &crate::ID,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(args.bidder_pubkey, true),
+        AccountMeta::new(auction_bank_pubkey, false),
+        AccountMeta::new(auction_root_state_pubkey, false),
+        AccountMeta::new(auction_cycle_state_pubkey, false),
+        AccountMeta::new(bid_commitment_pubkey, false),
+        AccountMeta::new(bid_deposit_pubkey, false),
+        AccountMeta::new_readonly(SYS_ID, false),
+        AccountMeta::new(bid_log_page_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::RevealBid {
+        id: args.auction_id,
+        cycle_number: args.cycle_number,
+        amount: args.amount,
+        salt: args.salt,
+    };
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
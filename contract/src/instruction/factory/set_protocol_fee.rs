@@ -2,7 +2,9 @@ use super::*;
 
 pub struct SetProtocolFeeArgs {
     pub contract_admin_pubkey: Pubkey,
-    pub new_fee: u8,
+    pub new_fee: u16,
+    /// Fee tier of verified auctions (`None` applies the global fee).
+    pub new_verified_fee: Option<u16>,
 }
 
 pub fn set_protocol_fee(args: &SetProtocolFeeArgs) -> Instruction {
@@ -21,6 +23,39 @@ pub fn set_protocol_fee(args: &SetProtocolFeeArgs) -> Instruction {
 
     let instruction = AuctionInstruction::SetProtocolFee {
         new_fee: args.new_fee,
+        new_verified_fee: args.new_verified_fee,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+pub struct SetAuctionProtocolFeeArgs {
+    pub contract_admin_pubkey: Pubkey,
+    pub auction_id: AuctionId,
+    /// Fee override of the auction (`None` removes the override).
+    pub new_fee: Option<u16>,
+}
+
+pub fn set_auction_protocol_fee(args: &SetAuctionProtocolFeeArgs) -> Instruction {
+    let (contract_bank_pubkey, _) =
+        Pubkey::find_program_address(&contract_bank_seeds(), &crate::ID);
+
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&args.auction_id), &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(args.contract_admin_pubkey, true),
+        AccountMeta::new_readonly(contract_bank_pubkey, false),
+        AccountMeta::new(auction_root_state_pubkey, false),
+    ];
+
+    let instruction = AuctionInstruction::SetAuctionProtocolFee {
+        id: args.auction_id,
+        new_fee: args.new_fee,
     };
 
     Instruction {
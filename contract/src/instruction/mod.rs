@@ -2,7 +2,8 @@
 pub mod factory;
 
 use crate::state::{
-    AuctionConfig, AuctionDescription, AuctionId, AuctionName, CreateTokenArgs, ModifyAuctionData,
+    AuctionConfig, AuctionDescription, AuctionId, AuctionName, BeneficiaryVec, BidMemo,
+    CreateTokenArgs, ModifyAuctionData, VestingSchedule,
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::clock::UnixTimestamp;
@@ -26,6 +27,8 @@ pub enum AuctionInstruction {
         description: AuctionDescription,
         create_token_args: CreateTokenArgs,
         auction_start_timestamp: Option<UnixTimestamp>,
+        beneficiaries: BeneficiaryVec,
+        vesting_schedule: Option<VestingSchedule>,
     },
     FilterAuction {
         id: AuctionId,
@@ -41,6 +44,10 @@ pub enum AuctionInstruction {
     Bid {
         id: AuctionId,
         amount: u64,
+        expected_cycle_number: Option<u64>,
+        expected_top_bid: Option<u64>,
+        memo: Option<BidMemo>,
+        referrer: Option<Pubkey>,
     },
     ClaimFunds {
         id: AuctionId,
@@ -49,6 +56,7 @@ pub enum AuctionInstruction {
     ClaimRewards {
         id: AuctionId,
         cycle_number: u64,
+        rank: u8,
     },
     VerifyAuction {
         id: AuctionId,
@@ -63,10 +71,60 @@ pub enum AuctionInstruction {
         new_max_auction_num: u32,
     },
     SetProtocolFee {
-        new_fee: u8,
+        new_fee: u16,
+        new_verified_fee: Option<u16>,
     },
     ModifyAuction {
         id: AuctionId,
         modify_data: ModifyAuctionData,
     },
+    CommitBid {
+        id: AuctionId,
+        commitment: [u8; 32],
+        deposit: u64,
+    },
+    RevealBid {
+        id: AuctionId,
+        cycle_number: u64,
+        amount: u64,
+        salt: [u8; 32],
+    },
+    PlaceProxyBid {
+        id: AuctionId,
+        max_amount: u64,
+    },
+    WithdrawRefund {
+        id: AuctionId,
+        cycle_number: u64,
+    },
+    ClaimReferralRewards,
+    PauseAuction {
+        id: AuctionId,
+    },
+    ResumeAuction {
+        id: AuctionId,
+    },
+    ProposeOwnerTransfer {
+        id: AuctionId,
+        new_owner: Option<Pubkey>,
+    },
+    AcceptOwnerTransfer {
+        id: AuctionId,
+    },
+    RefundFailedGoal {
+        id: AuctionId,
+        cycle_number: u64,
+    },
+    SetAuctionProtocolFee {
+        id: AuctionId,
+        new_fee: Option<u16>,
+    },
+    ProposeContractAdmin {
+        new_contract_admin: Option<Pubkey>,
+    },
+    AcceptContractAdmin,
+    MigrateAuction {
+        id: AuctionId,
+        cycle_number: u64,
+    },
 }
@@ -29,6 +29,16 @@ solana_program::declare_id!("go1dcKcvafq8SDwmBKo6t2NVzyhvTEZJkMwnnfae99U");
 /// Maximum number of [`Bids`](state::BidData) in the
 /// [`BidHistory`](state::BidHistory).
 pub const MAX_BID_HISTORY_LENGTH: usize = 10;
+/// Number of [`Bids`](state::BidData) held by a single
+/// [`BidLogPage`](state::BidLogPage).
+pub const BID_LOG_PAGE_LENGTH: usize = 32;
+/// Maximum number of bytes in a bid memo.
+pub const MAX_BID_MEMO_LEN: usize = 64;
+/// Maximum number of winners in an auction cycle.
+///
+/// Cannot exceed [`MAX_BID_HISTORY_LENGTH`] because the winning bids are held
+/// in the bid history.
+pub const MAX_WINNERS_PER_CYCLE: u8 = 10;
 /// Maximum number of characters in an auction description.
 pub const MAX_DESCRIPTION_LEN: usize = 200;
 /// Maximum number of characters in each social url.
@@ -36,6 +46,8 @@ pub const MAX_SOCIALS_LEN: usize = 100;
 /// Maximum number of socials the [`AuctionState`](state::AuctionRootState) may
 /// hold.
 pub const MAX_SOCIALS_NUM: usize = 5;
+/// Maximum number of [`Beneficiaries`](state::Beneficiary) of an auction.
+pub const MAX_BENEFICIARIES: usize = 5;
 /// Additional bytes allocated to the
 /// [`AuctionRootState`](state::AuctionRootState) account for future
 /// development.
@@ -54,8 +66,12 @@ pub const UNIVERSAL_BID_FLOOR: u64 = 50_000_000;
 pub const MIN_CYCLE_PERIOD: UnixTimestamp = 60; // one minute
 /// Minimum length of an auction cycle period in seconds.
 pub const MAX_CYCLE_PERIOD: UnixTimestamp = 31_557_600; // one year
-/// Default protocol fee in thousandths for all claimed funds.
-pub const DEFAULT_PROTOCOL_FEE: u8 = 50; // 5 %
+/// Number of basis points making up 100%.
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+/// Default protocol fee in basis points for all claimed funds.
+pub const DEFAULT_PROTOCOL_FEE: u16 = 500; // 5 %
+/// Maximum protocol fee in basis points.
+pub const MAX_PROTOCOL_FEE: u16 = 500; // 5 %
 
 /// The recommended number of state accounts that can be safely wiped via a
 /// `DeleteAuction` contract call without exceeding the allotted compute units.
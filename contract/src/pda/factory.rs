@@ -178,3 +178,34 @@ pub fn create_state_account<'a>(
 
     Ok(())
 }
+
+pub fn transfer_tokens<'a>(
+    source_account: &AccountInfo<'a>,
+    destination_account: &AccountInfo<'a>,
+    authority_account: &AccountInfo<'a>,
+    signers_seeds: Vec<&[u8]>,
+    token_program: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let transfer_instruction = token_instruction::transfer(
+        token_program.key,
+        source_account.key,
+        destination_account.key,
+        authority_account.key,
+        &[authority_account.key],
+        amount,
+    )?;
+
+    invoke_signed(
+        &transfer_instruction,
+        &[
+            source_account.clone(),
+            destination_account.clone(),
+            authority_account.clone(),
+            token_program.clone(),
+        ],
+        &[&signers_seeds[..]],
+    )?;
+
+    Ok(())
+}
@@ -23,6 +23,10 @@ pub fn auction_bank_seeds(auction_id: &[u8]) -> [&[u8]; 2] {
     [b"gold_auction_bank", auction_id]
 }
 
+pub fn auction_vault_seeds(auction_id: &[u8]) -> [&[u8]; 2] {
+    [b"gold_auction_vault", auction_id]
+}
+
 pub fn auction_root_state_seeds(auction_id: &[u8]) -> [&[u8]; 2] {
     [b"gold_auction_root_state", auction_id]
 }
@@ -38,10 +42,53 @@ pub fn auction_cycle_state_seeds<'a>(
     ]
 }
 
+pub fn bid_deposit_seeds<'a>(
+    auction_cycle_state_pubkey: &'a Pubkey,
+    bidder_pubkey: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"gold_bid_deposit",
+        auction_cycle_state_pubkey.as_ref(),
+        bidder_pubkey.as_ref(),
+    ]
+}
+
+pub fn referral_state_seeds(referrer_pubkey: &Pubkey) -> [&[u8]; 2] {
+    [b"gold_referral", referrer_pubkey.as_ref()]
+}
+
+pub fn bid_log_page_seeds<'a>(
+    auction_root_state_pubkey: &'a Pubkey,
+    cycle_number_bytes: &'a [u8],
+    page_bytes: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        b"gold_bid_log",
+        auction_root_state_pubkey.as_ref(),
+        cycle_number_bytes,
+        page_bytes,
+    ]
+}
+
+pub fn bid_commitment_seeds<'a>(
+    auction_cycle_state_pubkey: &'a Pubkey,
+    bidder_pubkey: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"gold_bid_commitment",
+        auction_cycle_state_pubkey.as_ref(),
+        bidder_pubkey.as_ref(),
+    ]
+}
+
 pub fn contract_bank_seeds<'a>() -> [&'a [u8]; 1] {
     [b"gold_contract_bank"]
 }
 
+pub fn contract_vault_seeds(mint: &Pubkey) -> [&[u8]; 2] {
+    [b"gold_contract_vault", mint.as_ref()]
+}
+
 pub fn token_mint_seeds(auction_id: &[u8]) -> [&[u8]; 2] {
     [b"gold_token_mint", auction_id]
 }
@@ -0,0 +1,67 @@
+use super::*;
+
+/// Proposes a new contract admin, who has to accept the role before it is
+/// handed over.
+///
+/// A pending proposal is replaced by a new one, or it is cancelled if no new
+/// admin is given.
+pub fn process_propose_contract_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_contract_admin: Option<Pubkey>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let contract_admin_account = next_account_info(account_info_iter)?; // 1
+    let contract_bank_account = next_account_info(account_info_iter)?; // 2
+
+    if !contract_admin_account.is_signer {
+        msg!("admin signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    SignerPda::check_owner(
+        &contract_bank_seeds(),
+        program_id,
+        program_id,
+        contract_bank_account,
+    )?;
+
+    let mut contract_bank_state = ContractBankState::read(contract_bank_account)?;
+    if contract_admin_account.key != &contract_bank_state.contract_admin {
+        return Err(AuctionContractError::ContractAdminMismatch.into());
+    }
+
+    contract_bank_state.pending_contract_admin = new_contract_admin;
+    write_contract_bank_state(contract_bank_account, &contract_bank_state)
+}
+
+/// Hands the contract admin role over to the proposed admin.
+pub fn process_accept_contract_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let new_contract_admin_account = next_account_info(account_info_iter)?; // 1
+    let contract_bank_account = next_account_info(account_info_iter)?; // 2
+
+    if !new_contract_admin_account.is_signer {
+        msg!("new admin signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    SignerPda::check_owner(
+        &contract_bank_seeds(),
+        program_id,
+        program_id,
+        contract_bank_account,
+    )?;
+
+    let mut contract_bank_state = ContractBankState::read(contract_bank_account)?;
+    if contract_bank_state.pending_contract_admin.as_ref() != Some(new_contract_admin_account.key) {
+        return Err(AuctionContractError::PendingAdminMismatch.into());
+    }
+
+    contract_bank_state.contract_admin = *new_contract_admin_account.key;
+    contract_bank_state.pending_contract_admin = None;
+    write_contract_bank_state(contract_bank_account, &contract_bank_state)
+}
@@ -39,7 +39,13 @@ pub fn process_admin_withdraw(
     checked_credit_account(withdraw_authority, amount)?;
     checked_debit_account(contract_bank_account, amount)?;
 
-    Ok(())
+    update_contract_stats(contract_bank_account, |stats| {
+        stats.fees_withdrawn = stats
+            .fees_withdrawn
+            .checked_add(amount)
+            .ok_or(AuctionContractError::ArithmeticError)?;
+        Ok(())
+    })
 }
 
 pub fn process_admin_withdraw_reassign(
@@ -68,7 +74,29 @@ pub fn process_admin_withdraw_reassign(
     }
 
     contract_bank_state.withdraw_authority = new_withdraw_authority;
-    contract_bank_state.write(contract_bank_account)?;
+    write_contract_bank_state(contract_bank_account, &contract_bank_state)
+}
+
+/// Updates the revenue counters held by the contract bank.
+pub fn update_contract_stats<F>(contract_bank_account: &AccountInfo, update: F) -> ProgramResult
+where
+    F: FnOnce(&mut ContractStats) -> Result<(), AuctionContractError>,
+{
+    let mut contract_bank_state = ContractBankState::read(contract_bank_account)?;
+    update(&mut contract_bank_state.stats)?;
+    write_contract_bank_state(contract_bank_account, &contract_bank_state)
+}
 
-    Ok(())
+/// Writes the contract bank state.
+///
+/// The legacy contract bank is resized to hold the revenue counters, the rent
+/// of the extra bytes is covered by the collected fees.
+pub fn write_contract_bank_state(
+    contract_bank_account: &AccountInfo,
+    contract_bank_state: &ContractBankState,
+) -> ProgramResult {
+    if contract_bank_account.data_len() < ContractBankState::MAX_SERIALIZED_LEN {
+        contract_bank_account.realloc(ContractBankState::MAX_SERIALIZED_LEN, false)?;
+    }
+    contract_bank_state.write(contract_bank_account)
 }
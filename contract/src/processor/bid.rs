@@ -1,20 +1,38 @@
 use super::*;
 
+use solana_program::clock::UnixTimestamp;
+
+/// Places a bid on the current cycle of an auction.
+///
+/// The bid may be guarded by the cycle number and the top bid amount (`0` if
+/// there are no bids yet) the bidder expects. If either of them changed
+/// before the transaction landed (e.g. the cycle was closed and the bid would
+/// be placed on the next asset), the bid is rejected.
+///
+/// The optional memo of the bidder is stored alongside the bid, just like the
+/// referrer of the bidder (if any) who is rewarded if the bid wins.
+#[allow(clippy::too_many_arguments)]
 pub fn process_bid(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     auction_id: AuctionId,
     amount: u64,
+    expected_cycle_number: Option<u64>,
+    expected_top_bid: Option<u64>,
+    memo: Option<BidMemo>,
+    referrer: Option<Pubkey>,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
     let user_main_account = next_account_info(account_info_iter)?; // 1
     let auction_bank_account = next_account_info(account_info_iter)?; // 2
     let auction_root_state_account = next_account_info(account_info_iter)?; // 3
     let auction_cycle_state_account = next_account_info(account_info_iter)?; // 4
-    let top_bidder_account = next_account_info(account_info_iter)?; // 5
+    let bid_deposit_account = next_account_info(account_info_iter)?; // 5
     let auction_pool_account = next_account_info(account_info_iter)?; // 6
     let secondary_pool_account = next_account_info(account_info_iter)?; // 7
     let system_program = next_account_info(account_info_iter)?; // 8
+    let bid_log_page_account = next_account_info(account_info_iter)?; // 9
+    let next_bid_log_page_account = next_account_info(account_info_iter)?; // 10
 
     // Check if user is signer
     if !user_main_account.is_signer {
@@ -22,6 +40,16 @@ pub fn process_bid(
     }
     // Check cross-program invocation addresses
     assert_system_program(system_program.key)?;
+    // Memos are not length-checked upon deserialization
+    if memo.as_ref().map_or(false, |memo| {
+        memo.contents().len() > crate::MAX_BID_MEMO_LEN
+    }) {
+        return Err(AuctionContractError::StringTooLong.into());
+    }
+    if referrer.as_ref() == Some(user_main_account.key) {
+        msg!("Bidders cannot refer themselves");
+        return Err(AuctionContractError::InvalidReferrer.into());
+    }
     // Check root and cycle states
     SignerPda::check_owner(
         &auction_root_state_seeds(&auction_id),
@@ -30,7 +58,14 @@ pub fn process_bid(
         auction_root_state_account,
     )?;
 
-    let auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+
+    if let Some(expected_cycle_number) = expected_cycle_number {
+        if expected_cycle_number != auction_root_state.status.current_auction_cycle {
+            msg!("Auction cycle changed since the bid was made");
+            return Err(AuctionContractError::StaleBid.into());
+        }
+    }
 
     let cycle_num = auction_root_state
         .status
@@ -46,6 +81,17 @@ pub fn process_bid(
 
     let mut auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
 
+    if let Some(expected_top_bid) = expected_top_bid {
+        let top_bid_amount = auction_cycle_state
+            .bid_history
+            .get_last_element()
+            .map_or(0, |top_bid| top_bid.bid_amount);
+        if expected_top_bid != top_bid_amount {
+            msg!("Top bid changed since the bid was made");
+            return Err(AuctionContractError::StaleBid.into());
+        }
+    }
+
     // check auction pools
     SignerPda::check_owner(
         &auction_pool_seeds(),
@@ -61,27 +107,21 @@ pub fn process_bid(
         secondary_pool_account,
     )?;
 
-    let mut primary_pool = AuctionPool::read(auction_pool_account)?;
-
     // Check status and bid amount
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp;
 
-    let interaction_type = if primary_pool.pool.binary_search(&auction_id).is_ok() {
-        AuctionInteraction::Bid
-    } else {
-        let mut secondary_pool = AuctionPool::read(secondary_pool_account)?;
-        secondary_pool.remove(&auction_id);
-        primary_pool.try_insert_sorted(auction_id)?;
-
-        primary_pool.write(auction_pool_account)?;
-        secondary_pool.write(secondary_pool_account)?;
-
-        auction_cycle_state.end_time = current_timestamp
-            .checked_add(auction_root_state.auction_config.cycle_period)
-            .ok_or(AuctionContractError::ArithmeticError)?;
-
+    let interaction_type = if activate_auction(
+        &auction_id,
+        auction_pool_account,
+        secondary_pool_account,
+        &mut auction_cycle_state,
+        auction_root_state.auction_config.cycle_period,
+        current_timestamp,
+    )? {
         AuctionInteraction::BidInactive
+    } else {
+        AuctionInteraction::Bid
     };
 
     check_status(
@@ -90,7 +130,13 @@ pub fn process_bid(
         current_timestamp,
         interaction_type,
     )?;
-    check_bid_amount(&auction_root_state, &auction_cycle_state, amount)?;
+    check_bid_amount(
+        &auction_root_state,
+        &auction_cycle_state,
+        user_main_account.key,
+        amount,
+        current_timestamp,
+    )?;
 
     // check auction bank
     SignerPda::check_owner(
@@ -100,55 +146,345 @@ pub fn process_bid(
         auction_bank_account,
     )?;
 
-    let most_recent_bid_option = auction_cycle_state.bid_history.get_last_element();
-    let previous_bid_amount = if let Some(most_recent_bid) = most_recent_bid_option {
-        if top_bidder_account.key != &most_recent_bid.bidder_pubkey {
-            return Err(AuctionContractError::TopBidderAccountMismatch.into());
+    let bid_data = BidData {
+        bid_amount: amount,
+        bidder_pubkey: *user_main_account.key,
+        memo,
+        referrer,
+    };
+
+    let bid_log_page_accounts = [bid_log_page_account, next_bid_log_page_account];
+    log_bid(
+        program_id,
+        user_main_account,
+        &bid_log_page_accounts,
+        auction_root_state_account.key,
+        system_program,
+        &mut auction_cycle_state,
+        auction_root_state.status.current_auction_cycle,
+        bid_data.clone(),
+    )?;
+
+    // Determine the amount to be transferred. Outbid bids are not refunded
+    // here, they can be withdrawn from the auction bank by their bidders.
+    let winners_per_cycle = auction_root_state.auction_config.winners_per_cycle;
+    let transfer_amount =
+        match auction_cycle_state.bid_to_outbid(user_main_account.key, winners_per_cycle) {
+            // Winners raising their own bid only pay the difference
+            Some(own_bid)
+                if winners_per_cycle > 1 && &own_bid.bidder_pubkey == user_main_account.key =>
+            {
+                amount
+                    .checked_sub(own_bid.bid_amount)
+                    .ok_or(AuctionContractError::ArithmeticError)?
+            }
+            _ => amount,
+        };
+
+    // The proxy bid backing the top bid (if any) outbids the new bid
+    // automatically, up to its maximum amount
+    if auction_cycle_state.proxy_bid_max.is_some() {
+        let is_top_bidder = auction_cycle_state
+            .bid_history
+            .get_last_element()
+            .map_or(false, |top_bid| {
+                &top_bid.bidder_pubkey == user_main_account.key
+            });
+        if is_top_bidder {
+            // The top bidder overrides their own proxy bid
+            auction_cycle_state.proxy_bid_max = None;
+        } else {
+            if let Some(counter_bid) = proxy_bid::counter_with_proxy_bid(
+                &mut auction_cycle_state,
+                &auction_root_state.auction_config,
+                bid_data.clone(),
+            )? {
+                log_bid(
+                    program_id,
+                    user_main_account,
+                    &bid_log_page_accounts,
+                    auction_root_state_account.key,
+                    system_program,
+                    &mut auction_cycle_state,
+                    auction_root_state.status.current_auction_cycle,
+                    counter_bid,
+                )?;
+                trigger_encore(
+                    &mut auction_cycle_state,
+                    auction_root_state.auction_config.encore_period,
+                    current_timestamp,
+                )?;
+                auction_cycle_state.write(auction_cycle_state_account)?;
+                return Ok(());
+            }
+        }
+    }
+
+    if let Some(bid_mint) = auction_root_state.auction_config.bid_mint {
+        let token_program = next_account_info(account_info_iter)?; // 11
+        let rent_program = next_account_info(account_info_iter)?; // 12
+        let bid_mint_account = next_account_info(account_info_iter)?; // 13
+        let auction_vault_account = next_account_info(account_info_iter)?; // 14
+        let user_token_account = next_account_info(account_info_iter)?; // 15
+        let user_holding_account = next_account_info(account_info_iter)?; // 16
+
+        // Check cross-program invocation addresses
+        assert_token_program(token_program.key)?;
+        assert_rent_program(rent_program.key)?;
+
+        // Check pda addresses
+        if bid_mint_account.key != &bid_mint {
+            return Err(AuctionContractError::BidMintMismatch.into());
+        }
+
+        SignerPda::check_owner(
+            &auction_vault_seeds(&auction_id),
+            program_id,
+            &TOKEN_ID,
+            auction_vault_account,
+        )?;
+
+        // Create the bidder's holding account (if needed) so that they can
+        // withdraw their bid when outbid
+        let user_holding_seeds = token_holding_seeds(&bid_mint, user_main_account.key);
+        let user_holding_pda =
+            SignerPda::new_checked(&user_holding_seeds, program_id, user_holding_account)?;
+
+        if user_holding_account.data_is_empty() {
+            create_token_holding_account(
+                user_main_account,
+                user_main_account,
+                user_holding_account,
+                bid_mint_account,
+                user_holding_pda.signer_seeds(),
+                system_program,
+                token_program,
+                rent_program,
+            )?;
         }
-        most_recent_bid.bid_amount
+
+        // Transfer tokens to the auction vault
+        let token_transfer_ix = token_instruction::transfer(
+            token_program.key,
+            user_token_account.key,
+            auction_vault_account.key,
+            user_main_account.key,
+            &[user_main_account.key],
+            transfer_amount,
+        )?;
+
+        invoke(
+            &token_transfer_ix,
+            &[
+                user_token_account.to_owned(),
+                auction_vault_account.to_owned(),
+                user_main_account.to_owned(),
+                token_program.to_owned(),
+            ],
+        )?;
     } else {
-        0
-    };
+        // Transfer SOL to fund
+        let lamport_transfer_ix = system_instruction::transfer(
+            user_main_account.key,
+            auction_bank_account.key,
+            transfer_amount,
+        );
 
-    // Transfer SOL to fund
-    let lamport_transfer_ix =
-        system_instruction::transfer(user_main_account.key, auction_bank_account.key, amount);
-
-    invoke(
-        &lamport_transfer_ix,
-        &[
-            user_main_account.to_owned(),
-            auction_bank_account.to_owned(),
-            system_program.to_owned(),
-        ],
+        invoke(
+            &lamport_transfer_ix,
+            &[
+                user_main_account.to_owned(),
+                auction_bank_account.to_owned(),
+                system_program.to_owned(),
+            ],
+        )?;
+    }
+
+    withdraw_refund::record_deposit(
+        program_id,
+        user_main_account,
+        bid_deposit_account,
+        auction_cycle_state_account.key,
+        system_program,
+        &mut auction_root_state,
+        transfer_amount,
     )?;
 
-    // Transfer SOL to previous top bidder
-    if previous_bid_amount > 0 {
-        checked_debit_account(auction_bank_account, previous_bid_amount)?;
-        checked_credit_account(top_bidder_account, previous_bid_amount)?;
+    auction_cycle_state.insert_bid(bid_data, winners_per_cycle)?;
+
+    // Settle the cycle immediately if the bid reaches the buy-now price or
+    // the auction is a Dutch auction
+    if auction_root_state.auction_config.settles_on_bid(amount) {
+        let next_auction_cycle_state_account = next_account_info(account_info_iter)?;
+        let contract_pda = next_account_info(account_info_iter)?;
+        let contract_bank_account = next_account_info(account_info_iter)?;
+
+        auction_cycle_state.end_time = current_timestamp;
+        if auction_root_state.auction_config.second_price_settlement {
+            auction_cycle_state.settled_price = Some(amount);
+        }
+        if auction_root_state.auction_config.all_or_nothing {
+            auction_cycle_state.settlement_records = vec![SettlementRecord {
+                bidder_pubkey: *user_main_account.key,
+                amount,
+            }]
+            .try_into()
+            .map_err(|_| AuctionContractError::InvalidWinnersPerCycle)?;
+        }
+        auction_cycle_state.write(auction_cycle_state_account)?;
+
+        let auction_config = &auction_root_state.auction_config;
+        let referral_rewards = match referrer {
+            Some(referrer) if auction_config.referral_share_bps > 0 => {
+                vec![(referrer, auction_config.referral_reward(amount)?)]
+            }
+            _ => Vec::new(),
+        };
+
+        return close_auction_cycle::settle_cycle(
+            program_id,
+            &auction_id,
+            &close_auction_cycle::CycleSettlementAccounts {
+                payer_account: user_main_account,
+                auction_bank_account,
+                contract_bank_account,
+                auction_pool_account,
+                secondary_pool_account,
+                auction_root_state_account,
+                next_auction_cycle_state_account,
+                contract_pda,
+                system_program,
+            },
+            account_info_iter,
+            &mut auction_root_state,
+            &close_auction_cycle::CycleSettlement {
+                proceeds: amount,
+                number_of_winners: 1,
+                referral_rewards,
+            },
+            current_timestamp,
+        );
     }
 
-    let bid_data = BidData {
-        bid_amount: amount,
-        bidder_pubkey: *user_main_account.key,
+    trigger_encore(
+        &mut auction_cycle_state,
+        auction_root_state.auction_config.encore_period,
+        current_timestamp,
+    )?;
+
+    auction_cycle_state.write(auction_cycle_state_account)?;
+    auction_root_state.write(auction_root_state_account)?;
+
+    Ok(())
+}
+
+/// Appends a bid to the bid log of the cycle, creating a new log page if the
+/// last one is full.
+///
+/// Instructions recording multiple bids pass the page following the current
+/// one as well, in case the current page fills up.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn log_bid<'a>(
+    program_id: &Pubkey,
+    payer_account: &AccountInfo<'a>,
+    bid_log_page_accounts: &[&AccountInfo<'a>],
+    auction_root_state_pubkey: &Pubkey,
+    system_program: &AccountInfo<'a>,
+    auction_cycle_state: &mut AuctionCycleState,
+    cycle_number: u64,
+    bid: BidData,
+) -> ProgramResult {
+    let cycle_number_bytes = cycle_number.to_le_bytes();
+    let page_bytes = auction_cycle_state.bid_log_page().to_le_bytes();
+    let bid_log_page_seeds =
+        bid_log_page_seeds(auction_root_state_pubkey, &cycle_number_bytes, &page_bytes);
+    let (bid_log_page_account, bid_log_page_pda) = bid_log_page_accounts
+        .iter()
+        .find_map(|account| {
+            SignerPda::new_checked(&bid_log_page_seeds, program_id, account)
+                .ok()
+                .map(|pda| (*account, pda))
+        })
+        .ok_or(AuctionContractError::InvalidSeeds)?;
+
+    let mut bid_log_page = if bid_log_page_account.data_is_empty() {
+        create_state_account(
+            payer_account,
+            bid_log_page_account,
+            bid_log_page_pda.signer_seeds(),
+            program_id,
+            system_program,
+            BidLogPage::MAX_SERIALIZED_LEN,
+        )?;
+        BidLogPage {
+            bids: BidLog::new(),
+        }
+    } else {
+        assert_owner(bid_log_page_account, program_id)?;
+        BidLogPage::read(bid_log_page_account)?
     };
 
-    auction_cycle_state.bid_history.cyclic_push(bid_data);
+    bid_log_page
+        .bids
+        .push(bid)
+        .map_err(|_| AuctionContractError::ArithmeticError)?;
+    bid_log_page.write(bid_log_page_account)?;
+
+    auction_cycle_state.bid_count = auction_cycle_state
+        .bid_count
+        .checked_add(1)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    Ok(())
+}
 
+/// Extends the end time of the cycle if a bid is placed within the encore
+/// period.
+pub(super) fn trigger_encore(
+    auction_cycle_state: &mut AuctionCycleState,
+    encore_period: UnixTimestamp,
+    current_timestamp: UnixTimestamp,
+) -> Result<(), AuctionContractError> {
     // Check if auction end time needs to be updated
-    let current_timestamp = clock.unix_timestamp;
     let min_time_for_encore_trigger = auction_cycle_state
         .end_time
-        .checked_sub(auction_root_state.auction_config.encore_period)
+        .checked_sub(encore_period)
         .ok_or(AuctionContractError::ArithmeticError)?;
     if current_timestamp > min_time_for_encore_trigger {
         auction_cycle_state.end_time = current_timestamp
-            .checked_add(auction_root_state.auction_config.encore_period)
+            .checked_add(encore_period)
             .ok_or(AuctionContractError::ArithmeticError)?;
     }
+    Ok(())
+}
 
-    auction_cycle_state.write(auction_cycle_state_account)?;
+/// Moves an inactive auction from the secondary pool to the primary pool and
+/// restarts its current cycle.
+///
+/// Returns `false` if the auction is already in the primary pool.
+pub(super) fn activate_auction(
+    auction_id: &AuctionId,
+    auction_pool_account: &AccountInfo,
+    secondary_pool_account: &AccountInfo,
+    auction_cycle_state: &mut AuctionCycleState,
+    cycle_period: UnixTimestamp,
+    current_timestamp: UnixTimestamp,
+) -> Result<bool, ProgramError> {
+    let mut primary_pool = AuctionPool::read(auction_pool_account)?;
+    if primary_pool.pool.binary_search(auction_id).is_ok() {
+        return Ok(false);
+    }
 
-    Ok(())
+    let mut secondary_pool = AuctionPool::read(secondary_pool_account)?;
+    secondary_pool.remove(auction_id);
+    primary_pool.try_insert_sorted(*auction_id)?;
+
+    primary_pool.write(auction_pool_account)?;
+    secondary_pool.write(secondary_pool_account)?;
+
+    auction_cycle_state.end_time = current_timestamp
+        .checked_add(cycle_period)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    Ok(true)
 }
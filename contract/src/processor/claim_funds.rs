@@ -1,7 +1,9 @@
 use super::*;
-use crate::DEFAULT_PROTOCOL_FEE;
 
+use solana_program::account_info::next_account_infos;
+use solana_program::program_pack::Pack;
 use solana_program::rent::Rent;
+use spl_token::state::Account as TokenAccount;
 
 pub fn process_claim_funds(
     program_id: &Pubkey,
@@ -11,7 +13,7 @@ pub fn process_claim_funds(
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
     let payer_account = next_account_info(account_info_iter)?;
-    let auction_owner_account = next_account_info(account_info_iter)?;
+    let auction_beneficiary_account = next_account_info(account_info_iter)?;
     let auction_bank_account = next_account_info(account_info_iter)?;
     let auction_root_state_account = next_account_info(account_info_iter)?;
     let auction_cycle_state_account = next_account_info(account_info_iter)?;
@@ -40,8 +42,10 @@ pub fn process_claim_funds(
 
     let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
 
-    if auction_owner_account.key != &auction_root_state.auction_owner {
-        return Err(AuctionContractError::AuctionOwnerMismatch.into());
+    // The claimed funds are always paid to the auction beneficiary, so anyone
+    // may pay for the claim
+    if auction_beneficiary_account.key != &auction_root_state.beneficiary {
+        return Err(AuctionContractError::AuctionBeneficiaryMismatch.into());
     }
 
     if auction_root_state.status.is_frozen {
@@ -60,8 +64,6 @@ pub fn process_claim_funds(
         auction_cycle_state_account,
     )?;
 
-    let auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
-
     SignerPda::check_owner(
         &auction_bank_seeds(&auction_id),
         program_id,
@@ -69,75 +71,366 @@ pub fn process_claim_funds(
         auction_bank_account,
     )?;
 
-    let mut lamports_to_claim = **auction_bank_account.lamports.borrow();
+    // Unsettled deposits (i.e. current bids and bids to be withdrawn by their
+    // bidders) and escrowed proceeds of all-or-nothing auctions cannot be
+    // claimed
+    let unclaimable_funds = auction_root_state
+        .unsettled_deposits
+        .checked_add(auction_root_state.escrowed_funds)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    // The claims are capped by the funds vested so far (if there is a vesting
+    // schedule)
+    let vested_unclaimed_funds =
+        auction_root_state.vested_unclaimed_funds(Clock::get()?.unix_timestamp);
+    let protocol_fee =
+        effective_protocol_fee(program_id, protocol_fee_state_account, &auction_root_state)?;
+    let beneficiaries = auction_root_state.beneficiaries.contents();
+
+    if let Some(bid_mint) = auction_root_state.auction_config.bid_mint {
+        let token_program = next_account_info(account_info_iter)?;
+        let rent_program = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let contract_pda = next_account_info(account_info_iter)?;
+        let bid_mint_account = next_account_info(account_info_iter)?;
+        let auction_vault_account = next_account_info(account_info_iter)?;
+        let auction_beneficiary_holding_account = next_account_info(account_info_iter)?;
+        let contract_vault_account = next_account_info(account_info_iter)?;
+        let beneficiary_accounts =
+            BeneficiaryAccounts::parse(account_info_iter, beneficiaries, true)?;
+
+        // Check cross-program invocation addresses
+        assert_token_program(token_program.key)?;
+        assert_rent_program(rent_program.key)?;
+        assert_system_program(system_program.key)?;
+
+        if bid_mint_account.key != &bid_mint {
+            return Err(AuctionContractError::BidMintMismatch.into());
+        }
+
+        SignerPda::check_owner(
+            &auction_vault_seeds(&auction_id),
+            program_id,
+            &TOKEN_ID,
+            auction_vault_account,
+        )?;
+
+        let mut tokens_to_claim =
+            TokenAccount::unpack_from_slice(&auction_vault_account.data.borrow())?
+                .amount
+                .checked_sub(unclaimable_funds)
+                .ok_or(AuctionContractError::ArithmeticError)?;
+        if let Some(vested_funds) = vested_unclaimed_funds {
+            tokens_to_claim = tokens_to_claim.min(vested_funds);
+        }
+
+        if amount > tokens_to_claim {
+            return Err(AuctionContractError::InvalidClaimAmount.into());
+        }
 
-    // If the auction is not active, the bank account does not need to persist
-    // anymore. Otherwise (i.e. !is_finished), leave the rent
-    let rent = Rent::get()?.minimum_balance(0);
-    if !auction_root_state.status.is_finished {
-        lamports_to_claim = lamports_to_claim
-            .checked_sub(rent)
+        create_token_payout_accounts(
+            payer_account,
+            auction_beneficiary_account,
+            auction_beneficiary_holding_account,
+            &beneficiary_accounts,
+            contract_pda,
+            contract_vault_account,
+            bid_mint_account,
+            program_id,
+            system_program,
+            token_program,
+            rent_program,
+        )?;
+
+        claim_tokens(
+            amount,
+            auction_beneficiary_holding_account,
+            beneficiaries,
+            &beneficiary_accounts,
+            auction_vault_account,
+            contract_vault_account,
+            protocol_fee,
+            contract_pda,
+            program_id,
+            token_program,
+        )?;
+    } else {
+        let beneficiary_accounts =
+            BeneficiaryAccounts::parse(account_info_iter, beneficiaries, false)?;
+        let mut lamports_to_claim = (**auction_bank_account.lamports.borrow())
+            .checked_sub(unclaimable_funds)
             .ok_or(AuctionContractError::ArithmeticError)?;
-        // Current bid cannot be claimed until the end of the auction cycle, unless
-        // it's the last one
-        if let Some(most_recent_bid) = auction_cycle_state.bid_history.get_last_element() {
+
+        // If the auction is not active and there is nothing left to withdraw,
+        // the bank account does not need to persist anymore. Otherwise, leave
+        // the rent
+        if !auction_root_state.status.is_finished || unclaimable_funds != 0 {
+            let rent = Rent::get()?.minimum_balance(0);
             lamports_to_claim = lamports_to_claim
-                .checked_sub(most_recent_bid.bid_amount)
+                .checked_sub(rent)
                 .ok_or(AuctionContractError::ArithmeticError)?;
         }
-    }
+        if let Some(vested_funds) = vested_unclaimed_funds {
+            lamports_to_claim = lamports_to_claim.min(vested_funds);
+        }
 
-    if amount > lamports_to_claim {
-        return Err(AuctionContractError::InvalidClaimAmount.into());
-    }
+        if amount > lamports_to_claim {
+            return Err(AuctionContractError::InvalidClaimAmount.into());
+        }
 
-    claim_lamports(
-        amount,
-        auction_owner_account,
-        auction_bank_account,
-        contract_bank_account,
-        protocol_fee_state_account,
-    )?;
+        claim_lamports(
+            amount,
+            auction_beneficiary_account,
+            beneficiaries,
+            &beneficiary_accounts,
+            auction_bank_account,
+            contract_bank_account,
+            protocol_fee,
+        )?;
+    }
 
-    // Update available funds in the root state
+    // Update available and claimed funds in the root state
     auction_root_state.available_funds = auction_root_state
         .available_funds
         .checked_sub(amount)
         .ok_or(AuctionContractError::ArithmeticError)?;
+    auction_root_state.claimed_funds = auction_root_state
+        .claimed_funds
+        .checked_add(amount)
+        .ok_or(AuctionContractError::ArithmeticError)?;
 
     auction_root_state.write(auction_root_state_account)
 }
 
+/// Accounts of the beneficiaries receiving their shares of the claimed funds.
+pub struct BeneficiaryAccounts<'a, 'b> {
+    /// Main accounts of the beneficiaries (in the order of the beneficiaries).
+    pub main_accounts: &'a [AccountInfo<'b>],
+    /// Token holding accounts of the beneficiaries (in case of token bids).
+    pub holding_accounts: &'a [AccountInfo<'b>],
+}
+
+impl<'a, 'b> BeneficiaryAccounts<'a, 'b> {
+    /// Parses the main accounts of the beneficiaries followed by their token
+    /// holding accounts (in case of token bids).
+    pub fn parse(
+        account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+        beneficiaries: &[Beneficiary],
+        is_token_payout: bool,
+    ) -> Result<Self, ProgramError> {
+        let main_accounts = next_account_infos(account_info_iter, beneficiaries.len())?;
+        for (account, beneficiary) in main_accounts.iter().zip(beneficiaries) {
+            if account.key != &beneficiary.pubkey {
+                return Err(AuctionContractError::BeneficiaryMismatch.into());
+            }
+        }
+        let holding_accounts = if is_token_payout {
+            next_account_infos(account_info_iter, beneficiaries.len())?
+        } else {
+            &[]
+        };
+        Ok(Self {
+            main_accounts,
+            holding_accounts,
+        })
+    }
+}
+
+/// Transfers the claimed lamports from the auction bank to the beneficiaries
+/// and the recipient of the remainder (the auction beneficiary when claiming,
+/// the auction owner when deleting), while the protocol fee is sent to the
+/// contract bank and added to its revenue counters.
 pub fn claim_lamports(
     amount: u64,
-    auction_owner_account: &AccountInfo<'_>,
+    recipient_account: &AccountInfo<'_>,
+    beneficiaries: &[Beneficiary],
+    beneficiary_accounts: &BeneficiaryAccounts<'_, '_>,
     auction_bank_account: &AccountInfo<'_>,
     contract_bank_account: &AccountInfo<'_>,
-    protocol_fee_state_account: &AccountInfo<'_>,
+    protocol_fee: u16,
 ) -> Result<(), ProgramError> {
-    let fee_state =
-        ProtocolFeeState::read(protocol_fee_state_account).unwrap_or(ProtocolFeeState {
-            fee: DEFAULT_PROTOCOL_FEE,
-        });
-
-    let mut fee_float: f64 = fee_state.fee.into();
-    // convert into multiplier from thousandths
-    fee_float /= 1_000.0;
-
-    // This may not be precise because of integer rounding but it is more simple
-    let amount_float = amount as f64;
-    let contract_bank_share = (amount_float * fee_float) as u64;
-    let auction_owner_share = amount
+    let contract_bank_share = protocol_fee_share(amount, protocol_fee);
+    let payout = amount
         .checked_sub(contract_bank_share)
         .ok_or(AuctionContractError::ArithmeticError)?;
+    let (beneficiary_shares, recipient_share) = split_among_beneficiaries(beneficiaries, payout)?;
 
+    checked_debit_account(auction_bank_account, amount)?;
     checked_credit_account(contract_bank_account, contract_bank_share)?;
-    checked_credit_account(auction_owner_account, auction_owner_share)?;
+    for (account, share) in beneficiary_accounts
+        .main_accounts
+        .iter()
+        .zip(beneficiary_shares)
+    {
+        checked_credit_account(account, share)?;
+    }
+    checked_credit_account(recipient_account, recipient_share)?;
 
-    checked_debit_account(
-        auction_bank_account,
-        auction_owner_share + contract_bank_share,
-    )?;
+    update_contract_stats(contract_bank_account, |stats| {
+        stats.fees_collected = stats
+            .fees_collected
+            .checked_add(contract_bank_share)
+            .ok_or(AuctionContractError::ArithmeticError)?;
+        Ok(())
+    })
+}
+
+/// Transfers the claimed tokens from the auction vault to the holding accounts
+/// of the beneficiaries and the recipient of the remainder, while the protocol
+/// fee is sent to the contract vault of the bid mint.
+///
+/// The token fees are not added to the contract stats, since those count
+/// lamports only.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_tokens<'a>(
+    amount: u64,
+    recipient_holding_account: &AccountInfo<'a>,
+    beneficiaries: &[Beneficiary],
+    beneficiary_accounts: &BeneficiaryAccounts<'_, 'a>,
+    auction_vault_account: &AccountInfo<'a>,
+    contract_vault_account: &AccountInfo<'a>,
+    protocol_fee: u16,
+    contract_pda: &AccountInfo<'a>,
+    program_id: &Pubkey,
+    token_program: &AccountInfo<'a>,
+) -> Result<(), ProgramError> {
+    let contract_vault_share = protocol_fee_share(amount, protocol_fee);
+    let payout = amount
+        .checked_sub(contract_vault_share)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    let (beneficiary_shares, recipient_share) = split_among_beneficiaries(beneficiaries, payout)?;
+
+    let contract_pda_seeds = contract_pda_seeds();
+    let contract_signer_pda =
+        SignerPda::new_checked(&contract_pda_seeds, program_id, contract_pda)?;
+
+    if contract_vault_share > 0 {
+        transfer_tokens(
+            auction_vault_account,
+            contract_vault_account,
+            contract_pda,
+            contract_signer_pda.signer_seeds(),
+            token_program,
+            contract_vault_share,
+        )?;
+    }
+
+    for (holding_account, share) in beneficiary_accounts
+        .holding_accounts
+        .iter()
+        .zip(beneficiary_shares)
+    {
+        if share > 0 {
+            transfer_tokens(
+                auction_vault_account,
+                holding_account,
+                contract_pda,
+                contract_signer_pda.signer_seeds(),
+                token_program,
+                share,
+            )?;
+        }
+    }
+
+    if recipient_share > 0 {
+        transfer_tokens(
+            auction_vault_account,
+            recipient_holding_account,
+            contract_pda,
+            contract_signer_pda.signer_seeds(),
+            token_program,
+            recipient_share,
+        )?;
+    }
 
     Ok(())
 }
+
+/// Checks the accounts receiving claimed tokens and creates them if they do
+/// not exist yet.
+#[allow(clippy::too_many_arguments)]
+pub fn create_token_payout_accounts<'a>(
+    payer_account: &AccountInfo<'a>,
+    recipient_account: &AccountInfo<'a>,
+    recipient_holding_account: &AccountInfo<'a>,
+    beneficiary_accounts: &BeneficiaryAccounts<'_, 'a>,
+    contract_pda: &AccountInfo<'a>,
+    contract_vault_account: &AccountInfo<'a>,
+    bid_mint_account: &AccountInfo<'a>,
+    program_id: &Pubkey,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    rent_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let holders = std::iter::once((recipient_account, recipient_holding_account)).chain(
+        beneficiary_accounts
+            .main_accounts
+            .iter()
+            .zip(beneficiary_accounts.holding_accounts),
+    );
+    for (holder_account, holding_account) in holders {
+        let holding_seeds = token_holding_seeds(bid_mint_account.key, holder_account.key);
+        let holding_pda = SignerPda::new_checked(&holding_seeds, program_id, holding_account)?;
+
+        if holding_account.data_is_empty() {
+            create_token_holding_account(
+                payer_account,
+                holder_account,
+                holding_account,
+                bid_mint_account,
+                holding_pda.signer_seeds(),
+                system_program,
+                token_program,
+                rent_program,
+            )?;
+        }
+    }
+
+    let contract_vault_seeds = contract_vault_seeds(bid_mint_account.key);
+    let contract_vault_pda =
+        SignerPda::new_checked(&contract_vault_seeds, program_id, contract_vault_account)?;
+
+    if contract_vault_account.data_is_empty() {
+        create_token_holding_account(
+            payer_account,
+            contract_pda,
+            contract_vault_account,
+            bid_mint_account,
+            contract_vault_pda.signer_seeds(),
+            system_program,
+            token_program,
+            rent_program,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Returns the protocol fee collected on the funds claimed from the auction
+/// (in basis points).
+///
+/// The default protocol fee applies if the protocol fee state is not
+/// initialized.
+pub fn effective_protocol_fee(
+    program_id: &Pubkey,
+    protocol_fee_state_account: &AccountInfo,
+    auction_root_state: &AuctionRootState,
+) -> Result<u16, ProgramError> {
+    let protocol_fee_state_seeds = protocol_fee_state_seeds();
+    let protocol_fee_state = if protocol_fee_state_account.data_is_empty() {
+        SignerPda::new_checked(
+            &protocol_fee_state_seeds,
+            program_id,
+            protocol_fee_state_account,
+        )?;
+        ProtocolFeeState::default()
+    } else {
+        SignerPda::check_owner(
+            &protocol_fee_state_seeds,
+            program_id,
+            program_id,
+            protocol_fee_state_account,
+        )?;
+        ProtocolFeeState::read(protocol_fee_state_account)?
+    };
+    Ok(protocol_fee_state.effective_fee(auction_root_state))
+}
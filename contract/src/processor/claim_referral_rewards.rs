@@ -0,0 +1,82 @@
+use super::*;
+
+/// Transfers the referral rewards accrued by the referrer to their account.
+pub fn process_claim_referral_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let referrer_account = next_account_info(account_info_iter)?; // 1
+    let referral_state_account = next_account_info(account_info_iter)?; // 2
+
+    if !referrer_account.is_signer {
+        msg!("referrer signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    SignerPda::check_owner(
+        &referral_state_seeds(referrer_account.key),
+        program_id,
+        program_id,
+        referral_state_account,
+    )?;
+
+    let mut referral_state = ReferralState::read(referral_state_account)?;
+    if referral_state.accrued_rewards == 0 {
+        return Err(AuctionContractError::NothingToWithdraw.into());
+    }
+
+    checked_debit_account(referral_state_account, referral_state.accrued_rewards)?;
+    checked_credit_account(referrer_account, referral_state.accrued_rewards)?;
+
+    referral_state.accrued_rewards = 0;
+    referral_state.write(referral_state_account)
+}
+
+/// Pays a referral reward from the auction bank to the referral state account
+/// of the referrer, creating the account if needed.
+pub(super) fn pay_referral_reward<'a>(
+    program_id: &Pubkey,
+    payer_account: &AccountInfo<'a>,
+    auction_bank_account: &AccountInfo<'a>,
+    referral_state_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    referrer_pubkey: &Pubkey,
+    reward: u64,
+) -> ProgramResult {
+    let referral_state_seeds = referral_state_seeds(referrer_pubkey);
+    let referral_state_pda =
+        SignerPda::new_checked(&referral_state_seeds, program_id, referral_state_account)?;
+
+    let mut referral_state = if referral_state_account.data_is_empty() {
+        create_state_account(
+            payer_account,
+            referral_state_account,
+            referral_state_pda.signer_seeds(),
+            program_id,
+            system_program,
+            ReferralState::MAX_SERIALIZED_LEN,
+        )?;
+        ReferralState {
+            referrer_pubkey: *referrer_pubkey,
+            accrued_rewards: 0,
+            all_time_rewards: 0,
+        }
+    } else {
+        assert_owner(referral_state_account, program_id)?;
+        ReferralState::read(referral_state_account)?
+    };
+
+    checked_debit_account(auction_bank_account, reward)?;
+    checked_credit_account(referral_state_account, reward)?;
+
+    referral_state.accrued_rewards = referral_state
+        .accrued_rewards
+        .checked_add(reward)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    referral_state.all_time_rewards = referral_state
+        .all_time_rewards
+        .checked_add(reward)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    referral_state.write(referral_state_account)
+}
@@ -7,14 +7,13 @@ const METADATA_DATA_START_POS: usize = 65;
 
 // NOTE: The user can be made to pay for this account's creation by locking its fee besides their bid at the time of bidding
 //   and using this locked fee now.
-// NOTE: With the current calculation method we may scam the auction owner with at most 19 lamports due to rounding.
-//   This may be improved.
 
 // NOTE: We might introduce a "grace period" in which the user can not bid before initiating a new auction
 //   in case they wanted to bid in the last second, so that they do not bid on the next auctioned asset accidentally
 /// Closes auction cycle
 ///
-/// Creates holding account for the won asset for the user with the highest bid.
+/// Creates holding account for the won asset for the user with the winning bid
+/// of the given rank (the top bid has rank 0).
 /// The cost of this account's creation is deducted from the highest bid.
 ///
 /// Then, distributes the deducted highest bid in the following fashion:
@@ -27,6 +26,7 @@ pub fn process_claim_rewards(
     accounts: &[AccountInfo],
     auction_id: AuctionId,
     cycle_number: u64,
+    rank: u8,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
     // misc
@@ -96,21 +96,24 @@ pub fn process_claim_rewards(
     if current_timestamp < auction_cycle_state.end_time {
         return Err(AuctionContractError::AuctionIsInProgress.into());
     }
-    if auction_cycle_state.end_time == 0 {
+    if auction_cycle_state.is_reward_claimed(rank) {
         return Err(AuctionContractError::RewardAlreadyClaimed.into());
     }
-
-    // Check top bidder account
-    let most_recent_bid_option = auction_cycle_state.bid_history.get_last_element();
-    if let Some(most_recent_bid) = most_recent_bid_option {
-        if top_bidder_account.key != &most_recent_bid.bidder_pubkey {
-            return Err(AuctionContractError::TopBidderAccountMismatch.into());
-        }
-    } else {
+    if auction_cycle_state.bid_history.is_empty() {
         return Err(AuctionContractError::AuctionIsInProgress.into());
     }
 
     let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    let winners_per_cycle = auction_root_state.auction_config.winners_per_cycle;
+
+    // Check winner account
+    let winning_bid = auction_cycle_state
+        .winning_bid(rank, winners_per_cycle)
+        .ok_or(AuctionContractError::InvalidRewardRank)?;
+    if top_bidder_account.key != &winning_bid.bidder_pubkey {
+        return Err(AuctionContractError::TopBidderAccountMismatch.into());
+    }
+
     match auction_root_state.token_config {
         TokenConfig::Nft(_) => {
             let metadata_program = next_account_info(account_info_iter)?;
@@ -149,11 +152,14 @@ pub fn process_claim_rewards(
             // child_edition_marker_account
             // master_edition_account
             // master_metadata_account
-            let child_mint_seeds = child_mint_seeds(&cycle_num_bytes, &auction_id);
+            let edition = edition_number(cycle_number, rank, winners_per_cycle)
+                .ok_or(AuctionContractError::ArithmeticError)?;
+            let edition_bytes = edition.to_le_bytes();
+            let child_mint_seeds = child_mint_seeds(&edition_bytes, &auction_id);
             let child_mint_pda =
                 SignerPda::new_checked(&child_mint_seeds, program_id, child_mint_account)?;
 
-            let child_holding_seeds = child_holding_seeds(&cycle_num_bytes, &auction_id);
+            let child_holding_seeds = child_holding_seeds(&edition_bytes, &auction_id);
             let child_holding_pda =
                 SignerPda::new_checked(&child_holding_seeds, program_id, child_holding_account)?;
 
@@ -263,7 +269,7 @@ pub fn process_claim_rewards(
                 *contract_pda.key,
                 *master_metadata_account.key,
                 *master_mint_account.key,
-                cycle_number,
+                edition,
             );
 
             invoke_signed(
@@ -368,7 +374,7 @@ pub fn process_claim_rewards(
         }
     }
 
-    auction_cycle_state.end_time = 0;
+    auction_cycle_state.set_reward_claimed(rank);
     auction_cycle_state.write(auction_cycle_state_account)?;
 
     auction_root_state.unclaimed_rewards = auction_root_state
@@ -10,11 +10,9 @@ const METADATA_DATA_START_POS: usize = 65;
 
 // NOTE: The user can be made to pay for this account's creation by locking its fee besides their bid at the time of bidding
 //   and using this locked fee now.
-// NOTE: With the current calculation method we may scam the auction owner with at most 19 lamports due to rounding.
-//   This may be improved.
 
-// NOTE: We might introduce a "grace period" in which the user can not bid before initiating a new auction
-//   in case they wanted to bid in the last second, so that they do not bid on the next auctioned asset accidentally
+// NOTE: Users bidding in the last second might bid on the next auctioned asset accidentally. Bids
+//   guarded by the expected cycle number are rejected in this case (see `process_bid`)
 /// Closes auction cycle
 ///
 /// Creates holding account for the won asset for the user with the highest bid.
@@ -40,6 +38,8 @@ pub fn close_auction_cycle(
     let auction_root_state_account = next_account_info(account_info_iter)?;
     let current_auction_cycle_state_account = next_account_info(account_info_iter)?;
     let next_auction_cycle_state_account = next_account_info(account_info_iter)?;
+    let auction_bank_account = next_account_info(account_info_iter)?;
+    let contract_bank_account = next_account_info(account_info_iter)?;
 
     // user accounts
     let top_bidder_account = next_account_info(account_info_iter)?;
@@ -106,22 +106,75 @@ pub fn close_auction_cycle(
         AuctionInteraction::CloseCycle,
     )?;
 
-    // If there were no bids, just reset auction cycle
-    let most_recent_bid_option = current_auction_cycle_state.bid_history.get_last_element();
-    if let Some(most_recent_bid) = most_recent_bid_option {
+    let most_recent_bid_option = current_auction_cycle_state
+        .bid_history
+        .get_last_element()
+        .cloned();
+    if let Some(ref most_recent_bid) = most_recent_bid_option {
         if top_bidder_account.key != &most_recent_bid.bidder_pubkey {
             return Err(AuctionContractError::TopBidderAccountMismatch.into());
         }
-        auction_root_state.available_funds = auction_root_state
-            .available_funds
-            .checked_add(most_recent_bid.bid_amount)
-            .ok_or(AuctionContractError::ArithmeticError)?;
+    }
 
-        auction_root_state.all_time_treasury = auction_root_state
-            .all_time_treasury
-            .checked_add(most_recent_bid.bid_amount)
+    // If there were no bids (or the top bid did not reach the reserve price),
+    // just reset auction cycle
+    let winning_bid_option = most_recent_bid_option.as_ref().filter(|bid| {
+        auction_root_state
+            .auction_config
+            .is_reserve_met(bid.bid_amount)
+    });
+    let auction_config = &auction_root_state.auction_config;
+    let (settlement, settlement_records) = if let Some(most_recent_bid) = winning_bid_option {
+        // Prices paid by the winners
+        let payments = if auction_config.second_price_settlement {
+            // The winner only pays the clearing price, the rest can be
+            // withdrawn by them
+            let second_bid_amount = current_auction_cycle_state
+                .bid_history
+                .contents()
+                .iter()
+                .rev()
+                .nth(1)
+                .map(|bid| bid.bid_amount);
+            let clearing_price =
+                auction_config.clearing_price(most_recent_bid.bid_amount, second_bid_amount)?;
+            vec![(most_recent_bid, clearing_price)]
+        } else {
+            // Each of the top bids wins in case of multiple winners per cycle
+            current_auction_cycle_state
+                .winning_bids(auction_config.winners_per_cycle)
+                .iter()
+                .map(|bid| (bid, bid.bid_amount))
+                .collect::<Vec<_>>()
+        };
+        let proceeds = payments
+            .iter()
+            .try_fold(0_u64, |sum, (_, price)| sum.checked_add(*price))
             .ok_or(AuctionContractError::ArithmeticError)?;
-        auction_root_state.write(auction_root_state_account)?;
+        let referral_rewards = if auction_config.referral_share_bps > 0 {
+            payments
+                .iter()
+                .filter_map(|(bid, price)| bid.referrer.map(|referrer| (referrer, *price)))
+                .map(|(referrer, price)| Ok((referrer, auction_config.referral_reward(price)?)))
+                .collect::<Result<Vec<_>, AuctionContractError>>()?
+        } else {
+            Vec::new()
+        };
+        let settlement_records = payments
+            .iter()
+            .map(|(bid, price)| SettlementRecord {
+                bidder_pubkey: bid.bidder_pubkey,
+                amount: *price,
+            })
+            .collect::<Vec<_>>();
+        (
+            CycleSettlement {
+                proceeds,
+                number_of_winners: payments.len() as u16,
+                referral_rewards,
+            },
+            settlement_records,
+        )
     } else {
         // check pool pdas
         SignerPda::check_owner(
@@ -138,6 +191,14 @@ pub fn close_auction_cycle(
             secondary_pool_account,
         )?;
 
+        // Release the top bid that did not reach the reserve price, so that
+        // it can be withdrawn by the bidder
+        if most_recent_bid_option.is_some() {
+            msg!("Reserve price not met, releasing top bid");
+            current_auction_cycle_state.bid_history = BidHistory::new();
+            current_auction_cycle_state.proxy_bid_max = None;
+        }
+
         increment_idle_streak(
             &auction_id,
             &mut current_auction_cycle_state,
@@ -148,6 +209,133 @@ pub fn close_auction_cycle(
             secondary_pool_account,
         )?;
         return Ok(());
+    };
+
+    // Record the price paid by the winner of a second-price auction, so that
+    // only the rest of their bid can be withdrawn
+    if auction_root_state.auction_config.second_price_settlement {
+        current_auction_cycle_state.settled_price = Some(settlement.proceeds);
+    }
+    // Record the payments of the winners, so that they can be refunded if the
+    // funding goal of an all-or-nothing auction is not met
+    if auction_root_state.auction_config.all_or_nothing {
+        current_auction_cycle_state.settlement_records = settlement_records
+            .try_into()
+            .map_err(|_| AuctionContractError::InvalidWinnersPerCycle)?;
+    }
+    current_auction_cycle_state.write(current_auction_cycle_state_account)?;
+
+    settle_cycle(
+        program_id,
+        &auction_id,
+        &CycleSettlementAccounts {
+            payer_account,
+            auction_bank_account,
+            contract_bank_account,
+            auction_pool_account,
+            secondary_pool_account,
+            auction_root_state_account,
+            next_auction_cycle_state_account,
+            contract_pda,
+            system_program,
+        },
+        account_info_iter,
+        &mut auction_root_state,
+        &settlement,
+        current_timestamp,
+    )
+}
+
+/// Accounts required to settle an auction cycle.
+pub(super) struct CycleSettlementAccounts<'a, 'b> {
+    pub payer_account: &'a AccountInfo<'b>,
+    pub auction_bank_account: &'a AccountInfo<'b>,
+    pub contract_bank_account: &'a AccountInfo<'b>,
+    pub auction_pool_account: &'a AccountInfo<'b>,
+    pub secondary_pool_account: &'a AccountInfo<'b>,
+    pub auction_root_state_account: &'a AccountInfo<'b>,
+    pub next_auction_cycle_state_account: &'a AccountInfo<'b>,
+    pub contract_pda: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+}
+
+/// Payments of the winners of an auction cycle.
+pub(super) struct CycleSettlement {
+    /// Total amount paid by the winners.
+    pub proceeds: u64,
+    pub number_of_winners: u16,
+    /// Referrers of the winning bids and their rewards paid from the
+    /// proceeds (in ascending order of the bids).
+    pub referral_rewards: Vec<(Pubkey, u64)>,
+}
+
+/// Settles the current auction cycle and starts the next one (unless the last
+/// cycle was settled).
+///
+/// The accounts of the auctioned master edition are parsed from
+/// `account_info_iter` in case of NFT auctions, followed by the referral state
+/// account of each rewarded referrer.
+pub(super) fn settle_cycle<'a, 'b>(
+    program_id: &Pubkey,
+    auction_id: &AuctionId,
+    accounts: &CycleSettlementAccounts<'a, 'b>,
+    account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+    auction_root_state: &mut AuctionRootState,
+    settlement: &CycleSettlement,
+    current_timestamp: UnixTimestamp,
+) -> ProgramResult {
+    let CycleSettlementAccounts {
+        payer_account,
+        auction_bank_account,
+        contract_bank_account,
+        auction_pool_account,
+        secondary_pool_account,
+        auction_root_state_account,
+        next_auction_cycle_state_account,
+        contract_pda,
+        system_program,
+    } = *accounts;
+
+    let CycleSettlement {
+        proceeds,
+        number_of_winners,
+        ref referral_rewards,
+    } = *settlement;
+
+    // Referral rewards are paid from the proceeds
+    let total_referral_rewards = referral_rewards
+        .iter()
+        .try_fold(0_u64, |sum, (_, reward)| sum.checked_add(*reward))
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    auction_root_state.available_funds = auction_root_state
+        .available_funds
+        .checked_add(proceeds)
+        .and_then(|funds| funds.checked_sub(total_referral_rewards))
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    // The proceeds cannot be withdrawn by the winners anymore
+    auction_root_state.unsettled_deposits = auction_root_state
+        .unsettled_deposits
+        .checked_sub(proceeds)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    auction_root_state.all_time_treasury = auction_root_state
+        .all_time_treasury
+        .checked_add(proceeds)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    // The proceeds of all-or-nothing auctions are held in escrow until the
+    // funding goal is met
+    if auction_root_state.auction_config.all_or_nothing {
+        auction_root_state.escrowed_funds = if auction_root_state.is_goal_reached() {
+            0
+        } else {
+            auction_root_state
+                .escrowed_funds
+                .checked_add(proceeds)
+                .ok_or(AuctionContractError::ArithmeticError)?
+        };
     }
 
     let contract_pda_seeds = contract_pda_seeds();
@@ -179,14 +367,14 @@ pub fn close_auction_cycle(
         let next_edition = auction_root_state.status.current_auction_cycle;
 
         SignerPda::check_owner(
-            &master_mint_seeds(&auction_id),
+            &master_mint_seeds(auction_id),
             program_id,
             &TOKEN_ID,
             master_mint_account,
         )?;
 
         SignerPda::check_owner(
-            &master_holding_seeds(&auction_id),
+            &master_holding_seeds(auction_id),
             program_id,
             &TOKEN_ID,
             master_holding_account,
@@ -219,7 +407,7 @@ pub fn close_auction_cycle(
 
             increment_uri(
                 &mut new_master_metadata.uri,
-                is_last_auction_cycle(&auction_root_state),
+                is_last_auction_cycle(auction_root_state),
             )?;
 
             let change_master_metadata_ix = meta_instruction::update_metadata_accounts(
@@ -239,8 +427,29 @@ pub fn close_auction_cycle(
         }
     }
 
+    if !referral_rewards.is_empty() {
+        SignerPda::check_owner(
+            &auction_bank_seeds(auction_id),
+            program_id,
+            program_id,
+            auction_bank_account,
+        )?;
+    }
+    for (referrer, reward) in referral_rewards.iter() {
+        let referral_state_account = next_account_info(account_info_iter)?;
+        claim_referral_rewards::pay_referral_reward(
+            program_id,
+            payer_account,
+            auction_bank_account,
+            referral_state_account,
+            system_program,
+            referrer,
+            *reward,
+        )?;
+    }
+
     // Reset auction cycle
-    if is_last_auction_cycle(&auction_root_state) {
+    if is_last_auction_cycle(auction_root_state) {
         // check pool pdas
         SignerPda::check_owner(
             &auction_pool_seeds(),
@@ -256,15 +465,33 @@ pub fn close_auction_cycle(
             secondary_pool_account,
         )?;
 
+        SignerPda::check_owner(
+            &contract_bank_seeds(),
+            program_id,
+            program_id,
+            contract_bank_account,
+        )?;
+
         auction_root_state.status.is_finished = true;
-        auction_root_state.available_funds = auction_root_state
-            .available_funds
-            .checked_add(Rent::get()?.minimum_balance(0))
-            .ok_or(AuctionContractError::ArithmeticError)?;
+        update_contract_stats(contract_bank_account, |stats| {
+            stats.auctions_finished = stats
+                .auctions_finished
+                .checked_add(1)
+                .ok_or(AuctionContractError::ArithmeticError)?;
+            Ok(())
+        })?;
+        // The rent of the auction bank becomes claimable (unless the bids are
+        // held in a token vault)
+        if auction_root_state.auction_config.bid_mint.is_none() {
+            auction_root_state.available_funds = auction_root_state
+                .available_funds
+                .checked_add(Rent::get()?.minimum_balance(0))
+                .ok_or(AuctionContractError::ArithmeticError)?;
+        }
         let mut auction_pool = AuctionPool::read(auction_pool_account)?;
         let mut secondary_pool = AuctionPool::read(secondary_pool_account)?;
-        auction_pool.remove(&auction_id);
-        secondary_pool.try_insert_sorted(auction_id)?;
+        auction_pool.remove(auction_id);
+        secondary_pool.try_insert_sorted(*auction_id)?;
         auction_pool.write(auction_pool_account)?;
         secondary_pool.write(secondary_pool_account)?;
     } else {
@@ -293,14 +520,19 @@ pub fn close_auction_cycle(
             AuctionCycleState::MAX_SERIALIZED_LEN,
         )?;
 
-        let end_time = clock
-            .unix_timestamp
+        let end_time = current_timestamp
             .checked_add(auction_root_state.auction_config.cycle_period)
             .ok_or(AuctionContractError::ArithmeticError)?;
 
         let next_auction_cycle_state = AuctionCycleState {
             bid_history: BidHistory::new(),
             end_time,
+            claimed_ranks: 0,
+            proxy_bid_max: None,
+            bid_count: 0,
+            settlement_records: SettlementRecordVec::new(),
+            settled_price: None,
+            paused_duration: 0,
         };
 
         next_auction_cycle_state.write(next_auction_cycle_state_account)?;
@@ -314,7 +546,7 @@ pub fn close_auction_cycle(
     auction_root_state.status.current_idle_cycle_streak = 0;
     auction_root_state.unclaimed_rewards = auction_root_state
         .unclaimed_rewards
-        .checked_add(1)
+        .checked_add(number_of_winners)
         .ok_or(AuctionContractError::ArithmeticError)?;
     auction_root_state.write(auction_root_state_account)?;
 
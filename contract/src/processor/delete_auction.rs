@@ -1,6 +1,8 @@
 use super::*;
 
 use solana_program::account_info::next_account_infos;
+use solana_program::program_pack::Pack;
+use spl_token::state::Account as TokenAccount;
 
 pub fn process_delete_auction(
     program_id: &Pubkey,
@@ -10,13 +12,13 @@ pub fn process_delete_auction(
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
     let auction_owner_account = next_account_info(account_info_iter)?; // 1
-    let top_bidder_account = next_account_info(account_info_iter)?; // 2
-    let auction_root_state_account = next_account_info(account_info_iter)?; // 3
-    let auction_bank_account = next_account_info(account_info_iter)?; // 4
-    let contract_bank_account = next_account_info(account_info_iter)?; // 5
-    let auction_pool_account = next_account_info(account_info_iter)?; // 6
-    let secondary_pool_account = next_account_info(account_info_iter)?; // 7
-    let protocol_fee_state_account = next_account_info(account_info_iter)?; // 8
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 2
+    let auction_bank_account = next_account_info(account_info_iter)?; // 3
+    let contract_bank_account = next_account_info(account_info_iter)?; // 4
+    let auction_pool_account = next_account_info(account_info_iter)?; // 5
+    let secondary_pool_account = next_account_info(account_info_iter)?; // 6
+    let protocol_fee_state_account = next_account_info(account_info_iter)?; // 7
+    let auction_beneficiary_account = next_account_info(account_info_iter)?; // 8
 
     if !auction_owner_account.is_signer {
         msg!("Auction owner signature is missing");
@@ -26,7 +28,6 @@ pub fn process_delete_auction(
     // Check account owners and seeds
     // User accounts:
     //   auction_owner_account
-    //   top_bidder_account
     SignerPda::check_owner(
         &auction_root_state_seeds(&auction_id),
         program_id,
@@ -73,18 +74,107 @@ pub fn process_delete_auction(
         return Err(AuctionContractError::AuctionOwnerMismatch.into());
     }
 
+    // The remaining funds are paid to the auction beneficiary
+    if auction_beneficiary_account.key != &auction_root_state.beneficiary {
+        return Err(AuctionContractError::AuctionBeneficiaryMismatch.into());
+    }
+
     // Check unclaimed rewards
     if auction_root_state.unclaimed_rewards != 0 {
         return Err(AuctionContractError::UnclaimedRewards.into());
     }
 
+    // Freeze the auction, so that the bids of its current cycle can be
+    // withdrawn by their bidders
+    let is_freezing =
+        !auction_root_state.status.is_frozen && !auction_root_state.status.is_finished;
+    if is_freezing {
+        auction_root_state.status.is_frozen = true;
+    }
+
+    let pending_error = if auction_root_state.unsettled_deposits != 0 {
+        // Cycle states are kept until every deposit is withdrawn, since they
+        // are needed to determine the withdrawable amounts
+        Some(AuctionContractError::UnwithdrawnDeposits)
+    } else if auction_root_state.escrowed_funds != 0 {
+        // The escrowed proceeds of an all-or-nothing auction become
+        // refundable once the auction is frozen, the cycle states are kept
+        // until they are refunded to the winners
+        Some(AuctionContractError::UnrefundedEscrow)
+    } else if auction_root_state.all_time_treasury != 0
+        && auction_root_state
+            .vested_unclaimed_funds(Clock::get()?.unix_timestamp)
+            .is_some()
+    {
+        // The remaining funds are paid out on deletion, thus the raised funds
+        // have to vest first
+        Some(AuctionContractError::UnvestedFunds)
+    } else {
+        None
+    };
+
+    if let Some(error) = pending_error {
+        // The freeze is persisted, so that the auction can be wound down
+        if is_freezing {
+            msg!("Auction frozen, deletion is pending: {:?}", error);
+            auction_root_state.write(auction_root_state_account)?;
+            return Ok(());
+        }
+        return Err(error.into());
+    }
+
     let removable_cycle_states_num = std::cmp::min(
         auction_root_state.status.current_auction_cycle,
         num_of_cycles_to_delete,
     ) as usize;
 
     // The auction cycle states to remove in reverse chronological order
-    let auction_cycle_states = next_account_infos(account_info_iter, removable_cycle_states_num)?; // 8+
+    let auction_cycle_states = next_account_infos(account_info_iter, removable_cycle_states_num)?; // 9+
+
+    // Token vault accounts (in case of token bids)
+    let token_vault_accounts = if let Some(bid_mint) = auction_root_state.auction_config.bid_mint {
+        let token_vault_accounts = TokenVaultAccounts {
+            token_program: next_account_info(account_info_iter)?,
+            rent_program: next_account_info(account_info_iter)?,
+            system_program: next_account_info(account_info_iter)?,
+            contract_pda: next_account_info(account_info_iter)?,
+            bid_mint_account: next_account_info(account_info_iter)?,
+            auction_vault_account: next_account_info(account_info_iter)?,
+            auction_beneficiary_holding_account: next_account_info(account_info_iter)?,
+            contract_vault_account: next_account_info(account_info_iter)?,
+        };
+
+        assert_token_program(token_vault_accounts.token_program.key)?;
+        assert_rent_program(token_vault_accounts.rent_program.key)?;
+        assert_system_program(token_vault_accounts.system_program.key)?;
+
+        if token_vault_accounts.bid_mint_account.key != &bid_mint {
+            return Err(AuctionContractError::BidMintMismatch.into());
+        }
+
+        SignerPda::check_owner(
+            &auction_vault_seeds(&auction_id),
+            program_id,
+            &TOKEN_ID,
+            token_vault_accounts.auction_vault_account,
+        )?;
+
+        Some(token_vault_accounts)
+    } else {
+        None
+    };
+
+    let protocol_fee =
+        effective_protocol_fee(program_id, protocol_fee_state_account, &auction_root_state)?;
+
+    // Accounts of the beneficiaries receiving their shares of the remaining
+    // funds
+    let beneficiaries = auction_root_state.beneficiaries.contents().to_vec();
+    let beneficiary_accounts = BeneficiaryAccounts::parse(
+        account_info_iter,
+        &beneficiaries,
+        token_vault_accounts.is_some(),
+    )?;
 
     // Iterate over auction cycle states
     let mut cycle_num = auction_root_state.status.current_auction_cycle;
@@ -99,17 +189,6 @@ pub fn process_delete_auction(
             auction_cycle_state_account,
         )?;
 
-        // Refund top bidder of the last cycle
-        if !auction_root_state.status.is_frozen && !auction_root_state.status.is_finished {
-            let auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
-            refund_top_bidder(
-                auction_bank_account,
-                top_bidder_account,
-                &auction_cycle_state,
-            )?;
-            auction_root_state.status.is_frozen = true;
-        }
-
         // Deallocate cycle state
         deallocate_state(auction_cycle_state_account, contract_bank_account)?;
 
@@ -126,18 +205,84 @@ pub fn process_delete_auction(
         return Ok(());
     }
 
+    // Claim and close the auction vault (in case of token bids)
+    if let Some(accounts) = token_vault_accounts {
+        let vault_balance =
+            TokenAccount::unpack_from_slice(&accounts.auction_vault_account.data.borrow())?.amount;
+
+        create_token_payout_accounts(
+            auction_owner_account,
+            auction_beneficiary_account,
+            accounts.auction_beneficiary_holding_account,
+            &beneficiary_accounts,
+            accounts.contract_pda,
+            accounts.contract_vault_account,
+            accounts.bid_mint_account,
+            program_id,
+            accounts.system_program,
+            accounts.token_program,
+            accounts.rent_program,
+        )?;
+
+        claim_tokens(
+            vault_balance,
+            accounts.auction_beneficiary_holding_account,
+            &beneficiaries,
+            &beneficiary_accounts,
+            accounts.auction_vault_account,
+            accounts.contract_vault_account,
+            protocol_fee,
+            accounts.contract_pda,
+            program_id,
+            accounts.token_program,
+        )?;
+
+        let contract_pda_seeds = contract_pda_seeds();
+        let contract_signer_pda =
+            SignerPda::new_checked(&contract_pda_seeds, program_id, accounts.contract_pda)?;
+
+        let close_vault_ix = token_instruction::close_account(
+            accounts.token_program.key,
+            accounts.auction_vault_account.key,
+            auction_owner_account.key,
+            accounts.contract_pda.key,
+            &[accounts.contract_pda.key],
+        )?;
+
+        invoke_signed(
+            &close_vault_ix,
+            &[
+                accounts.auction_vault_account.clone(),
+                auction_owner_account.clone(),
+                accounts.contract_pda.clone(),
+                accounts.token_program.clone(),
+            ],
+            &[&contract_signer_pda.signer_seeds()],
+        )?;
+    }
+
     // Deallocate remaining states if all cycle states are deallocated
     let bank_balance = **auction_bank_account.lamports.borrow();
     claim_lamports(
         bank_balance,
-        auction_owner_account,
+        auction_beneficiary_account,
+        &beneficiaries,
+        &beneficiary_accounts,
         auction_bank_account,
         contract_bank_account,
-        protocol_fee_state_account,
+        protocol_fee,
     )?;
 
     deallocate_state(auction_root_state_account, auction_owner_account)?;
 
+    update_contract_stats(contract_bank_account, |stats| {
+        stats.auctions_deleted = stats
+            .auctions_deleted
+            .checked_add(1)
+            .ok_or(AuctionContractError::ArithmeticError)?;
+        Ok(())
+    })?;
+
     // Remove auction entry from auction pools
     let mut auction_pool = AuctionPool::read(auction_pool_account)?;
     auction_pool.remove(&auction_id);
@@ -160,19 +305,13 @@ fn deallocate_state<'a>(
     checked_credit_account(to, lamports_to_claim)
 }
 
-fn refund_top_bidder<'a>(
-    auction_bank_account: &'a AccountInfo,
-    top_bidder_account: &'a AccountInfo,
-    auction_cycle_state: &'a AuctionCycleState,
-) -> Result<(), AuctionContractError> {
-    let most_recent_bid_option = auction_cycle_state.bid_history.get_last_element();
-    if let Some(most_recent_bid) = most_recent_bid_option {
-        if top_bidder_account.key != &most_recent_bid.bidder_pubkey {
-            return Err(AuctionContractError::TopBidderAccountMismatch);
-        }
-
-        checked_debit_account(auction_bank_account, most_recent_bid.bid_amount)?;
-        checked_credit_account(top_bidder_account, most_recent_bid.bid_amount)?;
-    }
-    Ok(())
+struct TokenVaultAccounts<'a, 'b> {
+    token_program: &'a AccountInfo<'b>,
+    rent_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    contract_pda: &'a AccountInfo<'b>,
+    bid_mint_account: &'a AccountInfo<'b>,
+    auction_vault_account: &'a AccountInfo<'b>,
+    auction_beneficiary_holding_account: &'a AccountInfo<'b>,
+    contract_vault_account: &'a AccountInfo<'b>,
 }
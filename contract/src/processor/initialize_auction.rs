@@ -1,7 +1,11 @@
 use super::*;
 
-use crate::{MAX_CYCLE_PERIOD, MIN_CYCLE_PERIOD, UNIVERSAL_BID_FLOOR};
+use crate::{
+    MAX_BASIS_POINTS, MAX_CYCLE_PERIOD, MAX_WINNERS_PER_CYCLE, MIN_CYCLE_PERIOD,
+    UNIVERSAL_BID_FLOOR,
+};
 use solana_program::clock::UnixTimestamp;
+use solana_program::rent::Rent;
 
 // In case of token auction creation there are two possibilities:
 // - Create new mint
@@ -20,6 +24,8 @@ pub fn initialize_auction(
     mut auction_config: AuctionConfig,
     create_token_args: CreateTokenArgs,
     auction_start_timestamp: Option<UnixTimestamp>,
+    beneficiaries: BeneficiaryVec,
+    vesting_schedule: Option<VestingSchedule>,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
     // User accounts
@@ -29,6 +35,7 @@ pub fn initialize_auction(
     let auction_root_state_account = next_account_info(account_info_iter)?;
     let auction_cycle_state_account = next_account_info(account_info_iter)?;
     let auction_bank_account = next_account_info(account_info_iter)?;
+    let contract_bank_account = next_account_info(account_info_iter)?;
     // Contract PDA account
     let contract_pda = next_account_info(account_info_iter)?;
     // Solana accounts
@@ -63,6 +70,13 @@ pub fn initialize_auction(
         auction_pool_account,
     )?;
 
+    SignerPda::check_owner(
+        &contract_bank_seeds(),
+        program_id,
+        program_id,
+        contract_bank_account,
+    )?;
+
     let auction_root_state_seeds = auction_root_state_seeds(&auction_id);
     let auction_root_state_pda = SignerPda::new_checked(
         &auction_root_state_seeds,
@@ -128,10 +142,134 @@ pub fn initialize_auction(
     )?;
 
     // Check if provided minimum_bid_amount is higher than the universal bid floor
-    if auction_config.minimum_bid_amount < UNIVERSAL_BID_FLOOR {
+    // (the floor is only enforced on lamport bids)
+    let bid_floor = if auction_config.bid_mint.is_some() {
+        1
+    } else {
+        UNIVERSAL_BID_FLOOR
+    };
+    if auction_config.minimum_bid_amount < bid_floor {
         return Err(AuctionContractError::InvalidMinimumBidAmount.into());
     }
 
+    // Check if the reserve price (if any) is not lower than the minimum bid
+    if let Some(reserve_price) = auction_config.reserve_price {
+        if reserve_price.amount < auction_config.minimum_bid_amount {
+            return Err(AuctionContractError::InvalidReservePrice.into());
+        }
+    }
+
+    // Check if the buy-now price (if any) is not lower than the minimum bid
+    // and the reserve price
+    if let Some(buy_now_price) = auction_config.buy_now_price {
+        if buy_now_price < auction_config.minimum_bid_amount
+            || !auction_config.is_reserve_met(buy_now_price)
+        {
+            return Err(AuctionContractError::InvalidBuyNowPrice.into());
+        }
+    }
+
+    // Check if the Dutch price curve (if any) decays to a floor that is not
+    // lower than the minimum bid and the reserve price
+    if let AuctionKind::Dutch(price_curve) = auction_config.kind {
+        if price_curve.start_price < price_curve.floor_price
+            || price_curve.floor_price < auction_config.minimum_bid_amount
+            || !auction_config.is_reserve_met(price_curve.floor_price)
+        {
+            return Err(AuctionContractError::InvalidPriceCurve.into());
+        }
+    }
+
+    // Check if the reveal period of sealed-bid auctions is valid and that they
+    // only accept SOL bids without a buy-now price
+    if let AuctionKind::Sealed(sealed_bid_config) = auction_config.kind {
+        if sealed_bid_config.reveal_period <= 0
+            || sealed_bid_config.reveal_period > MAX_CYCLE_PERIOD
+        {
+            return Err(AuctionContractError::InvalidRevealPeriod.into());
+        }
+        if auction_config.bid_mint.is_some() || auction_config.buy_now_price.is_some() {
+            return Err(AuctionContractError::UnsupportedAuctionKind.into());
+        }
+    }
+
+    // Check if second-price settlement is only used by English auctions
+    if auction_config.second_price_settlement
+        && !matches!(auction_config.kind, AuctionKind::English)
+    {
+        return Err(AuctionContractError::UnsupportedAuctionKind.into());
+    }
+
+    // Check if the number of winners per cycle is valid
+    //
+    // Multiple winners are only supported by English auctions with a single
+    // price paid by each winner
+    if auction_config.winners_per_cycle == 0
+        || auction_config.winners_per_cycle > MAX_WINNERS_PER_CYCLE
+    {
+        return Err(AuctionContractError::InvalidWinnersPerCycle.into());
+    }
+    if auction_config.winners_per_cycle > 1
+        && (!matches!(auction_config.kind, AuctionKind::English)
+            || auction_config.second_price_settlement
+            || auction_config.buy_now_price.is_some()
+            || auction_config.reserve_price.is_some())
+    {
+        return Err(AuctionContractError::InvalidWinnersPerCycle.into());
+    }
+
+    // Check if the referral share is valid
+    //
+    // Referral rewards are paid in lamports, thus they are only supported by
+    // auctions accepting SOL bids
+    if auction_config.referral_share_bps > MAX_BASIS_POINTS
+        || (auction_config.referral_share_bps > 0 && auction_config.bid_mint.is_some())
+    {
+        return Err(AuctionContractError::InvalidReferralShare.into());
+    }
+
+    // Check if the all-or-nothing mode is valid
+    //
+    // The proceeds are held in escrow until the auction ends, thus it needs a
+    // funding goal and a finite number of cycles, and the proceeds cannot be
+    // used to pay referral rewards
+    if auction_config.all_or_nothing {
+        if auction_description.goal_treasury_amount.is_none()
+            || auction_config.number_of_cycles.is_none()
+        {
+            return Err(AuctionContractError::InvalidFundingGoal.into());
+        }
+        if auction_config.referral_share_bps > 0 {
+            return Err(AuctionContractError::InvalidReferralShare.into());
+        }
+    }
+
+    // Check if the auction can finish on reaching its goal
+    if auction_config.finish_on_goal && auction_description.goal_treasury_amount.is_none() {
+        return Err(AuctionContractError::InvalidFundingGoal.into());
+    }
+
+    // Check if the minimum bid increment (if any) is valid
+    if let Some(ref bid_increment) = auction_config.min_bid_increment {
+        check_bid_increment(bid_increment)?;
+    }
+
+    // Check if the vesting schedule (if any) is valid
+    if let Some(ref schedule) = vesting_schedule {
+        if schedule.vesting_period <= 0
+            || schedule.cliff_period < 0
+            || schedule.cliff_period > schedule.vesting_period
+        {
+            return Err(AuctionContractError::InvalidVestingSchedule.into());
+        }
+    }
+
+    check_beneficiaries(
+        beneficiaries.contents(),
+        auction_owner_account.key,
+        auction_owner_account.key,
+    )?;
+
     // Check if provided auction cycle period is valid
     if auction_config.cycle_period < MIN_CYCLE_PERIOD
         || auction_config.cycle_period > MAX_CYCLE_PERIOD
@@ -174,6 +312,12 @@ pub fn initialize_auction(
     let cycle_state = AuctionCycleState {
         end_time,
         bid_history,
+        claimed_ranks: 0,
+        proxy_bid_max: None,
+        bid_count: 0,
+        settlement_records: SettlementRecordVec::new(),
+        settled_price: None,
+        paused_duration: 0,
     };
     cycle_state.write(auction_cycle_state_account)?;
 
@@ -301,6 +445,16 @@ pub fn initialize_auction(
             )?;
 
             // turn nft into master edition
+            // (each winner of a cycle receives a separate edition)
+            let max_supply = if let Some(number_of_cycles) = auction_config.number_of_cycles {
+                Some(
+                    number_of_cycles
+                        .checked_mul(u64::from(auction_config.winners_per_cycle))
+                        .ok_or(AuctionContractError::ArithmeticError)?,
+                )
+            } else {
+                None
+            };
             let master_edition_ix = meta_instruction::create_master_edition(
                 *metadata_program.key,
                 *master_edition_account.key,
@@ -309,7 +463,7 @@ pub fn initialize_auction(
                 *contract_pda.key,
                 *master_metadata_account.key,
                 *auction_owner_account.key,
-                auction_config.number_of_cycles,
+                max_supply,
             );
 
             invoke_signed(
@@ -399,6 +553,36 @@ pub fn initialize_auction(
         }
     };
 
+    // Create the auction vault holding the bids (in case of token bids)
+    if let Some(bid_mint) = auction_config.bid_mint {
+        let bid_mint_account = next_account_info(account_info_iter)?;
+        let auction_vault_account = next_account_info(account_info_iter)?;
+
+        // Check account ownership
+        // Accounts created in this instruction:
+        //   auction_vault_account
+        if bid_mint_account.key != &bid_mint {
+            return Err(AuctionContractError::BidMintMismatch.into());
+        }
+        assert_owner(bid_mint_account, &TOKEN_ID)?;
+
+        // Check pda addresses
+        let auction_vault_seeds = auction_vault_seeds(&auction_id);
+        let auction_vault_pda =
+            SignerPda::new_checked(&auction_vault_seeds, program_id, auction_vault_account)?;
+
+        create_token_holding_account(
+            auction_owner_account,
+            contract_pda,
+            auction_vault_account,
+            bid_mint_account,
+            auction_vault_pda.signer_seeds(),
+            system_program,
+            token_program,
+            rent_program,
+        )?;
+    }
+
     // Initialize root state account
     let root_state = AuctionRootState {
         auction_name,
@@ -413,13 +597,49 @@ pub fn initialize_auction(
             is_frozen: false,
             is_filtered: false,
             is_verified: false,
+            paused_at: None,
         },
         all_time_treasury: 0,
         available_funds: 0,
         start_time,
         unclaimed_rewards: 0,
+        unsettled_deposits: 0,
+        pending_owner: None,
+        beneficiary: *auction_owner_account.key,
+        beneficiaries,
+        escrowed_funds: 0,
+        vesting_schedule,
+        claimed_funds: 0,
+        protocol_fee_override: None,
     };
     root_state.write(auction_root_state_account)?;
 
-    Ok(())
+    // Top up the rent of the legacy contract bank (if it cannot cover it) that
+    // is resized to hold the revenue counters
+    let rent_difference = Rent::get()?
+        .minimum_balance(ContractBankState::MAX_SERIALIZED_LEN)
+        .saturating_sub(**contract_bank_account.lamports.borrow());
+    if rent_difference > 0 {
+        let transfer_ix = system_instruction::transfer(
+            auction_owner_account.key,
+            contract_bank_account.key,
+            rent_difference,
+        );
+        invoke(
+            &transfer_ix,
+            &[
+                auction_owner_account.to_owned(),
+                contract_bank_account.to_owned(),
+                system_program.to_owned(),
+            ],
+        )?;
+    }
+
+    update_contract_stats(contract_bank_account, |stats| {
+        stats.auctions_created = stats
+            .auctions_created
+            .checked_add(1)
+            .ok_or(AuctionContractError::ArithmeticError)?;
+        Ok(())
+    })
 }
@@ -87,6 +87,8 @@ pub fn initialize_contract(
     let contract_bank_state = ContractBankState {
         contract_admin: *contract_admin_account.key,
         withdraw_authority,
+        stats: ContractStats::default(),
+        pending_contract_admin: None,
     };
     contract_bank_state.write(contract_bank_account)
 }
@@ -0,0 +1,142 @@
+use super::*;
+
+use solana_program::rent::Rent;
+
+/// Migrates the root state of an auction and the state of one of its cycles
+/// from the legacy layout by resizing their accounts.
+///
+/// Legacy states can be read, but they have to be migrated before they are
+/// modified. Anyone may pay for the migration, accounts holding the current
+/// layout are left untouched.
+///
+/// The legacy auction bank held the top bid of the current cycle without a
+/// deposit account, thus it is recorded as a deposit of the top bidder when
+/// the cycle state is migrated.
+pub fn process_migrate_auction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+    cycle_number: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let payer_account = next_account_info(account_info_iter)?; // 1
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 2
+    let auction_cycle_state_account = next_account_info(account_info_iter)?; // 3
+    let system_program = next_account_info(account_info_iter)?; // 4
+
+    if !payer_account.is_signer {
+        msg!("payer signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check cross-program invocation addresses
+    assert_system_program(system_program.key)?;
+
+    // Check pda addresses
+    SignerPda::check_owner(
+        &auction_root_state_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let cycle_num_bytes = cycle_number.to_le_bytes();
+    SignerPda::check_owner(
+        &auction_cycle_state_seeds(auction_root_state_account.key, &cycle_num_bytes),
+        program_id,
+        program_id,
+        auction_cycle_state_account,
+    )?;
+
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    let auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
+
+    let root_state_len = AuctionRootState::MAX_SERIALIZED_LEN
+        .checked_add(crate::EXTRA_ROOT_STATE_BYTES)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    resize_state_account(
+        payer_account,
+        auction_root_state_account,
+        system_program,
+        root_state_len,
+    )?;
+
+    if auction_cycle_state_account.data_len() == AuctionCycleState::LEGACY_ACCOUNT_LEN {
+        resize_state_account(
+            payer_account,
+            auction_cycle_state_account,
+            system_program,
+            AuctionCycleState::MAX_SERIALIZED_LEN,
+        )?;
+
+        let is_settled = cycle_number < auction_root_state.status.current_auction_cycle
+            || auction_root_state.status.is_finished;
+        // The top bid of a settled cycle was already added to the funds of
+        // the auction
+        let unsettled_top_bid = auction_cycle_state
+            .bid_history
+            .get_last_element()
+            .filter(|_| !is_settled);
+        if let Some(top_bid) = unsettled_top_bid {
+            let bid_deposit_account = next_account_info(account_info_iter)?; // 5
+            let bid_deposit_seeds =
+                bid_deposit_seeds(auction_cycle_state_account.key, &top_bid.bidder_pubkey);
+            let bid_deposit_pda =
+                SignerPda::new_checked(&bid_deposit_seeds, program_id, bid_deposit_account)?;
+
+            create_state_account(
+                payer_account,
+                bid_deposit_account,
+                bid_deposit_pda.signer_seeds(),
+                program_id,
+                system_program,
+                BidDeposit::MAX_SERIALIZED_LEN,
+            )?;
+            let bid_deposit = BidDeposit {
+                bidder_pubkey: top_bid.bidder_pubkey,
+                amount: top_bid.bid_amount,
+            };
+            bid_deposit.write(bid_deposit_account)?;
+
+            auction_root_state.unsettled_deposits = auction_root_state
+                .unsettled_deposits
+                .checked_add(top_bid.bid_amount)
+                .ok_or(AuctionContractError::ArithmeticError)?;
+        }
+
+        auction_cycle_state.write(auction_cycle_state_account)?;
+    }
+
+    auction_root_state.write(auction_root_state_account)
+}
+
+/// Resizes a state account to `new_len` bytes (unless it is not shorter),
+/// topping up its rent from the payer.
+pub(super) fn resize_state_account<'a>(
+    payer_account: &AccountInfo<'a>,
+    state_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    new_len: usize,
+) -> ProgramResult {
+    if state_account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent_difference = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(**state_account.lamports.borrow());
+    if rent_difference > 0 {
+        let transfer_ix =
+            system_instruction::transfer(payer_account.key, state_account.key, rent_difference);
+        invoke(
+            &transfer_ix,
+            &[
+                payer_account.to_owned(),
+                state_account.to_owned(),
+                system_program.to_owned(),
+            ],
+        )?;
+    }
+
+    state_account.realloc(new_len, false)
+}
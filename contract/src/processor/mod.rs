@@ -1,16 +1,25 @@
+mod admin_transfer;
 mod admin_withdraw;
 mod bid;
 mod claim_funds;
+mod claim_referral_rewards;
 mod claim_rewards;
 mod close_auction_cycle;
 mod delete_auction;
 mod filter_auction;
 mod initialize_auction;
 mod initialize_contract;
+mod migrate_auction;
 mod modify_auction;
+mod owner_transfer;
+mod pause_auction;
+mod proxy_bid;
 mod reallocate_pool;
+mod refund_failed_goal;
+mod sealed_bid;
 mod set_protocol_fee;
 mod verify_auction;
+mod withdraw_refund;
 
 use crate::assertions::*;
 use crate::error::AuctionContractError;
@@ -18,7 +27,7 @@ use crate::instruction::AuctionInstruction;
 use crate::pda::factory::*;
 use crate::pda::*;
 use crate::state::*;
-use crate::utils::initialize_create_metadata_args;
+use crate::utils::{edition_number, initialize_create_metadata_args};
 
 use agsol_token_metadata::instruction as meta_instruction;
 use agsol_token_metadata::ID as META_ID;
@@ -38,7 +47,11 @@ use spl_token::ID as TOKEN_ID;
 
 use agsol_common::{AccountState, MaxSerializedLen, SignerPda};
 
-pub use claim_funds::claim_lamports;
+pub use admin_withdraw::{update_contract_stats, write_contract_bank_state};
+pub use claim_funds::{
+    claim_lamports, claim_tokens, create_token_payout_accounts, effective_protocol_fee,
+    BeneficiaryAccounts,
+};
 pub use close_auction_cycle::{increment_name, increment_uri};
 
 pub fn process(
@@ -64,6 +77,8 @@ pub fn process(
             auction_config,
             create_token_args,
             auction_start_timestamp,
+            beneficiaries,
+            vesting_schedule,
         } => initialize_auction::initialize_auction(
             program_id,
             accounts,
@@ -73,10 +88,26 @@ pub fn process(
             auction_config,
             create_token_args,
             auction_start_timestamp,
+            beneficiaries,
+            vesting_schedule,
+        ),
+        AuctionInstruction::Bid {
+            id,
+            amount,
+            expected_cycle_number,
+            expected_top_bid,
+            memo,
+            referrer,
+        } => bid::process_bid(
+            program_id,
+            accounts,
+            id,
+            amount,
+            expected_cycle_number,
+            expected_top_bid,
+            memo,
+            referrer,
         ),
-        AuctionInstruction::Bid { amount, id } => {
-            bid::process_bid(program_id, accounts, id, amount)
-        }
         AuctionInstruction::CloseAuctionCycle { id } => {
             close_auction_cycle::close_auction_cycle(program_id, accounts, id)
         }
@@ -95,9 +126,11 @@ pub fn process(
         AuctionInstruction::ClaimFunds { id, amount } => {
             claim_funds::process_claim_funds(program_id, accounts, id, amount)
         }
-        AuctionInstruction::ClaimRewards { id, cycle_number } => {
-            claim_rewards::process_claim_rewards(program_id, accounts, id, cycle_number)
-        }
+        AuctionInstruction::ClaimRewards {
+            id,
+            cycle_number,
+            rank,
+        } => claim_rewards::process_claim_rewards(program_id, accounts, id, cycle_number, rank),
         AuctionInstruction::VerifyAuction { id } => {
             verify_auction::process_verify_auction(program_id, accounts, id)
         }
@@ -114,11 +147,64 @@ pub fn process(
         AuctionInstruction::ReallocatePool {
             new_max_auction_num,
         } => reallocate_pool::reallocate_pool(program_id, accounts, new_max_auction_num),
-        AuctionInstruction::SetProtocolFee { new_fee } => {
-            set_protocol_fee::process_set_protocol_fee(program_id, accounts, new_fee)
-        }
+        AuctionInstruction::SetProtocolFee {
+            new_fee,
+            new_verified_fee,
+        } => set_protocol_fee::process_set_protocol_fee(
+            program_id,
+            accounts,
+            new_fee,
+            new_verified_fee,
+        ),
         AuctionInstruction::ModifyAuction { id, modify_data } => {
             modify_auction::process_modify_auction(program_id, accounts, id, modify_data)
         }
+        AuctionInstruction::CommitBid {
+            id,
+            commitment,
+            deposit,
+        } => sealed_bid::process_commit_bid(program_id, accounts, id, commitment, deposit),
+        AuctionInstruction::RevealBid {
+            id,
+            cycle_number,
+            amount,
+            salt,
+        } => sealed_bid::process_reveal_bid(program_id, accounts, id, cycle_number, amount, salt),
+        AuctionInstruction::PlaceProxyBid { id, max_amount } => {
+            proxy_bid::process_place_proxy_bid(program_id, accounts, id, max_amount)
+        }
+        AuctionInstruction::WithdrawRefund { id, cycle_number } => {
+            withdraw_refund::process_withdraw_refund(program_id, accounts, id, cycle_number)
+        }
+        AuctionInstruction::ClaimReferralRewards => {
+            claim_referral_rewards::process_claim_referral_rewards(program_id, accounts)
+        }
+        AuctionInstruction::PauseAuction { id } => {
+            pause_auction::process_pause_auction(program_id, accounts, id)
+        }
+        AuctionInstruction::ResumeAuction { id } => {
+            pause_auction::process_resume_auction(program_id, accounts, id)
+        }
+        AuctionInstruction::ProposeOwnerTransfer { id, new_owner } => {
+            owner_transfer::process_propose_owner_transfer(program_id, accounts, id, new_owner)
+        }
+        AuctionInstruction::AcceptOwnerTransfer { id } => {
+            owner_transfer::process_accept_owner_transfer(program_id, accounts, id)
+        }
+        AuctionInstruction::RefundFailedGoal { id, cycle_number } => {
+            refund_failed_goal::process_refund_failed_goal(program_id, accounts, id, cycle_number)
+        }
+        AuctionInstruction::SetAuctionProtocolFee { id, new_fee } => {
+            set_protocol_fee::process_set_auction_protocol_fee(program_id, accounts, id, new_fee)
+        }
+        AuctionInstruction::ProposeContractAdmin { new_contract_admin } => {
+            admin_transfer::process_propose_contract_admin(program_id, accounts, new_contract_admin)
+        }
+        AuctionInstruction::AcceptContractAdmin => {
+            admin_transfer::process_accept_contract_admin(program_id, accounts)
+        }
+        AuctionInstruction::MigrateAuction { id, cycle_number } => {
+            migrate_auction::process_migrate_auction(program_id, accounts, id, cycle_number)
+        }
     }
 }
@@ -9,6 +9,7 @@ pub fn process_modify_auction(
     let account_info_iter = &mut accounts.iter();
     let auction_owner_account = next_account_info(account_info_iter)?;
     let auction_root_state_account = next_account_info(account_info_iter)?;
+    let auction_cycle_state_account = next_account_info(account_info_iter)?;
 
     if !auction_owner_account.is_signer {
         msg!("owner signature is missing");
@@ -46,6 +47,48 @@ pub fn process_modify_auction(
         auction_root_state.auction_config.encore_period = new_encore_period;
     }
 
+    if let Some(new_min_bid_increment) = modify_data.new_min_bid_increment {
+        if let Some(ref bid_increment) = new_min_bid_increment {
+            check_bid_increment(bid_increment)?;
+        }
+        // The clearing price of a second-price auction depends on the
+        // increment, thus it cannot change once the current cycle has bids
+        if auction_root_state.auction_config.second_price_settlement
+            && !auction_root_state.status.is_finished
+        {
+            let cycle_num_bytes = auction_root_state
+                .status
+                .current_auction_cycle
+                .to_le_bytes();
+            SignerPda::check_owner(
+                &auction_cycle_state_seeds(auction_root_state_account.key, &cycle_num_bytes),
+                program_id,
+                program_id,
+                auction_cycle_state_account,
+            )?;
+            let auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
+            if !auction_cycle_state.bid_history.is_empty() {
+                msg!("Bid increment cannot change while the cycle has bids");
+                return Err(AuctionContractError::InvalidBidIncrement.into());
+            }
+        }
+        auction_root_state.auction_config.min_bid_increment = new_min_bid_increment;
+    }
+
+    if modify_data.new_beneficiaries.is_some() || modify_data.new_beneficiary.is_some() {
+        if let Some(new_beneficiaries) = modify_data.new_beneficiaries {
+            auction_root_state.beneficiaries = new_beneficiaries;
+        }
+        if let Some(new_beneficiary) = modify_data.new_beneficiary {
+            auction_root_state.beneficiary = new_beneficiary;
+        }
+        check_beneficiaries(
+            auction_root_state.beneficiaries.contents(),
+            &auction_root_state.auction_owner,
+            &auction_root_state.beneficiary,
+        )?;
+    }
+
     auction_root_state.write(auction_root_state_account)?;
 
     Ok(())
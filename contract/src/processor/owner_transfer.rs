@@ -0,0 +1,100 @@
+use super::*;
+
+/// Proposes a new owner for the auction, who has to accept the ownership
+/// before it is transferred.
+///
+/// A pending proposal is replaced by a new one, or it is cancelled if no new
+/// owner is given.
+///
+/// The root state of a legacy auction is resized at the expense of the owner,
+/// so that the proposal can be recorded (the cycle states can be migrated
+/// later on via `MigrateAuction`).
+pub fn process_propose_owner_transfer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+    new_owner: Option<Pubkey>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let auction_owner_account = next_account_info(account_info_iter)?; // 1
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 2
+    let system_program = next_account_info(account_info_iter)?; // 3
+
+    if !auction_owner_account.is_signer {
+        msg!("owner signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check cross-program invocation addresses
+    assert_system_program(system_program.key)?;
+
+    SignerPda::check_owner(
+        &auction_root_state_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    if auction_owner_account.key != &auction_root_state.auction_owner {
+        return Err(AuctionContractError::AuctionOwnerMismatch.into());
+    }
+
+    if auction_root_state_account.data_len() == AuctionRootState::LEGACY_ACCOUNT_LEN {
+        let root_state_len = AuctionRootState::MAX_SERIALIZED_LEN
+            .checked_add(crate::EXTRA_ROOT_STATE_BYTES)
+            .ok_or(AuctionContractError::ArithmeticError)?;
+        migrate_auction::resize_state_account(
+            auction_owner_account,
+            auction_root_state_account,
+            system_program,
+            root_state_len,
+        )?;
+    }
+
+    auction_root_state.pending_owner = new_owner;
+    auction_root_state.write(auction_root_state_account)
+}
+
+/// Transfers the ownership of the auction to the proposed owner.
+///
+/// The claimed funds are paid to the new owner as well, unless the previous
+/// owner has set a different beneficiary.
+pub fn process_accept_owner_transfer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let new_owner_account = next_account_info(account_info_iter)?; // 1
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 2
+
+    if !new_owner_account.is_signer {
+        msg!("new owner signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    SignerPda::check_owner(
+        &auction_root_state_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    if auction_root_state.pending_owner.as_ref() != Some(new_owner_account.key) {
+        return Err(AuctionContractError::PendingOwnerMismatch.into());
+    }
+
+    if auction_root_state.beneficiary == auction_root_state.auction_owner {
+        auction_root_state.beneficiary = *new_owner_account.key;
+    }
+    auction_root_state.auction_owner = *new_owner_account.key;
+    auction_root_state.pending_owner = None;
+    check_beneficiaries(
+        auction_root_state.beneficiaries.contents(),
+        &auction_root_state.auction_owner,
+        &auction_root_state.beneficiary,
+    )?;
+    auction_root_state.write(auction_root_state_account)
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+/// Pauses the auction until it is resumed by its owner.
+///
+/// Bids cannot be placed and the current cycle cannot be closed while the
+/// auction is paused, but the bids remain locked (unlike in a frozen
+/// auction).
+pub fn process_pause_auction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let auction_owner_account = next_account_info(account_info_iter)?; // 1
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 2
+
+    let mut auction_root_state = read_owned_root_state(
+        program_id,
+        &auction_id,
+        auction_owner_account,
+        auction_root_state_account,
+    )?;
+
+    if auction_root_state.status.paused_at.is_some() {
+        return Err(AuctionContractError::AuctionPaused.into());
+    }
+
+    let clock = Clock::get()?;
+    auction_root_state.status.paused_at = Some(clock.unix_timestamp);
+    auction_root_state.write(auction_root_state_account)
+}
+
+/// Resumes a paused auction.
+///
+/// The end time of the current cycle (and the start time of the auction if
+/// it has not started before it was paused) is shifted by the paused
+/// duration, so that bidders get the time remaining upon pausing. The
+/// deadlines of the sealed bids committed to the cycle are shifted as well
+/// (see [`BidCommitment::is_stale`]).
+pub fn process_resume_auction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let auction_owner_account = next_account_info(account_info_iter)?; // 1
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 2
+    let auction_cycle_state_account = next_account_info(account_info_iter)?; // 3
+
+    let mut auction_root_state = read_owned_root_state(
+        program_id,
+        &auction_id,
+        auction_owner_account,
+        auction_root_state_account,
+    )?;
+
+    let paused_at = auction_root_state
+        .status
+        .paused_at
+        .ok_or(AuctionContractError::AuctionNotPaused)?;
+
+    let cycle_num_bytes = auction_root_state
+        .status
+        .current_auction_cycle
+        .to_le_bytes();
+    SignerPda::check_owner(
+        &auction_cycle_state_seeds(auction_root_state_account.key, &cycle_num_bytes),
+        program_id,
+        program_id,
+        auction_cycle_state_account,
+    )?;
+    let mut auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
+
+    let clock = Clock::get()?;
+    let paused_duration = clock
+        .unix_timestamp
+        .checked_sub(paused_at)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    auction_cycle_state.end_time = auction_cycle_state
+        .end_time
+        .checked_add(paused_duration)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    auction_cycle_state.paused_duration = auction_cycle_state
+        .paused_duration
+        .checked_add(paused_duration)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    if auction_root_state.start_time > paused_at {
+        auction_root_state.start_time = auction_root_state
+            .start_time
+            .checked_add(paused_duration)
+            .ok_or(AuctionContractError::ArithmeticError)?;
+    }
+    auction_root_state.status.paused_at = None;
+
+    auction_cycle_state.write(auction_cycle_state_account)?;
+    auction_root_state.write(auction_root_state_account)
+}
+
+/// Reads the root state of an ongoing auction after checking the signature
+/// of its owner.
+fn read_owned_root_state(
+    program_id: &Pubkey,
+    auction_id: &AuctionId,
+    auction_owner_account: &AccountInfo,
+    auction_root_state_account: &AccountInfo,
+) -> Result<AuctionRootState, ProgramError> {
+    if !auction_owner_account.is_signer {
+        msg!("owner signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    SignerPda::check_owner(
+        &auction_root_state_seeds(auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    if auction_owner_account.key != &auction_root_state.auction_owner {
+        return Err(AuctionContractError::AuctionOwnerMismatch.into());
+    }
+    if auction_root_state.status.is_frozen {
+        return Err(AuctionContractError::AuctionFrozen.into());
+    }
+    if auction_root_state.status.is_finished {
+        return Err(AuctionContractError::AuctionEnded.into());
+    }
+
+    Ok(auction_root_state)
+}
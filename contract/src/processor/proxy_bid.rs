@@ -0,0 +1,303 @@
+use super::*;
+
+/// Places a proxy bid on the current cycle of an auction.
+///
+/// The bidder deposits their maximum amount and the contract bids on their
+/// behalf only as much as needed to stay on top, up to that maximum.
+/// Competing proxy bids are resolved against each other: the higher maximum
+/// wins at the minimum step above the other maximum. The unused part of the
+/// deposit can be withdrawn once the proxy bid no longer backs the top bid
+/// or the cycle is closed.
+pub fn process_place_proxy_bid(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+    max_amount: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let user_main_account = next_account_info(account_info_iter)?; // 1
+    let auction_bank_account = next_account_info(account_info_iter)?; // 2
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 3
+    let auction_cycle_state_account = next_account_info(account_info_iter)?; // 4
+    let bid_deposit_account = next_account_info(account_info_iter)?; // 5
+    let auction_pool_account = next_account_info(account_info_iter)?; // 6
+    let secondary_pool_account = next_account_info(account_info_iter)?; // 7
+    let system_program = next_account_info(account_info_iter)?; // 8
+    let bid_log_page_account = next_account_info(account_info_iter)?; // 9
+    let next_bid_log_page_account = next_account_info(account_info_iter)?; // 10
+
+    // Check if user is signer
+    if !user_main_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    // Check cross-program invocation addresses
+    assert_system_program(system_program.key)?;
+    // Check root and cycle states
+    SignerPda::check_owner(
+        &auction_root_state_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    let auction_config = auction_root_state.auction_config;
+
+    let cycle_num = auction_root_state
+        .status
+        .current_auction_cycle
+        .to_le_bytes();
+
+    SignerPda::check_owner(
+        &auction_cycle_state_seeds(auction_root_state_account.key, &cycle_num),
+        program_id,
+        program_id,
+        auction_cycle_state_account,
+    )?;
+
+    let mut auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
+
+    // check auction pools
+    SignerPda::check_owner(
+        &auction_pool_seeds(),
+        program_id,
+        program_id,
+        auction_pool_account,
+    )?;
+
+    SignerPda::check_owner(
+        &secondary_pool_seeds(),
+        program_id,
+        program_id,
+        secondary_pool_account,
+    )?;
+
+    // Check status and maximum amount
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    let interaction_type = if bid::activate_auction(
+        &auction_id,
+        auction_pool_account,
+        secondary_pool_account,
+        &mut auction_cycle_state,
+        auction_config.cycle_period,
+        current_timestamp,
+    )? {
+        AuctionInteraction::BidInactive
+    } else {
+        AuctionInteraction::Bid
+    };
+
+    check_status(
+        &auction_root_state,
+        &auction_cycle_state,
+        current_timestamp,
+        interaction_type,
+    )?;
+
+    if !auction_config.supports_proxy_bids() {
+        return Err(AuctionContractError::UnsupportedAuctionKind.into());
+    }
+
+    // The maximum amount has to be a valid bid on its own
+    check_bid_amount(
+        &auction_root_state,
+        &auction_cycle_state,
+        user_main_account.key,
+        max_amount,
+        current_timestamp,
+    )?;
+
+    // check auction bank
+    SignerPda::check_owner(
+        &auction_bank_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_bank_account,
+    )?;
+
+    // Deposit the maximum amount into the auction bank
+    let lamport_transfer_ix =
+        system_instruction::transfer(user_main_account.key, auction_bank_account.key, max_amount);
+
+    invoke(
+        &lamport_transfer_ix,
+        &[
+            user_main_account.to_owned(),
+            auction_bank_account.to_owned(),
+            system_program.to_owned(),
+        ],
+    )?;
+
+    withdraw_refund::record_deposit(
+        program_id,
+        user_main_account,
+        bid_deposit_account,
+        auction_cycle_state_account.key,
+        system_program,
+        &mut auction_root_state,
+        max_amount,
+    )?;
+    auction_root_state.write(auction_root_state_account)?;
+
+    let bid_log_page_accounts = [bid_log_page_account, next_bid_log_page_account];
+    let cycle_number = auction_root_state.status.current_auction_cycle;
+
+    // Resolve the proxy bid against the top bid
+    let top_bid_option = auction_cycle_state.bid_history.get_last_element().cloned();
+    let bid_amount = if let Some(top_bid) = top_bid_option {
+        if &top_bid.bidder_pubkey == user_main_account.key {
+            // The proxy bid backs the bidder's own top bid
+            None
+        } else {
+            let top_proxy_bid_max = auction_cycle_state.proxy_bid_max;
+            let new_bid = BidData {
+                bid_amount: max_amount,
+                bidder_pubkey: *user_main_account.key,
+                memo: None,
+                referrer: None,
+            };
+            if top_proxy_bid_max.is_some() {
+                if let Some(counter_bid) = counter_with_proxy_bid(
+                    &mut auction_cycle_state,
+                    &auction_config,
+                    new_bid.clone(),
+                )? {
+                    // The new proxy bid is outbid right away, thus its deposit
+                    // can be withdrawn
+                    for bid in [new_bid, counter_bid] {
+                        bid::log_bid(
+                            program_id,
+                            user_main_account,
+                            &bid_log_page_accounts,
+                            auction_root_state_account.key,
+                            system_program,
+                            &mut auction_cycle_state,
+                            cycle_number,
+                            bid,
+                        )?;
+                    }
+                    bid::trigger_encore(
+                        &mut auction_cycle_state,
+                        auction_config.encore_period,
+                        current_timestamp,
+                    )?;
+                    auction_cycle_state.write(auction_cycle_state_account)?;
+                    return Ok(());
+                }
+            }
+
+            let required_amount = if let Some(top_proxy_bid_max) = top_proxy_bid_max {
+                // The exhausted proxy bid is recorded at its maximum
+                if top_proxy_bid_max > top_bid.bid_amount {
+                    let exhausted_bid = BidData {
+                        bid_amount: top_proxy_bid_max,
+                        bidder_pubkey: top_bid.bidder_pubkey,
+                        memo: None,
+                        referrer: None,
+                    };
+                    auction_cycle_state
+                        .bid_history
+                        .cyclic_push(exhausted_bid.clone());
+                    bid::log_bid(
+                        program_id,
+                        user_main_account,
+                        &bid_log_page_accounts,
+                        auction_root_state_account.key,
+                        system_program,
+                        &mut auction_cycle_state,
+                        cycle_number,
+                        exhausted_bid,
+                    )?;
+                }
+                auction_config.next_valid_bid(top_proxy_bid_max)?
+            } else {
+                auction_config.next_valid_bid(top_bid.bid_amount)?
+            };
+            Some(auction_config.proxy_bid_amount(required_amount, max_amount))
+        }
+    } else {
+        Some(auction_config.proxy_bid_amount(auction_config.minimum_bid_amount, max_amount))
+    };
+
+    if let Some(bid_amount) = bid_amount {
+        let proxy_bid = BidData {
+            bid_amount,
+            bidder_pubkey: *user_main_account.key,
+            memo: None,
+            referrer: None,
+        };
+        auction_cycle_state
+            .bid_history
+            .cyclic_push(proxy_bid.clone());
+        bid::log_bid(
+            program_id,
+            user_main_account,
+            &bid_log_page_accounts,
+            auction_root_state_account.key,
+            system_program,
+            &mut auction_cycle_state,
+            cycle_number,
+            proxy_bid,
+        )?;
+    }
+    auction_cycle_state.proxy_bid_max = Some(max_amount);
+
+    bid::trigger_encore(
+        &mut auction_cycle_state,
+        auction_config.encore_period,
+        current_timestamp,
+    )?;
+    auction_cycle_state.write(auction_cycle_state_account)?;
+
+    Ok(())
+}
+
+/// Resolves a new bid against the proxy bid backing the top bid.
+///
+/// If the new bid does not exceed the maximum of the proxy bid, the proxy bid
+/// is raised to the minimum step above the new bid (but at most to its
+/// maximum), and both bids are recorded in the bid history. Earlier proxy
+/// bids win ties. The raise is covered by the deposit of the proxy bid.
+///
+/// Returns the counter-bid of the proxy bid, or `None` and removes the proxy
+/// bid from the cycle if it is exhausted, i.e. the new bid becomes the top
+/// bid.
+pub(super) fn counter_with_proxy_bid(
+    auction_cycle_state: &mut AuctionCycleState,
+    auction_config: &AuctionConfig,
+    new_bid: BidData,
+) -> Result<Option<BidData>, AuctionContractError> {
+    let proxy_bid_max = auction_cycle_state
+        .proxy_bid_max
+        .ok_or(AuctionContractError::InvalidBidAmount)?;
+    let top_bid = auction_cycle_state
+        .bid_history
+        .get_last_element()
+        .cloned()
+        .ok_or(AuctionContractError::InvalidBidAmount)?;
+
+    if new_bid.bid_amount > proxy_bid_max {
+        auction_cycle_state.proxy_bid_max = None;
+        return Ok(None);
+    }
+
+    let counter_amount = auction_config.proxy_bid_amount(
+        auction_config.next_valid_bid(new_bid.bid_amount)?,
+        proxy_bid_max,
+    );
+
+    let counter_bid = BidData {
+        bid_amount: counter_amount,
+        bidder_pubkey: top_bid.bidder_pubkey,
+        memo: None,
+        referrer: None,
+    };
+    auction_cycle_state.bid_history.cyclic_push(new_bid);
+    auction_cycle_state
+        .bid_history
+        .cyclic_push(counter_bid.clone());
+
+    Ok(Some(counter_bid))
+}
@@ -0,0 +1,118 @@
+use super::*;
+
+/// Refunds the payment of a winner of an all-or-nothing auction that ended
+/// (or is being deleted) without reaching its funding goal.
+///
+/// Anyone may pay for the refund, the funds are always returned to the
+/// winner.
+pub fn process_refund_failed_goal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+    cycle_number: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let payer_account = next_account_info(account_info_iter)?; // 1
+    let bidder_account = next_account_info(account_info_iter)?; // 2
+    let auction_bank_account = next_account_info(account_info_iter)?; // 3
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 4
+    let auction_cycle_state_account = next_account_info(account_info_iter)?; // 5
+
+    if !payer_account.is_signer {
+        msg!("payer signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check pda addresses
+    SignerPda::check_owner(
+        &auction_root_state_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let cycle_num_bytes = cycle_number.to_le_bytes();
+    SignerPda::check_owner(
+        &auction_cycle_state_seeds(auction_root_state_account.key, &cycle_num_bytes),
+        program_id,
+        program_id,
+        auction_cycle_state_account,
+    )?;
+
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    if !auction_root_state.is_goal_failed() {
+        return Err(AuctionContractError::FundingGoalNotFailed.into());
+    }
+
+    let mut auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
+    let record_index = auction_cycle_state
+        .settlement_records
+        .contents()
+        .iter()
+        .position(|record| &record.bidder_pubkey == bidder_account.key)
+        .ok_or(AuctionContractError::NothingToWithdraw)?;
+    let refund_amount = auction_cycle_state.settlement_records.contents()[record_index].amount;
+
+    if let Some(bid_mint) = auction_root_state.auction_config.bid_mint {
+        let token_program = next_account_info(account_info_iter)?; // 6
+        let contract_pda = next_account_info(account_info_iter)?; // 7
+        let auction_vault_account = next_account_info(account_info_iter)?; // 8
+        let bidder_holding_account = next_account_info(account_info_iter)?; // 9
+
+        // Check cross-program invocation addresses
+        assert_token_program(token_program.key)?;
+
+        // Check pda addresses
+        let contract_pda_seeds = contract_pda_seeds();
+        let contract_signer_pda =
+            SignerPda::new_checked(&contract_pda_seeds, program_id, contract_pda)?;
+
+        SignerPda::check_owner(
+            &auction_vault_seeds(&auction_id),
+            program_id,
+            &TOKEN_ID,
+            auction_vault_account,
+        )?;
+
+        SignerPda::check_owner(
+            &token_holding_seeds(&bid_mint, bidder_account.key),
+            program_id,
+            &TOKEN_ID,
+            bidder_holding_account,
+        )?;
+
+        transfer_tokens(
+            auction_vault_account,
+            bidder_holding_account,
+            contract_pda,
+            contract_signer_pda.signer_seeds(),
+            token_program,
+            refund_amount,
+        )?;
+    } else {
+        SignerPda::check_owner(
+            &auction_bank_seeds(&auction_id),
+            program_id,
+            program_id,
+            auction_bank_account,
+        )?;
+
+        checked_debit_account(auction_bank_account, refund_amount)?;
+        checked_credit_account(bidder_account, refund_amount)?;
+    }
+
+    // The refunded payment is removed from the settlement records, so that it
+    // cannot be refunded twice
+    auction_cycle_state.settlement_records.remove(record_index);
+    auction_cycle_state.write(auction_cycle_state_account)?;
+
+    auction_root_state.escrowed_funds = auction_root_state
+        .escrowed_funds
+        .checked_sub(refund_amount)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    auction_root_state.available_funds = auction_root_state
+        .available_funds
+        .checked_sub(refund_amount)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    auction_root_state.write(auction_root_state_account)
+}
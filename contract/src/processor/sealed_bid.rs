@@ -0,0 +1,321 @@
+use super::*;
+
+use solana_program::clock::UnixTimestamp;
+
+/// Commits a sealed bid to the current cycle of a sealed-bid auction.
+///
+/// The bidder locks a deposit in a commitment account that is released when
+/// the bid is revealed after the cycle's end time.
+///
+/// A stale commitment (committed before the cycle was extended) is replaced
+/// and its deposit is returned to the bidder.
+pub fn process_commit_bid(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+    commitment: [u8; 32],
+    deposit: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let user_main_account = next_account_info(account_info_iter)?; // 1
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 2
+    let auction_cycle_state_account = next_account_info(account_info_iter)?; // 3
+    let bid_commitment_account = next_account_info(account_info_iter)?; // 4
+    let auction_pool_account = next_account_info(account_info_iter)?; // 5
+    let secondary_pool_account = next_account_info(account_info_iter)?; // 6
+    let system_program = next_account_info(account_info_iter)?; // 7
+
+    // Check if user is signer
+    if !user_main_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    // Check cross-program invocation addresses
+    assert_system_program(system_program.key)?;
+    // Check root and cycle states
+    SignerPda::check_owner(
+        &auction_root_state_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+
+    let cycle_num = auction_root_state
+        .status
+        .current_auction_cycle
+        .to_le_bytes();
+
+    SignerPda::check_owner(
+        &auction_cycle_state_seeds(auction_root_state_account.key, &cycle_num),
+        program_id,
+        program_id,
+        auction_cycle_state_account,
+    )?;
+
+    let mut auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
+
+    // check auction pools
+    SignerPda::check_owner(
+        &auction_pool_seeds(),
+        program_id,
+        program_id,
+        auction_pool_account,
+    )?;
+
+    SignerPda::check_owner(
+        &secondary_pool_seeds(),
+        program_id,
+        program_id,
+        secondary_pool_account,
+    )?;
+
+    // Check status and deposit
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    bid::activate_auction(
+        &auction_id,
+        auction_pool_account,
+        secondary_pool_account,
+        &mut auction_cycle_state,
+        auction_root_state.auction_config.cycle_period,
+        current_timestamp,
+    )?;
+
+    check_status(
+        &auction_root_state,
+        &auction_cycle_state,
+        current_timestamp,
+        AuctionInteraction::CommitBid,
+    )?;
+
+    if deposit < auction_root_state.auction_config.minimum_bid_amount {
+        return Err(AuctionContractError::InvalidBidAmount.into());
+    }
+
+    // Create the commitment account (one per bidder in each cycle)
+    let bid_commitment_seeds =
+        bid_commitment_seeds(auction_cycle_state_account.key, user_main_account.key);
+    let bid_commitment_pda =
+        SignerPda::new_checked(&bid_commitment_seeds, program_id, bid_commitment_account)?;
+
+    if bid_commitment_account.data_is_empty() {
+        create_state_account(
+            user_main_account,
+            bid_commitment_account,
+            bid_commitment_pda.signer_seeds(),
+            program_id,
+            system_program,
+            BidCommitment::MAX_SERIALIZED_LEN,
+        )?;
+    } else {
+        let stale_commitment = BidCommitment::read(bid_commitment_account)?;
+        if !stale_commitment.is_stale(&auction_cycle_state) {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+        checked_debit_account(bid_commitment_account, stale_commitment.deposit)?;
+        checked_credit_account(user_main_account, stale_commitment.deposit)?;
+    }
+
+    // Lock the deposit in the commitment account
+    let lamport_transfer_ix =
+        system_instruction::transfer(user_main_account.key, bid_commitment_account.key, deposit);
+
+    invoke(
+        &lamport_transfer_ix,
+        &[
+            user_main_account.to_owned(),
+            bid_commitment_account.to_owned(),
+            system_program.to_owned(),
+        ],
+    )?;
+
+    let bid_commitment = BidCommitment {
+        bidder_pubkey: *user_main_account.key,
+        commitment,
+        deposit,
+        end_time: auction_cycle_state.end_time,
+        paused_duration: auction_cycle_state.paused_duration,
+    };
+    bid_commitment.write(bid_commitment_account)?;
+
+    auction_cycle_state.write(auction_cycle_state_account)?;
+
+    Ok(())
+}
+
+/// Reveals a sealed bid committed to a cycle of a sealed-bid auction.
+///
+/// Valid bids revealed within the reveal window that are higher than the
+/// current top bid become the new top bid and are recorded as a bid deposit,
+/// which the previous top bidder can withdraw from once outbid. The rest of
+/// the commitment deposit (or the whole deposit if the bid is not the top
+/// bid) is returned to the bidder and the commitment account is closed. This
+/// way `close_auction_cycle` settles the cycle to the highest valid revealed
+/// bid.
+///
+/// Bids revealed after the reveal window (or stale bids committed before the
+/// cycle was extended) only release the deposit.
+pub fn process_reveal_bid(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+    cycle_number: u64,
+    amount: u64,
+    salt: [u8; 32],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let user_main_account = next_account_info(account_info_iter)?; // 1
+    let auction_bank_account = next_account_info(account_info_iter)?; // 2
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 3
+    let auction_cycle_state_account = next_account_info(account_info_iter)?; // 4
+    let bid_commitment_account = next_account_info(account_info_iter)?; // 5
+    let bid_deposit_account = next_account_info(account_info_iter)?; // 6
+    let system_program = next_account_info(account_info_iter)?; // 7
+    let bid_log_page_account = next_account_info(account_info_iter)?; // 8
+
+    // Check if user is signer
+    if !user_main_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    // Check cross-program invocation addresses
+    assert_system_program(system_program.key)?;
+    // Check root and cycle states
+    SignerPda::check_owner(
+        &auction_root_state_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+
+    SignerPda::check_owner(
+        &auction_cycle_state_seeds(auction_root_state_account.key, &cycle_number.to_le_bytes()),
+        program_id,
+        program_id,
+        auction_cycle_state_account,
+    )?;
+
+    let mut auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
+
+    // Check the commitment
+    SignerPda::check_owner(
+        &bid_commitment_seeds(auction_cycle_state_account.key, user_main_account.key),
+        program_id,
+        program_id,
+        bid_commitment_account,
+    )?;
+
+    let bid_commitment = BidCommitment::read(bid_commitment_account)?;
+    if BidCommitment::hash(amount, &salt) != bid_commitment.commitment {
+        return Err(AuctionContractError::BidCommitmentMismatch.into());
+    }
+
+    // check auction bank
+    SignerPda::check_owner(
+        &auction_bank_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_bank_account,
+    )?;
+
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    // Stale commitments are released even before the end of the extended
+    // cycle
+    let is_stale = bid_commitment.is_stale(&auction_cycle_state);
+    if !is_stale
+        && is_revealed_in_time(
+            &auction_root_state,
+            &auction_cycle_state,
+            cycle_number,
+            current_timestamp,
+        )?
+        && amount <= bid_commitment.deposit
+        && check_bid_amount(
+            &auction_root_state,
+            &auction_cycle_state,
+            user_main_account.key,
+            amount,
+            current_timestamp,
+        )
+        .is_ok()
+    {
+        // Transfer the bid from the deposit to the auction bank
+        checked_debit_account(bid_commitment_account, amount)?;
+        checked_credit_account(auction_bank_account, amount)?;
+
+        withdraw_refund::record_deposit(
+            program_id,
+            user_main_account,
+            bid_deposit_account,
+            auction_cycle_state_account.key,
+            system_program,
+            &mut auction_root_state,
+            amount,
+        )?;
+        auction_root_state.write(auction_root_state_account)?;
+
+        let revealed_bid = BidData {
+            bid_amount: amount,
+            bidder_pubkey: *user_main_account.key,
+            memo: None,
+            referrer: None,
+        };
+        auction_cycle_state
+            .bid_history
+            .cyclic_push(revealed_bid.clone());
+        bid::log_bid(
+            program_id,
+            user_main_account,
+            &[bid_log_page_account],
+            auction_root_state_account.key,
+            system_program,
+            &mut auction_cycle_state,
+            cycle_number,
+            revealed_bid,
+        )?;
+        auction_cycle_state.write(auction_cycle_state_account)?;
+    }
+
+    // Return the rest of the deposit and close the commitment account
+    let remaining_lamports = **bid_commitment_account.lamports.borrow();
+    checked_debit_account(bid_commitment_account, remaining_lamports)?;
+    checked_credit_account(user_main_account, remaining_lamports)?;
+
+    Ok(())
+}
+
+/// Returns `true` if the bid is revealed within the reveal window of the
+/// auction's current cycle.
+///
+/// Bids of past cycles and bids of frozen or finished auctions can no longer
+/// win, thus their deposits are released. Revealing a bid before the end of
+/// the cycle or while the auction is paused is not allowed.
+fn is_revealed_in_time(
+    auction_root_state: &AuctionRootState,
+    auction_cycle_state: &AuctionCycleState,
+    cycle_number: u64,
+    current_timestamp: UnixTimestamp,
+) -> Result<bool, AuctionContractError> {
+    if cycle_number != auction_root_state.status.current_auction_cycle
+        || auction_root_state.status.is_frozen
+        || auction_root_state.status.is_finished
+    {
+        return Ok(false);
+    }
+    match check_status(
+        auction_root_state,
+        auction_cycle_state,
+        current_timestamp,
+        AuctionInteraction::RevealBid,
+    ) {
+        Ok(()) => Ok(true),
+        // The reveal window is over
+        Err(AuctionContractError::AuctionCycleEnded) => Ok(false),
+        Err(error) => Err(error),
+    }
+}
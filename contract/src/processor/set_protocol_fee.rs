@@ -1,9 +1,13 @@
 use super::*;
+use crate::MAX_PROTOCOL_FEE;
+
+use solana_program::rent::Rent;
 
 pub fn process_set_protocol_fee(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    new_fee: u8,
+    new_fee: u16,
+    new_verified_fee: Option<u16>,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
     let contract_admin_account = next_account_info(account_info_iter)?;
@@ -36,8 +40,12 @@ pub fn process_set_protocol_fee(
         return Err(AuctionContractError::ContractAdminMismatch.into());
     }
 
+    if new_fee > MAX_PROTOCOL_FEE || new_verified_fee.map_or(false, |fee| fee > MAX_PROTOCOL_FEE) {
+        return Err(AuctionContractError::InvalidProtocolFee.into());
+    }
+
     // Check fee account owner or create it if necessary
-    let mut fee_state = if protocol_fee_state_account.data_is_empty() {
+    if protocol_fee_state_account.data_is_empty() {
         create_state_account(
             contract_admin_account,
             protocol_fee_state_account,
@@ -46,18 +54,90 @@ pub fn process_set_protocol_fee(
             system_program,
             ProtocolFeeState::MAX_SERIALIZED_LEN,
         )?;
-        ProtocolFeeState { fee: 50 }
     } else {
         assert_owner(protocol_fee_state_account, program_id)?;
-        ProtocolFeeState::read(protocol_fee_state_account)?
+        // Migrate the legacy state (a single fee in thousandths) by resizing
+        // it to hold the fee in basis points and the fee tiers as well
+        if protocol_fee_state_account.data_len() < ProtocolFeeState::MAX_SERIALIZED_LEN {
+            let rent = Rent::get()?;
+            let rent_difference = rent
+                .minimum_balance(ProtocolFeeState::MAX_SERIALIZED_LEN)
+                .saturating_sub(**protocol_fee_state_account.lamports.borrow());
+            if rent_difference > 0 {
+                let transfer_ix = system_instruction::transfer(
+                    contract_admin_account.key,
+                    protocol_fee_state_account.key,
+                    rent_difference,
+                );
+                invoke(
+                    &transfer_ix,
+                    &[
+                        contract_admin_account.to_owned(),
+                        protocol_fee_state_account.to_owned(),
+                        system_program.to_owned(),
+                    ],
+                )?;
+            }
+            protocol_fee_state_account.realloc(ProtocolFeeState::MAX_SERIALIZED_LEN, false)?;
+        }
+    }
+
+    let fee_state = ProtocolFeeState {
+        fee: new_fee,
+        verified_fee: new_verified_fee,
     };
+    fee_state.write(protocol_fee_state_account)?;
 
-    if new_fee > 50 {
+    Ok(())
+}
+
+/// Sets the protocol fee collected on the funds claimed from an auction,
+/// overriding the fee tiers and the global fee.
+///
+/// `None` removes the override.
+pub fn process_set_auction_protocol_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+    new_fee: Option<u16>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let contract_admin_account = next_account_info(account_info_iter)?;
+    let contract_bank_account = next_account_info(account_info_iter)?;
+    let auction_root_state_account = next_account_info(account_info_iter)?;
+
+    if !contract_admin_account.is_signer {
+        msg!("admin signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check pda addresses
+    SignerPda::check_owner(
+        &contract_bank_seeds(),
+        program_id,
+        program_id,
+        contract_bank_account,
+    )?;
+
+    let contract_bank_state = ContractBankState::read(contract_bank_account)?;
+    if contract_admin_account.key != &contract_bank_state.contract_admin {
+        return Err(AuctionContractError::ContractAdminMismatch.into());
+    }
+
+    SignerPda::check_owner(
+        &auction_root_state_seeds(&auction_id),
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    if new_fee.map_or(false, |fee| fee > MAX_PROTOCOL_FEE) {
         return Err(AuctionContractError::InvalidProtocolFee.into());
     }
 
-    fee_state.fee = new_fee;
-    fee_state.write(protocol_fee_state_account)?;
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    auction_root_state.protocol_fee_override = new_fee;
+    auction_root_state.write(auction_root_state_account)?;
 
     Ok(())
 }
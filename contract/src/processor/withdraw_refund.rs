@@ -0,0 +1,216 @@
+use super::*;
+
+/// Withdraws the part of a bidder's deposit in an auction cycle that is not
+/// locked by their bids, i.e. their outbid bids and the surplus of their
+/// settled winning bid.
+///
+/// Anyone may pay for the withdrawal, the funds are always returned to the
+/// bidder. The deposit account is closed once nothing is left to withdraw
+/// from it.
+pub fn process_withdraw_refund(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auction_id: AuctionId,
+    cycle_number: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let payer_account = next_account_info(account_info_iter)?; // 1
+    let bidder_account = next_account_info(account_info_iter)?; // 2
+    let auction_bank_account = next_account_info(account_info_iter)?; // 3
+    let auction_root_state_account = next_account_info(account_info_iter)?; // 4
+    let auction_cycle_state_account = next_account_info(account_info_iter)?; // 5
+    let bid_deposit_account = next_account_info(account_info_iter)?; // 6
+
+    if !payer_account.is_signer {
+        msg!("payer signature is missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check pda addresses
+    let auction_root_state_seeds = auction_root_state_seeds(&auction_id);
+    SignerPda::new_checked(
+        &auction_root_state_seeds,
+        program_id,
+        auction_root_state_account,
+    )?;
+
+    let cycle_num_bytes = cycle_number.to_le_bytes();
+    let auction_cycle_state_seeds =
+        auction_cycle_state_seeds(auction_root_state_account.key, &cycle_num_bytes);
+    SignerPda::new_checked(
+        &auction_cycle_state_seeds,
+        program_id,
+        auction_cycle_state_account,
+    )?;
+
+    SignerPda::check_owner(
+        &bid_deposit_seeds(auction_cycle_state_account.key, bidder_account.key),
+        program_id,
+        program_id,
+        bid_deposit_account,
+    )?;
+
+    let mut bid_deposit = BidDeposit::read(bid_deposit_account)?;
+
+    // Cycle states are only deleted once every deposit is settled or
+    // withdrawn, thus there is nothing to withdraw from the remaining
+    // deposit accounts
+    if auction_cycle_state_account.data_is_empty() {
+        return close_bid_deposit(bid_deposit_account, bidder_account);
+    }
+
+    SignerPda::check_owner(
+        &auction_root_state_seeds,
+        program_id,
+        program_id,
+        auction_root_state_account,
+    )?;
+    assert_owner(auction_cycle_state_account, program_id)?;
+
+    let mut auction_root_state = AuctionRootState::read(auction_root_state_account)?;
+    let auction_cycle_state = AuctionCycleState::read(auction_cycle_state_account)?;
+
+    let locked_amount = auction_cycle_state.locked_deposit(
+        &auction_root_state,
+        cycle_number,
+        bidder_account.key,
+    )?;
+    let withdrawable_amount = bid_deposit
+        .amount
+        .checked_sub(locked_amount)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    // The deposit of a settled cycle cannot change anymore
+    let is_settled = cycle_number < auction_root_state.status.current_auction_cycle
+        || auction_root_state.status.is_finished;
+    let is_closable = is_settled || locked_amount == 0;
+
+    if withdrawable_amount == 0 && !is_closable {
+        if auction_cycle_state.proxy_bid_max.is_some() {
+            return Err(AuctionContractError::ProxyBidActive.into());
+        }
+        return Err(AuctionContractError::NothingToWithdraw.into());
+    }
+
+    if withdrawable_amount > 0 {
+        if let Some(bid_mint) = auction_root_state.auction_config.bid_mint {
+            let token_program = next_account_info(account_info_iter)?; // 7
+            let contract_pda = next_account_info(account_info_iter)?; // 8
+            let auction_vault_account = next_account_info(account_info_iter)?; // 9
+            let bidder_holding_account = next_account_info(account_info_iter)?; // 10
+
+            // Check cross-program invocation addresses
+            assert_token_program(token_program.key)?;
+
+            // Check pda addresses
+            let contract_pda_seeds = contract_pda_seeds();
+            let contract_signer_pda =
+                SignerPda::new_checked(&contract_pda_seeds, program_id, contract_pda)?;
+
+            SignerPda::check_owner(
+                &auction_vault_seeds(&auction_id),
+                program_id,
+                &TOKEN_ID,
+                auction_vault_account,
+            )?;
+
+            SignerPda::check_owner(
+                &token_holding_seeds(&bid_mint, bidder_account.key),
+                program_id,
+                &TOKEN_ID,
+                bidder_holding_account,
+            )?;
+
+            transfer_tokens(
+                auction_vault_account,
+                bidder_holding_account,
+                contract_pda,
+                contract_signer_pda.signer_seeds(),
+                token_program,
+                withdrawable_amount,
+            )?;
+        } else {
+            SignerPda::check_owner(
+                &auction_bank_seeds(&auction_id),
+                program_id,
+                program_id,
+                auction_bank_account,
+            )?;
+
+            checked_debit_account(auction_bank_account, withdrawable_amount)?;
+            checked_credit_account(bidder_account, withdrawable_amount)?;
+        }
+
+        auction_root_state.unsettled_deposits = auction_root_state
+            .unsettled_deposits
+            .checked_sub(withdrawable_amount)
+            .ok_or(AuctionContractError::ArithmeticError)?;
+        auction_root_state.write(auction_root_state_account)?;
+    }
+
+    if is_closable {
+        close_bid_deposit(bid_deposit_account, bidder_account)
+    } else {
+        bid_deposit.amount = locked_amount;
+        bid_deposit.write(bid_deposit_account)?;
+        Ok(())
+    }
+}
+
+/// Records a deposit of the bidder into the auction bank (or vault), creating
+/// their deposit account for the cycle if needed.
+pub(super) fn record_deposit<'a>(
+    program_id: &Pubkey,
+    user_main_account: &AccountInfo<'a>,
+    bid_deposit_account: &AccountInfo<'a>,
+    auction_cycle_state_pubkey: &Pubkey,
+    system_program: &AccountInfo<'a>,
+    auction_root_state: &mut AuctionRootState,
+    amount: u64,
+) -> ProgramResult {
+    let bid_deposit_seeds = bid_deposit_seeds(auction_cycle_state_pubkey, user_main_account.key);
+    let bid_deposit_pda =
+        SignerPda::new_checked(&bid_deposit_seeds, program_id, bid_deposit_account)?;
+
+    let mut bid_deposit = if bid_deposit_account.data_is_empty() {
+        create_state_account(
+            user_main_account,
+            bid_deposit_account,
+            bid_deposit_pda.signer_seeds(),
+            program_id,
+            system_program,
+            BidDeposit::MAX_SERIALIZED_LEN,
+        )?;
+        BidDeposit {
+            bidder_pubkey: *user_main_account.key,
+            amount: 0,
+        }
+    } else {
+        assert_owner(bid_deposit_account, program_id)?;
+        BidDeposit::read(bid_deposit_account)?
+    };
+
+    bid_deposit.amount = bid_deposit
+        .amount
+        .checked_add(amount)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+    bid_deposit.write(bid_deposit_account)?;
+
+    auction_root_state.unsettled_deposits = auction_root_state
+        .unsettled_deposits
+        .checked_add(amount)
+        .ok_or(AuctionContractError::ArithmeticError)?;
+
+    Ok(())
+}
+
+/// Closes the deposit account, returning its rent to the bidder.
+fn close_bid_deposit(
+    bid_deposit_account: &AccountInfo,
+    bidder_account: &AccountInfo,
+) -> ProgramResult {
+    let remaining_lamports = **bid_deposit_account.lamports.borrow();
+    checked_debit_account(bid_deposit_account, remaining_lamports)?;
+    checked_credit_account(bidder_account, remaining_lamports)?;
+    Ok(())
+}
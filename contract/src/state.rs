@@ -1,10 +1,17 @@
 use crate::error::AuctionContractError;
-use crate::{MAX_BID_HISTORY_LENGTH, MAX_DESCRIPTION_LEN, MAX_SOCIALS_LEN, MAX_SOCIALS_NUM};
+use crate::{
+    BID_LOG_PAGE_LENGTH, DEFAULT_PROTOCOL_FEE, EXTRA_ROOT_STATE_BYTES, MAX_BASIS_POINTS,
+    MAX_BENEFICIARIES, MAX_BID_HISTORY_LENGTH, MAX_BID_MEMO_LEN, MAX_DESCRIPTION_LEN,
+    MAX_SOCIALS_LEN, MAX_SOCIALS_NUM, MAX_WINNERS_PER_CYCLE,
+};
 
 use agsol_borsh_schema::BorshSchema;
 use agsol_common::{AccountState, MaxLenString, MaxLenVec, MaxSerializedLen};
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
 use solana_program::clock::UnixTimestamp;
+use solana_program::hash::hashv;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
 use agsol_token_metadata::instruction::CreateMetadataAccountArgs;
@@ -17,12 +24,20 @@ pub type AuctionId = [u8; 32];
 pub type AuctionName = [u8; 32];
 /// Vector of the most recent bids submitted to a given auction.
 pub type BidHistory = MaxLenVec<BidData, MAX_BID_HISTORY_LENGTH>;
+/// Vector of consecutive bids submitted to a given auction cycle.
+pub type BidLog = MaxLenVec<BidData, BID_LOG_PAGE_LENGTH>;
+/// A short message attached to a bid by the bidder.
+pub type BidMemo = MaxLenString<MAX_BID_MEMO_LEN>;
 /// A string containing the description of the auction.
 pub type DescriptionString = MaxLenString<MAX_DESCRIPTION_LEN>;
 /// A string containing a social url (Discord, Telegram, etc.) of the auction.
 pub type SocialsString = MaxLenString<MAX_SOCIALS_LEN>;
 /// A vector containing socials (Discord, Telegram, etc.) of the auction.
 pub type SocialsVec = MaxLenVec<SocialsString, MAX_SOCIALS_NUM>;
+/// A vector containing the beneficiaries of the auction.
+pub type BeneficiaryVec = MaxLenVec<Beneficiary, MAX_BENEFICIARIES>;
+/// Vector of the payments of the winners of a settled auction cycle.
+pub type SettlementRecordVec = MaxLenVec<SettlementRecord, { MAX_WINNERS_PER_CYCLE as usize }>;
 
 /// Provides key information on a given auction.
 #[repr(C)]
@@ -38,6 +53,43 @@ pub struct AuctionDescription {
     pub goal_treasury_amount: Option<u64>,
 }
 
+/// A recipient of a share of the funds claimed from an auction.
+#[repr(C)]
+#[derive(
+    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy, PartialEq,
+)]
+pub struct Beneficiary {
+    /// Account receiving the share.
+    pub pubkey: Pubkey,
+    /// Share of the claimed funds (after the protocol fee) in basis points.
+    pub share_bps: u16,
+}
+
+/// Splits the claimed `amount` among the beneficiaries.
+///
+/// Returns the shares of the beneficiaries (in the order of the
+/// beneficiaries) and the remainder that is paid to the recipient of the
+/// claimed funds.
+pub fn split_among_beneficiaries(
+    beneficiaries: &[Beneficiary],
+    amount: u64,
+) -> Result<(Vec<u64>, u64), AuctionContractError> {
+    let mut remainder = amount;
+    let mut shares = Vec::with_capacity(beneficiaries.len());
+    for beneficiary in beneficiaries {
+        let share = u128::from(amount)
+            .checked_mul(u128::from(beneficiary.share_bps))
+            .ok_or(AuctionContractError::ArithmeticError)?
+            / u128::from(MAX_BASIS_POINTS);
+        let share = u64::try_from(share).map_err(|_| AuctionContractError::ArithmeticError)?;
+        remainder = remainder
+            .checked_sub(share)
+            .ok_or(AuctionContractError::ArithmeticError)?;
+        shares.push(share);
+    }
+    Ok((shares, remainder))
+}
+
 /// Contains which information to modify on a ModifyAuction call.
 /// If a member is set to `None` it will be unchanged.
 #[repr(C)]
@@ -48,6 +100,11 @@ pub struct ModifyAuctionData {
     #[alias(Option<Vec<String>>)]
     pub new_socials: Option<SocialsVec>,
     pub new_encore_period: Option<UnixTimestamp>,
+    /// `Some(None)` removes the minimum bid increment.
+    pub new_min_bid_increment: Option<Option<BidIncrement>>,
+    #[alias(Option<Vec<Beneficiary>>)]
+    pub new_beneficiaries: Option<BeneficiaryVec>,
+    pub new_beneficiary: Option<Pubkey>,
 }
 
 /// The main configuration parameters of an auction.
@@ -60,8 +117,291 @@ pub struct AuctionConfig {
     pub encore_period: UnixTimestamp,
     /// Number of auction cycles taking place throughout the fundraiser.
     pub number_of_cycles: Option<u64>,
-    /// Minimum bid amount accepted (in lamports).
+    /// Minimum bid amount accepted (in lamports or in the smallest unit of the
+    /// bid mint).
     pub minimum_bid_amount: u64,
+    /// Mint of the SPL token in which bids are placed.
+    ///
+    /// Bids are placed in lamports if it is not set.
+    pub bid_mint: Option<Pubkey>,
+    /// Minimum price the top bid has to reach for the asset to be sold in an
+    /// auction cycle.
+    pub reserve_price: Option<ReservePrice>,
+    /// Minimum amount by which a new bid has to exceed the current top bid.
+    pub min_bid_increment: Option<BidIncrement>,
+    /// Bids reaching this price win the current auction cycle immediately.
+    pub buy_now_price: Option<u64>,
+    /// Determines how the winning bid of an auction cycle is selected.
+    pub kind: AuctionKind,
+    /// If set, the winner of a cycle only pays the second-highest bid plus
+    /// the minimum bid increment (only supported by English auctions).
+    pub second_price_settlement: bool,
+    /// Number of distinct bidders winning an edition (or token allotment) in
+    /// each auction cycle.
+    pub winners_per_cycle: u8,
+    /// Share of the price paid by a winner that is rewarded to the referrer
+    /// of their bid (in basis points, only supported by auctions accepting SOL
+    /// bids).
+    pub referral_share_bps: u16,
+    /// If set, the proceeds are held in escrow until the funding goal of the
+    /// auction is met. The winners may reclaim their payments if the goal is
+    /// not met by the end of the auction.
+    pub all_or_nothing: bool,
+    /// If set, the auction finishes as soon as the funds raised reach its
+    /// funding goal, even if there are cycles left.
+    pub finish_on_goal: bool,
+}
+
+/// Schedule of releasing the funds raised by an auction.
+///
+/// The funds vest linearly from the start of the auction, but none of them can
+/// be claimed before the cliff.
+#[repr(C)]
+#[derive(
+    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy, PartialEq,
+)]
+pub struct VestingSchedule {
+    /// Time from the start of the auction until the first funds vest (in
+    /// seconds).
+    pub cliff_period: UnixTimestamp,
+    /// Time from the start of the auction until all funds vest (in seconds).
+    pub vesting_period: UnixTimestamp,
+}
+
+impl VestingSchedule {
+    /// Returns the vested part of `amount` at `timestamp` if the schedule
+    /// started at `start_time`.
+    pub fn vested_amount(
+        &self,
+        amount: u64,
+        start_time: UnixTimestamp,
+        timestamp: UnixTimestamp,
+    ) -> u64 {
+        let elapsed = timestamp.saturating_sub(start_time);
+        if elapsed < self.cliff_period || elapsed <= 0 {
+            return 0;
+        }
+        if elapsed >= self.vesting_period {
+            return amount;
+        }
+        // elapsed < vesting_period, thus the result is less than amount
+        (u128::from(amount) * elapsed as u128 / self.vesting_period as u128) as u64
+    }
+}
+
+/// The kind of an auction.
+#[repr(C)]
+#[derive(BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy)]
+pub enum AuctionKind {
+    /// Ascending price auction where the top bid wins at the end of the cycle.
+    English,
+    /// Descending price auction where the first bid reaching the current
+    /// price wins and closes the cycle.
+    Dutch(DutchPriceCurve),
+    /// Sealed-bid auction where bids are committed as hashes during the cycle
+    /// and revealed in a reveal window that follows the cycle's end time.
+    ///
+    /// Sealed-bid auctions only accept SOL bids.
+    Sealed(SealedBidConfig),
+}
+
+/// Configuration of a sealed-bid auction.
+#[repr(C)]
+#[derive(BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy)]
+pub struct SealedBidConfig {
+    /// Length of the reveal window following the end of each cycle (in
+    /// seconds).
+    pub reveal_period: UnixTimestamp,
+}
+
+/// The price of a Dutch auction cycle decays linearly from `start_price` to
+/// `floor_price` over the cycle period.
+#[repr(C)]
+#[derive(BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy)]
+pub struct DutchPriceCurve {
+    /// Price at the start of the cycle (in lamports or in the smallest unit
+    /// of the bid mint).
+    pub start_price: u64,
+    /// Price at the end of the cycle (in lamports or in the smallest unit of
+    /// the bid mint).
+    pub floor_price: u64,
+}
+
+impl DutchPriceCurve {
+    /// Returns the price at `timestamp` of a cycle ending at `cycle_end_time`.
+    pub fn price_at(
+        &self,
+        cycle_end_time: UnixTimestamp,
+        cycle_period: UnixTimestamp,
+        timestamp: UnixTimestamp,
+    ) -> u64 {
+        if cycle_period <= 0 || self.start_price <= self.floor_price {
+            return self.floor_price;
+        }
+        let cycle_start_time = cycle_end_time.saturating_sub(cycle_period);
+        let elapsed = timestamp
+            .saturating_sub(cycle_start_time)
+            .clamp(0, cycle_period);
+        let decay = u128::from(self.start_price - self.floor_price) * elapsed as u128
+            / cycle_period as u128;
+        // decay is at most `start_price - floor_price`, thus it fits into u64
+        self.start_price - decay as u64
+    }
+}
+
+/// Price below which the auctioned asset is not sold in an auction cycle.
+///
+/// If the top bid is lower than the reserve price when the cycle is closed,
+/// the bid is refunded and the cycle is treated as an idle one.
+#[repr(C)]
+#[derive(BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy)]
+pub struct ReservePrice {
+    /// The reserve price (in lamports or in the smallest unit of the bid
+    /// mint).
+    pub amount: u64,
+    /// The frontend only shows whether the reserve price has been met if it
+    /// is hidden.
+    pub is_hidden: bool,
+}
+
+/// Minimum step between consecutive bids of an auction cycle.
+///
+/// The larger of the two increments is required, but a new bid has to be at
+/// least one unit higher than the current top bid in any case.
+#[repr(C)]
+#[derive(BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy)]
+pub struct BidIncrement {
+    /// Absolute increment (in lamports or in the smallest unit of the bid
+    /// mint).
+    pub amount: u64,
+    /// Increment relative to the current top bid (in basis points).
+    pub basis_points: u16,
+}
+
+impl AuctionConfig {
+    /// Returns `true` if the bid reaches the reserve price (if any).
+    pub fn is_reserve_met(&self, bid_amount: u64) -> bool {
+        self.reserve_price
+            .map_or(true, |reserve_price| bid_amount >= reserve_price.amount)
+    }
+
+    /// Returns `true` if the bid reaches the buy-now price (if any).
+    pub fn is_buy_now(&self, bid_amount: u64) -> bool {
+        self.buy_now_price
+            .map_or(false, |buy_now_price| bid_amount >= buy_now_price)
+    }
+
+    /// Returns `true` if a winning bid settles the cycle immediately.
+    pub fn settles_on_bid(&self, bid_amount: u64) -> bool {
+        matches!(self.kind, AuctionKind::Dutch(_)) || self.is_buy_now(bid_amount)
+    }
+
+    /// Returns the reward of the referrer of a winning bid, i.e. the referral
+    /// share of the price paid by the winner.
+    pub fn referral_reward(&self, price: u64) -> Result<u64, AuctionContractError> {
+        let reward = u128::from(price)
+            .checked_mul(u128::from(self.referral_share_bps))
+            .ok_or(AuctionContractError::ArithmeticError)?
+            / u128::from(MAX_BASIS_POINTS);
+        u64::try_from(reward).map_err(|_| AuctionContractError::ArithmeticError)
+    }
+
+    /// Returns `true` if bids may be placed via proxy bids.
+    ///
+    /// Proxy bids are only supported by single-winner English auctions
+    /// accepting SOL bids without a buy-now price.
+    pub fn supports_proxy_bids(&self) -> bool {
+        matches!(self.kind, AuctionKind::English)
+            && self.winners_per_cycle == 1
+            && self.bid_mint.is_none()
+            && self.buy_now_price.is_none()
+    }
+
+    /// Returns the amount a proxy bid of at most `max_amount` bids if at least
+    /// `required_amount` is needed to become the top bid.
+    ///
+    /// The bid is raised to the reserve price if the maximum amount allows it.
+    pub fn proxy_bid_amount(&self, required_amount: u64, max_amount: u64) -> u64 {
+        let amount = match self.reserve_price {
+            Some(reserve_price) if reserve_price.amount <= max_amount => {
+                required_amount.max(reserve_price.amount)
+            }
+            _ => required_amount,
+        };
+        amount.min(max_amount)
+    }
+
+    /// Returns the current price of a Dutch auction cycle ending at
+    /// `cycle_end_time`.
+    ///
+    /// Returns `None` in case of English and sealed-bid auctions.
+    pub fn current_price(
+        &self,
+        cycle_end_time: UnixTimestamp,
+        timestamp: UnixTimestamp,
+    ) -> Option<u64> {
+        match self.kind {
+            AuctionKind::English | AuctionKind::Sealed(_) => None,
+            AuctionKind::Dutch(price_curve) => {
+                Some(price_curve.price_at(cycle_end_time, self.cycle_period, timestamp))
+            }
+        }
+    }
+
+    /// Returns the time after which a cycle ending at `cycle_end_time` can be
+    /// closed.
+    ///
+    /// Cycles of sealed-bid auctions can only be closed after their reveal
+    /// window.
+    pub fn cycle_close_time(&self, cycle_end_time: UnixTimestamp) -> UnixTimestamp {
+        match self.kind {
+            AuctionKind::Sealed(sealed_bid_config) => {
+                cycle_end_time.saturating_add(sealed_bid_config.reveal_period)
+            }
+            _ => cycle_end_time,
+        }
+    }
+
+    /// Returns the price paid by the winner of a second-price settled cycle.
+    ///
+    /// The clearing price is the second-highest bid plus the minimum bid
+    /// increment (or the minimum bid amount if there was a single bid), but
+    /// at least the reserve price and at most the winning bid.
+    pub fn clearing_price(
+        &self,
+        winning_bid_amount: u64,
+        second_bid_amount: Option<u64>,
+    ) -> Result<u64, AuctionContractError> {
+        let clearing_price = if let Some(second_bid_amount) = second_bid_amount {
+            self.next_valid_bid(second_bid_amount)?
+        } else {
+            self.minimum_bid_amount
+        };
+        let clearing_price = self.reserve_price.map_or(clearing_price, |reserve_price| {
+            clearing_price.max(reserve_price.amount)
+        });
+        Ok(clearing_price.min(winning_bid_amount))
+    }
+
+    /// Returns the smallest bid amount that outbids the current top bid.
+    pub fn next_valid_bid(&self, top_bid_amount: u64) -> Result<u64, AuctionContractError> {
+        let increment = if let Some(bid_increment) = self.min_bid_increment {
+            let relative_increment = u128::from(top_bid_amount)
+                .checked_mul(u128::from(bid_increment.basis_points))
+                .ok_or(AuctionContractError::ArithmeticError)?
+                / u128::from(MAX_BASIS_POINTS);
+            // relative increment fits into u64 as long as basis points are
+            // not above `MAX_BASIS_POINTS`
+            let relative_increment = u64::try_from(relative_increment)
+                .map_err(|_| AuctionContractError::ArithmeticError)?;
+            relative_increment.max(bid_increment.amount).max(1)
+        } else {
+            1
+        };
+        top_bid_amount
+            .checked_add(increment)
+            .ok_or(AuctionContractError::ArithmeticError)
+    }
 }
 
 /// Current status of the auction.
@@ -84,6 +424,8 @@ pub struct AuctionStatus {
     pub is_filtered: bool,
     /// The auction can be verified by the contract owners.
     pub is_verified: bool,
+    /// Time when the auction was paused by its owner, if it is paused.
+    pub paused_at: Option<UnixTimestamp>,
 }
 
 /// Data of an incoming bid to the contract.
@@ -92,8 +434,26 @@ pub struct AuctionStatus {
 pub struct BidData {
     /// The public key of the bidder's account.
     pub bidder_pubkey: Pubkey,
-    /// The bid amount placed by the bidder (in lamports).
+    /// The bid amount placed by the bidder (in lamports or in the smallest
+    /// unit of the bid mint).
     pub bid_amount: u64,
+    /// Optional message of the bidder.
+    #[alias(Option<String>)]
+    pub memo: Option<BidMemo>,
+    /// Account that referred the bidder to the auction (if any).
+    pub referrer: Option<Pubkey>,
+}
+
+/// Payment of a winner of an auction cycle.
+#[repr(C)]
+#[derive(
+    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy, PartialEq,
+)]
+pub struct SettlementRecord {
+    /// The public key of the winner's account.
+    pub bidder_pubkey: Pubkey,
+    /// Amount paid by the winner.
+    pub amount: u64,
 }
 
 /// Information required to start either an NFT or a token-based fundraiser.
@@ -144,9 +504,7 @@ pub enum TokenConfig {
 /// The main state of a fundraiser that holds data persistent between auction
 /// cycles.
 #[repr(C)]
-#[derive(
-    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, AccountState, Debug, Clone,
-)]
+#[derive(BorshSchema, BorshSerialize, MaxSerializedLen, Debug, Clone)]
 pub struct AuctionRootState {
     /// Name of the auction.
     #[alias([u8; 32])]
@@ -169,19 +527,578 @@ pub struct AuctionRootState {
     pub start_time: UnixTimestamp,
     /// Number of unclaimed rewards
     pub unclaimed_rewards: u16,
+    /// Bid deposits held by the auction bank (or vault) that are not settled
+    /// yet, i.e. they either back bids of the current cycle or they can be
+    /// withdrawn by their bidders.
+    pub unsettled_deposits: u64,
+    /// Account proposed by the owner to take over the ownership of the
+    /// auction. The ownership is transferred once the proposal is accepted.
+    pub pending_owner: Option<Pubkey>,
+    /// Account receiving the claimed funds (defaults to the auction owner).
+    pub beneficiary: Pubkey,
+    /// Accounts receiving a share of the claimed funds besides the auction
+    /// beneficiary, who receives the remainder.
+    #[alias(Vec<Beneficiary>)]
+    pub beneficiaries: BeneficiaryVec,
+    /// Proceeds of an all-or-nothing auction held in escrow until the funding
+    /// goal is met.
+    pub escrowed_funds: u64,
+    /// Schedule of releasing the raised funds (all funds are claimable
+    /// immediately if not set).
+    pub vesting_schedule: Option<VestingSchedule>,
+    /// All-time total funds claimed from this auction.
+    pub claimed_funds: u64,
+    /// Protocol fee set by the contract admin for this auction, overriding
+    /// the fee tiers and the global fee (in basis points).
+    pub protocol_fee_override: Option<u16>,
+}
+
+impl AuctionRootState {
+    /// Length of the legacy accounts allocated before the auction
+    /// configuration was extended.
+    pub const LEGACY_ACCOUNT_LEN: usize =
+        LegacyAuctionRootState::MAX_SERIALIZED_LEN + EXTRA_ROOT_STATE_BYTES;
+
+    /// Returns `true` if the funds raised reach the funding goal (if any).
+    pub fn is_goal_reached(&self) -> bool {
+        self.description
+            .goal_treasury_amount
+            .map_or(true, |goal| self.all_time_treasury >= goal)
+    }
+
+    /// Returns `true` if the escrowed proceeds of an all-or-nothing auction
+    /// are refundable to the winners, i.e. the auction ended (or it is being
+    /// deleted) without reaching its funding goal.
+    pub fn is_goal_failed(&self) -> bool {
+        self.auction_config.all_or_nothing
+            && (self.status.is_finished || self.status.is_frozen)
+            && !self.is_goal_reached()
+    }
+
+    /// Returns the amount of funds that may be claimed at `timestamp`
+    /// according to the vesting schedule.
+    ///
+    /// Returns `None` if the claims are not capped, i.e. there is no vesting
+    /// schedule or all funds have vested.
+    pub fn vested_unclaimed_funds(&self, timestamp: UnixTimestamp) -> Option<u64> {
+        let schedule = self.vesting_schedule?;
+        if timestamp.saturating_sub(self.start_time) >= schedule.vesting_period {
+            return None;
+        }
+        let vested_funds =
+            schedule.vested_amount(self.all_time_treasury, self.start_time, timestamp);
+        Some(vested_funds.saturating_sub(self.claimed_funds))
+    }
+}
+
+// The state is always deserialized from the whole account data, thus the
+// legacy layout is told apart by its length
+impl BorshDeserialize for AuctionRootState {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() == Self::LEGACY_ACCOUNT_LEN {
+            return LegacyAuctionRootState::deserialize(buf).map(Self::from);
+        }
+        Ok(Self {
+            auction_name: AuctionName::deserialize(buf)?,
+            auction_owner: Pubkey::deserialize(buf)?,
+            description: AuctionDescription::deserialize(buf)?,
+            auction_config: AuctionConfig::deserialize(buf)?,
+            token_config: TokenConfig::deserialize(buf)?,
+            status: AuctionStatus::deserialize(buf)?,
+            all_time_treasury: u64::deserialize(buf)?,
+            available_funds: u64::deserialize(buf)?,
+            start_time: UnixTimestamp::deserialize(buf)?,
+            unclaimed_rewards: u16::deserialize(buf)?,
+            unsettled_deposits: u64::deserialize(buf)?,
+            pending_owner: Option::<Pubkey>::deserialize(buf)?,
+            beneficiary: Pubkey::deserialize(buf)?,
+            beneficiaries: BeneficiaryVec::deserialize(buf)?,
+            escrowed_funds: u64::deserialize(buf)?,
+            vesting_schedule: Option::<VestingSchedule>::deserialize(buf)?,
+            claimed_funds: u64::deserialize(buf)?,
+            protocol_fee_override: Option::<u16>::deserialize(buf)?,
+        })
+    }
+}
+
+// Legacy accounts have to be migrated (resized) before they are written,
+// otherwise the current layout would be read as the legacy one
+impl AccountState for AuctionRootState {
+    fn write(&self, account: &AccountInfo) -> Result<(), ProgramError> {
+        if account.data_len() == Self::LEGACY_ACCOUNT_LEN {
+            return Err(AuctionContractError::AuctionStateNotMigrated.into());
+        }
+        self.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        Ok(())
+    }
+}
+
+/// Layout of the [`AuctionConfig`] before it was extended.
+#[derive(BorshDeserialize, MaxSerializedLen)]
+struct LegacyAuctionConfig {
+    cycle_period: UnixTimestamp,
+    encore_period: UnixTimestamp,
+    number_of_cycles: Option<u64>,
+    minimum_bid_amount: u64,
+}
+
+/// Layout of the [`AuctionStatus`] before auctions could be paused.
+#[derive(BorshDeserialize, MaxSerializedLen)]
+struct LegacyAuctionStatus {
+    current_auction_cycle: u64,
+    current_idle_cycle_streak: u32,
+    is_frozen: bool,
+    is_finished: bool,
+    is_filtered: bool,
+    is_verified: bool,
+}
+
+/// Layout of the [`AuctionRootState`] before the auction configuration was
+/// extended.
+#[derive(BorshDeserialize, MaxSerializedLen)]
+struct LegacyAuctionRootState {
+    auction_name: AuctionName,
+    auction_owner: Pubkey,
+    description: AuctionDescription,
+    auction_config: LegacyAuctionConfig,
+    token_config: TokenConfig,
+    status: LegacyAuctionStatus,
+    all_time_treasury: u64,
+    available_funds: u64,
+    start_time: UnixTimestamp,
+    unclaimed_rewards: u16,
+}
+
+impl From<LegacyAuctionRootState> for AuctionRootState {
+    fn from(legacy: LegacyAuctionRootState) -> Self {
+        let LegacyAuctionConfig {
+            cycle_period,
+            encore_period,
+            number_of_cycles,
+            minimum_bid_amount,
+        } = legacy.auction_config;
+        let LegacyAuctionStatus {
+            current_auction_cycle,
+            current_idle_cycle_streak,
+            is_frozen,
+            is_finished,
+            is_filtered,
+            is_verified,
+        } = legacy.status;
+        Self {
+            auction_name: legacy.auction_name,
+            auction_owner: legacy.auction_owner,
+            description: legacy.description,
+            auction_config: AuctionConfig {
+                cycle_period,
+                encore_period,
+                number_of_cycles,
+                minimum_bid_amount,
+                bid_mint: None,
+                reserve_price: None,
+                min_bid_increment: None,
+                buy_now_price: None,
+                kind: AuctionKind::English,
+                second_price_settlement: false,
+                winners_per_cycle: 1,
+                referral_share_bps: 0,
+                all_or_nothing: false,
+                finish_on_goal: false,
+            },
+            token_config: legacy.token_config,
+            status: AuctionStatus {
+                current_auction_cycle,
+                current_idle_cycle_streak,
+                is_frozen,
+                is_finished,
+                is_filtered,
+                is_verified,
+                paused_at: None,
+            },
+            all_time_treasury: legacy.all_time_treasury,
+            available_funds: legacy.available_funds,
+            start_time: legacy.start_time,
+            unclaimed_rewards: legacy.unclaimed_rewards,
+            // The top bid of the current cycle is recorded as a deposit when
+            // its cycle state is migrated
+            unsettled_deposits: 0,
+            pending_owner: None,
+            beneficiary: legacy.auction_owner,
+            beneficiaries: BeneficiaryVec::new(),
+            escrowed_funds: 0,
+            vesting_schedule: None,
+            claimed_funds: legacy
+                .all_time_treasury
+                .saturating_sub(legacy.available_funds),
+            protocol_fee_override: None,
+        }
+    }
 }
 
 /// State respective to a given auction cycle.
 #[repr(C)]
-#[derive(
-    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, AccountState, Debug, Clone,
-)]
+#[derive(BorshSchema, BorshSerialize, MaxSerializedLen, Debug, Clone)]
 pub struct AuctionCycleState {
     /// When the auction cycle will end (in seconds).
     pub end_time: UnixTimestamp,
     /// The most recent bids of the current auction cycle.
+    ///
+    /// If there are multiple winners per cycle, it is a bid book holding the
+    /// current winning bids of distinct bidders. The bids are sorted in
+    /// ascending order in both cases, i.e. the last bid is the top bid.
     #[alias(Vec<BidData>)]
     pub bid_history: BidHistory,
+    /// Bitmask of the ranks whose rewards have already been claimed.
+    pub claimed_ranks: u16,
+    /// Maximum amount of the proxy bid backing the top bid (if any).
+    pub proxy_bid_max: Option<u64>,
+    /// Number of bids placed on the cycle, i.e. the length of its bid log.
+    pub bid_count: u64,
+    /// Payments of the winners of an all-or-nothing auction that have not
+    /// been refunded, recorded when the cycle is settled.
+    #[alias(Vec<SettlementRecord>)]
+    pub settlement_records: SettlementRecordVec,
+    /// Price paid by the winner of a second-price auction, recorded when the
+    /// cycle is settled.
+    pub settled_price: Option<u64>,
+    /// Total time the end time of the cycle was shifted by resuming the
+    /// paused auction.
+    pub paused_duration: UnixTimestamp,
+}
+
+impl AuctionCycleState {
+    /// Length of the legacy accounts allocated before bids could hold memos
+    /// and referrers.
+    pub const LEGACY_ACCOUNT_LEN: usize = LegacyAuctionCycleState::MAX_SERIALIZED_LEN;
+
+    /// Returns the page of the bid log the next bid is appended to.
+    pub fn bid_log_page(&self) -> u64 {
+        self.bid_count / BID_LOG_PAGE_LENGTH as u64
+    }
+
+    /// Returns the winning bids of the cycle in ascending order.
+    pub fn winning_bids(&self, winners_per_cycle: u8) -> &[BidData] {
+        let bids = self.bid_history.contents();
+        &bids[bids.len().saturating_sub(winners_per_cycle as usize)..]
+    }
+
+    /// Returns the referrers of the winning bids (in ascending order of the
+    /// bids) that are rewarded when the cycle is settled.
+    pub fn rewarded_referrers(&self, auction_config: &AuctionConfig) -> Vec<Pubkey> {
+        if auction_config.referral_share_bps == 0 {
+            return Vec::new();
+        }
+        self.winning_bids(auction_config.winners_per_cycle)
+            .iter()
+            .filter_map(|bid| bid.referrer)
+            .collect()
+    }
+
+    /// Returns the winning bid of the given rank (the top bid has rank 0).
+    pub fn winning_bid(&self, rank: u8, winners_per_cycle: u8) -> Option<&BidData> {
+        self.winning_bids(winners_per_cycle)
+            .iter()
+            .rev()
+            .nth(rank as usize)
+    }
+
+    /// Returns the bid a new bid of `bidder` has to outbid.
+    ///
+    /// This is the top bid if there is a single winner per cycle. Otherwise
+    /// it is the bidder's own bid if they are already among the winners, or
+    /// the lowest winning bid if the bid book is full.
+    pub fn bid_to_outbid(&self, bidder: &Pubkey, winners_per_cycle: u8) -> Option<&BidData> {
+        if winners_per_cycle <= 1 {
+            return self.bid_history.get_last_element();
+        }
+        let bids = self.bid_history.contents();
+        bids.iter()
+            .find(|bid| &bid.bidder_pubkey == bidder)
+            .or_else(|| {
+                if bids.len() >= winners_per_cycle as usize {
+                    bids.first()
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Inserts a new bid into the bid history.
+    ///
+    /// If there are multiple winners per cycle, the new bid replaces the
+    /// bidder's previous bid or the lowest bid of a full bid book, and it is
+    /// inserted in sorted order (behind the earlier bids of equal amount).
+    pub fn insert_bid(
+        &mut self,
+        bid: BidData,
+        winners_per_cycle: u8,
+    ) -> Result<(), AuctionContractError> {
+        if winners_per_cycle <= 1 {
+            self.bid_history.cyclic_push(bid);
+            return Ok(());
+        }
+        let bids = self.bid_history.contents();
+        if let Some(index) = bids
+            .iter()
+            .position(|old_bid| old_bid.bidder_pubkey == bid.bidder_pubkey)
+        {
+            self.bid_history.remove(index);
+        } else if bids.len() >= winners_per_cycle as usize {
+            self.bid_history.remove(0);
+        }
+        let index = self
+            .bid_history
+            .contents()
+            .iter()
+            .position(|old_bid| old_bid.bid_amount >= bid.bid_amount)
+            .unwrap_or_else(|| self.bid_history.len());
+        self.bid_history
+            .insert(index, bid)
+            .map_err(|_| AuctionContractError::InvalidWinnersPerCycle)
+    }
+
+    /// Returns `true` if the reward of the given rank has already been
+    /// claimed.
+    pub fn is_reward_claimed(&self, rank: u8) -> bool {
+        rank < MAX_WINNERS_PER_CYCLE && self.claimed_ranks & (1 << rank) != 0
+    }
+
+    /// Marks the reward of the given rank as claimed.
+    pub fn set_reward_claimed(&mut self, rank: u8) {
+        if rank < MAX_WINNERS_PER_CYCLE {
+            self.claimed_ranks |= 1 << rank;
+        }
+    }
+
+    /// Returns the part of the bidder's deposit in the cycle that cannot be
+    /// withdrawn.
+    ///
+    /// Once the cycle is settled, this is the amount the bidder pays for
+    /// their winning bid. Until then, the bidder's winning bids (or the
+    /// maximum amount of the proxy bid backing their top bid) are locked,
+    /// unless the auction is frozen.
+    pub fn locked_deposit(
+        &self,
+        auction_root_state: &AuctionRootState,
+        cycle_number: u64,
+        bidder: &Pubkey,
+    ) -> Result<u64, AuctionContractError> {
+        let status = &auction_root_state.status;
+        let auction_config = &auction_root_state.auction_config;
+        let top_bid = self.bid_history.get_last_element();
+        let is_top_bidder = top_bid.map_or(false, |bid| &bid.bidder_pubkey == bidder);
+        let is_settled = cycle_number < status.current_auction_cycle || status.is_finished;
+
+        if !is_settled {
+            if status.is_frozen {
+                return Ok(0);
+            }
+            if let Some(proxy_bid_max) = self.proxy_bid_max {
+                if is_top_bidder {
+                    return Ok(proxy_bid_max);
+                }
+            }
+        } else if auction_config.second_price_settlement {
+            // The winner only pays the price charged at settlement, i.e. the
+            // clearing price (or the whole bid if it reached the buy-now price)
+            return match (top_bid, self.settled_price) {
+                (Some(_), Some(settled_price)) if is_top_bidder => Ok(settled_price),
+                (Some(top_bid), None) if is_top_bidder => {
+                    let second_bid_amount = self
+                        .bid_history
+                        .contents()
+                        .iter()
+                        .rev()
+                        .nth(1)
+                        .map(|bid| bid.bid_amount);
+                    auction_config.clearing_price(top_bid.bid_amount, second_bid_amount)
+                }
+                _ => Ok(0),
+            };
+        }
+
+        self.winning_bids(auction_config.winners_per_cycle)
+            .iter()
+            .filter(|bid| &bid.bidder_pubkey == bidder)
+            .try_fold(0_u64, |sum, bid| sum.checked_add(bid.bid_amount))
+            .ok_or(AuctionContractError::ArithmeticError)
+    }
+}
+
+// The state is always deserialized from the whole account data, thus the
+// legacy layout is told apart by its length
+impl BorshDeserialize for AuctionCycleState {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() == Self::LEGACY_ACCOUNT_LEN {
+            return LegacyAuctionCycleState::deserialize(buf).map(Self::from);
+        }
+        Ok(Self {
+            end_time: UnixTimestamp::deserialize(buf)?,
+            bid_history: BidHistory::deserialize(buf)?,
+            claimed_ranks: u16::deserialize(buf)?,
+            proxy_bid_max: Option::<u64>::deserialize(buf)?,
+            bid_count: u64::deserialize(buf)?,
+            settlement_records: SettlementRecordVec::deserialize(buf)?,
+            settled_price: Option::<u64>::deserialize(buf)?,
+            paused_duration: UnixTimestamp::deserialize(buf)?,
+        })
+    }
+}
+
+// Legacy accounts have to be migrated (resized) before they are written,
+// otherwise the current layout would be read as the legacy one
+impl AccountState for AuctionCycleState {
+    fn write(&self, account: &AccountInfo) -> Result<(), ProgramError> {
+        if account.data_len() == Self::LEGACY_ACCOUNT_LEN {
+            return Err(AuctionContractError::AuctionStateNotMigrated.into());
+        }
+        self.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        Ok(())
+    }
+}
+
+/// Layout of the [`BidData`] before bids could hold memos and referrers.
+#[derive(BorshDeserialize, MaxSerializedLen)]
+struct LegacyBidData {
+    bidder_pubkey: Pubkey,
+    bid_amount: u64,
+}
+
+/// Layout of the [`AuctionCycleState`] before bids could hold memos and
+/// referrers.
+#[derive(BorshDeserialize, MaxSerializedLen)]
+struct LegacyAuctionCycleState {
+    end_time: UnixTimestamp,
+    bid_history: MaxLenVec<LegacyBidData, MAX_BID_HISTORY_LENGTH>,
+}
+
+impl From<LegacyAuctionCycleState> for AuctionCycleState {
+    fn from(legacy: LegacyAuctionCycleState) -> Self {
+        let mut bid_history = BidHistory::new();
+        for bid in legacy.bid_history.contents() {
+            bid_history.cyclic_push(BidData {
+                bidder_pubkey: bid.bidder_pubkey,
+                bid_amount: bid.bid_amount,
+                memo: None,
+                referrer: None,
+            });
+        }
+        Self {
+            end_time: legacy.end_time,
+            bid_history,
+            // The reward of a legacy cycle was marked as claimed by zeroing
+            // its end time
+            claimed_ranks: u16::from(legacy.end_time == 0),
+            proxy_bid_max: None,
+            // The legacy bids are not recorded in the bid log
+            bid_count: 0,
+            settlement_records: SettlementRecordVec::new(),
+            settled_price: None,
+            paused_duration: 0,
+        }
+    }
+}
+
+/// A page of the bid log of an auction cycle.
+///
+/// Every bid recorded on the cycle (including proxy bids, their counter-bids
+/// and revealed sealed bids) is appended to the log in the order of
+/// placement, unlike the [`BidHistory`] that only holds the most recent bids.
+/// A new page is created once the last one is full.
+#[repr(C)]
+#[derive(
+    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, AccountState, Debug, Clone,
+)]
+pub struct BidLogPage {
+    #[alias(Vec<BidData>)]
+    pub bids: BidLog,
+}
+
+/// A sealed bid committed to an auction cycle of a sealed-bid auction.
+///
+/// The account also holds the bidder's deposit that is released when the bid
+/// is revealed.
+#[repr(C)]
+#[derive(
+    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, AccountState, Debug, Clone,
+)]
+pub struct BidCommitment {
+    /// The public key of the bidder's account.
+    pub bidder_pubkey: Pubkey,
+    /// Hash of the bid amount and a secret salt (see
+    /// [`BidCommitment::hash`]).
+    pub commitment: [u8; 32],
+    /// Deposit locked by the bidder (in lamports).
+    ///
+    /// Revealed bids higher than the deposit are invalid.
+    pub deposit: u64,
+    /// End time of the cycle when the bid was committed.
+    ///
+    /// The bid can only be revealed in the reveal window following this end
+    /// time, thus the commitment becomes stale if the cycle is extended
+    /// without being closed (e.g. an idle cycle).
+    pub end_time: UnixTimestamp,
+    /// Paused duration of the cycle when the bid was committed (see
+    /// [`AuctionCycleState::paused_duration`]).
+    pub paused_duration: UnixTimestamp,
+}
+
+impl BidCommitment {
+    /// Returns `true` if the cycle was extended since the bid was committed.
+    ///
+    /// Resuming a paused auction shifts the deadline of the commitment along
+    /// with the end time of the cycle, thus it does not make the commitment
+    /// stale.
+    pub fn is_stale(&self, cycle_state: &AuctionCycleState) -> bool {
+        let shifted_end_time = self
+            .end_time
+            .saturating_add(cycle_state.paused_duration)
+            .saturating_sub(self.paused_duration);
+        shifted_end_time != cycle_state.end_time
+    }
+
+    /// Computes the commitment of a sealed bid from the bid amount and a
+    /// secret salt chosen by the bidder.
+    pub fn hash(bid_amount: u64, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[&bid_amount.to_le_bytes(), salt]).to_bytes()
+    }
+}
+
+/// Funds deposited by a bidder into the auction bank (or vault) during an
+/// auction cycle.
+///
+/// Outbid bids are not refunded to their bidders immediately, they remain in
+/// the auction bank until withdrawn. The deposit minus the amount locked by
+/// the bidder's bids (see [`AuctionCycleState::locked_deposit`]) can be
+/// withdrawn at any time.
+#[repr(C)]
+#[derive(
+    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, AccountState, Debug, Clone,
+)]
+pub struct BidDeposit {
+    /// The public key of the bidder's account.
+    pub bidder_pubkey: Pubkey,
+    /// Deposited amount not withdrawn yet (in lamports or in the smallest
+    /// unit of the bid mint).
+    pub amount: u64,
+}
+
+/// Referral rewards accrued by a referrer.
+///
+/// The rewards are paid to the account when the auction cycles won by the
+/// referred bidders are settled, and they can be claimed by the referrer at
+/// any time.
+#[repr(C)]
+#[derive(
+    BorshSchema, BorshDeserialize, BorshSerialize, MaxSerializedLen, AccountState, Debug, Clone,
+)]
+pub struct ReferralState {
+    /// The public key of the referrer's account.
+    pub referrer_pubkey: Pubkey,
+    /// Rewards not claimed yet (in lamports).
+    pub accrued_rewards: u64,
+    /// All-time total rewards of the referrer (in lamports).
+    pub all_time_rewards: u64,
 }
 
 /// Pool of auctions containing the [`AuctionId`] of each auction
@@ -239,22 +1156,146 @@ impl AuctionPool {
 }
 
 #[repr(C)]
-#[derive(BorshDeserialize, BorshSerialize, AccountState, MaxSerializedLen, Debug, Clone)]
+#[derive(BorshSerialize, AccountState, MaxSerializedLen, Debug, Clone)]
 pub struct ContractBankState {
     /// Address of the contract admin.
     pub contract_admin: Pubkey,
     /// Address of the withdraw authority who may withdraw from the contract
     /// bank.
     pub withdraw_authority: Pubkey,
+    /// Cumulative protocol revenue counters.
+    pub stats: ContractStats,
+    /// Address proposed as the new contract admin, who has to accept the
+    /// role before it is handed over.
+    pub pending_contract_admin: Option<Pubkey>,
+}
+
+impl ContractBankState {
+    /// Serialized length of the legacy state that held no revenue counters.
+    pub const LEGACY_SERIALIZED_LEN: usize = 64;
+    /// Serialized length of the legacy state that held no pending contract
+    /// admin.
+    pub const UNTRANSFERABLE_SERIALIZED_LEN: usize = 104;
+}
+
+// The state is always deserialized from the whole account data, thus the
+// legacy layouts are told apart by their length
+impl BorshDeserialize for ContractBankState {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let len = buf.len();
+        let contract_admin = Pubkey::deserialize(buf)?;
+        let withdraw_authority = Pubkey::deserialize(buf)?;
+        let stats = if len == Self::LEGACY_SERIALIZED_LEN {
+            ContractStats::default()
+        } else {
+            ContractStats::deserialize(buf)?
+        };
+        let pending_contract_admin = if len <= Self::UNTRANSFERABLE_SERIALIZED_LEN {
+            None
+        } else {
+            Option::<Pubkey>::deserialize(buf)?
+        };
+        Ok(Self {
+            contract_admin,
+            withdraw_authority,
+            stats,
+            pending_contract_admin,
+        })
+    }
 }
 
 #[repr(C)]
-#[derive(BorshDeserialize, BorshSerialize, AccountState, MaxSerializedLen, Debug, Clone)]
+#[derive(
+    BorshDeserialize, BorshSerialize, MaxSerializedLen, Debug, Clone, Copy, Default, PartialEq,
+)]
+pub struct ContractStats {
+    /// All-time total protocol fees collected in lamports.
+    ///
+    /// The fees of auctions accepting token bids are sent to the contract
+    /// vaults of the bid mints, thus they are not counted.
+    pub fees_collected: u64,
+    /// All-time total lamports withdrawn from the contract bank.
+    pub fees_withdrawn: u64,
+    /// Number of auctions created.
+    pub auctions_created: u64,
+    /// Number of auctions finished, counted when their last cycle is settled.
+    pub auctions_finished: u64,
+    /// Number of auctions deleted (finished or not).
+    pub auctions_deleted: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, AccountState, MaxSerializedLen, Debug, Clone)]
 pub struct ProtocolFeeState {
-    /// The protocol fee collected on all claimed funds.
+    /// The protocol fee collected on all claimed funds (unless the auction
+    /// has a fee override or belongs to a fee tier).
+    ///
+    /// Expressed in basis points, max 5% (500)
+    pub fee: u16,
+    /// The protocol fee collected on the funds claimed from verified auctions
+    /// (in basis points).
+    pub verified_fee: Option<u16>,
+}
+
+impl Default for ProtocolFeeState {
+    fn default() -> Self {
+        Self {
+            fee: DEFAULT_PROTOCOL_FEE,
+            verified_fee: None,
+        }
+    }
+}
+
+impl ProtocolFeeState {
+    /// Serialized length of the legacy state that held the fee in thousandths
+    /// in a `u8`.
+    pub const LEGACY_SERIALIZED_LEN: usize = 1;
+
+    /// Returns the protocol fee collected on the funds claimed from the given
+    /// auction (in basis points).
     ///
-    /// Expressed in thousandths, max 5% (50)
-    pub fee: u8,
+    /// The fee override of the auction takes precedence over the fee tier of
+    /// verified auctions, which takes precedence over the global fee.
+    pub fn effective_fee(&self, root_state: &AuctionRootState) -> u16 {
+        if let Some(fee) = root_state.protocol_fee_override {
+            return fee;
+        }
+        if root_state.status.is_verified {
+            if let Some(fee) = self.verified_fee {
+                return fee;
+            }
+        }
+        self.fee
+    }
+}
+
+// The state is always deserialized from the whole account data, thus the
+// legacy layout is told apart by its length
+impl BorshDeserialize for ProtocolFeeState {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() == Self::LEGACY_SERIALIZED_LEN {
+            Ok(Self {
+                fee: u16::from(u8::deserialize(buf)?) * 10,
+                verified_fee: None,
+            })
+        } else {
+            Ok(Self {
+                fee: u16::deserialize(buf)?,
+                verified_fee: Option::<u16>::deserialize(buf)?,
+            })
+        }
+    }
+}
+
+/// Returns the protocol fee collected on `amount` given the fee in basis
+/// points.
+///
+/// The fee is rounded down, i.e. rounding never shortchanges the recipients of
+/// the claimed funds.
+pub fn protocol_fee_share(amount: u64, fee: u16) -> u64 {
+    let fee = u128::from(fee.min(MAX_BASIS_POINTS));
+    // fee <= MAX_BASIS_POINTS, thus the result fits into a u64
+    (u128::from(amount) * fee / u128::from(MAX_BASIS_POINTS)) as u64
 }
 
 #[cfg(test)]
@@ -269,12 +1310,33 @@ mod test {
             encore_period: 300,
             minimum_bid_amount: 10_000,
             number_of_cycles: Some(5),
+            bid_mint: Some(Pubkey::new_unique()),
+            reserve_price: Some(ReservePrice {
+                amount: 100_000,
+                is_hidden: true,
+            }),
+            min_bid_increment: Some(BidIncrement {
+                amount: 1_000,
+                basis_points: 500,
+            }),
+            buy_now_price: Some(1_000_000),
+            kind: AuctionKind::Dutch(DutchPriceCurve {
+                start_price: 10_000_000,
+                floor_price: 100_000,
+            }),
+            second_price_settlement: true,
+            winners_per_cycle: 1,
+            referral_share_bps: 0,
+            all_or_nothing: false,
+            finish_on_goal: false,
         };
 
         let mut bid_history = BidHistory::new();
         let bid_data = BidData {
             bid_amount: 0,
             bidder_pubkey: Pubkey::new_unique(),
+            memo: Some(BidMemo::try_from("x".repeat(MAX_BID_MEMO_LEN)).unwrap()),
+            referrer: Some(Pubkey::new_unique()),
         };
         for _ in 0..10 {
             bid_history.cyclic_push(bid_data.clone());
@@ -293,6 +1355,7 @@ mod test {
             is_verified: false,
             current_auction_cycle: 1,
             current_idle_cycle_streak: 0,
+            paused_at: Some(1_650_000_000),
         };
 
         let description_string: DescriptionString =
@@ -339,6 +1402,25 @@ mod test {
             available_funds: 0,
             start_time: 0,
             unclaimed_rewards: 0,
+            unsettled_deposits: 0,
+            pending_owner: Some(Pubkey::new_unique()),
+            beneficiary: Pubkey::new_unique(),
+            beneficiaries: vec![
+                Beneficiary {
+                    pubkey: Pubkey::new_unique(),
+                    share_bps: 1_000,
+                };
+                MAX_BENEFICIARIES
+            ]
+            .try_into()
+            .unwrap(),
+            escrowed_funds: 0,
+            vesting_schedule: Some(VestingSchedule {
+                cliff_period: 100,
+                vesting_period: 1_000,
+            }),
+            claimed_funds: 0,
+            protocol_fee_override: Some(100),
         };
 
         assert_eq!(
@@ -349,6 +1431,20 @@ mod test {
         let cycle_state = AuctionCycleState {
             end_time: 100_000_000,
             bid_history: bid_history.clone(),
+            claimed_ranks: u16::MAX,
+            proxy_bid_max: Some(u64::MAX),
+            bid_count: u64::MAX,
+            settlement_records: vec![
+                SettlementRecord {
+                    bidder_pubkey: Pubkey::new_unique(),
+                    amount: u64::MAX,
+                };
+                MAX_WINNERS_PER_CYCLE as usize
+            ]
+            .try_into()
+            .unwrap(),
+            settled_price: Some(u64::MAX),
+            paused_duration: 0,
         };
 
         assert_eq!(
@@ -356,6 +1452,17 @@ mod test {
             cycle_state.try_to_vec().unwrap().len()
         );
 
+        let mut bids = BidLog::new();
+        for _ in 0..BID_LOG_PAGE_LENGTH {
+            bids.push(bid_data.clone()).unwrap();
+        }
+        let bid_log_page = BidLogPage { bids };
+
+        assert_eq!(
+            BidLogPage::MAX_SERIALIZED_LEN,
+            bid_log_page.try_to_vec().unwrap().len()
+        );
+
         assert_eq!(AuctionPool::max_serialized_len(100), Some(3208));
         assert_eq!(AuctionPool::max_serialized_len(1000), Some(32008));
     }
@@ -398,4 +1505,782 @@ mod test {
             AuctionPool::max_serialized_len(len as usize).unwrap()
         );
     }
+
+    #[test]
+    fn next_valid_bid() {
+        let mut auction_config = AuctionConfig {
+            cycle_period: 86400,
+            encore_period: 300,
+            minimum_bid_amount: 10_000,
+            number_of_cycles: Some(5),
+            bid_mint: None,
+            reserve_price: None,
+            min_bid_increment: None,
+            buy_now_price: None,
+            kind: AuctionKind::English,
+            second_price_settlement: false,
+            winners_per_cycle: 1,
+            referral_share_bps: 0,
+            all_or_nothing: false,
+            finish_on_goal: false,
+        };
+        assert_eq!(auction_config.next_valid_bid(10_000), Ok(10_001));
+
+        auction_config.min_bid_increment = Some(BidIncrement {
+            amount: 1_000,
+            basis_points: 0,
+        });
+        assert_eq!(auction_config.next_valid_bid(10_000), Ok(11_000));
+
+        auction_config.min_bid_increment = Some(BidIncrement {
+            amount: 1_000,
+            basis_points: 500, // 5%
+        });
+        assert_eq!(auction_config.next_valid_bid(10_000), Ok(11_000));
+        assert_eq!(auction_config.next_valid_bid(100_000), Ok(105_000));
+
+        auction_config.min_bid_increment = Some(BidIncrement {
+            amount: 0,
+            basis_points: 1,
+        });
+        assert_eq!(auction_config.next_valid_bid(5_000), Ok(5_001));
+        assert_eq!(
+            auction_config.next_valid_bid(u64::MAX),
+            Err(AuctionContractError::ArithmeticError)
+        );
+    }
+
+    #[test]
+    fn clearing_price() {
+        let mut auction_config = AuctionConfig {
+            cycle_period: 86400,
+            encore_period: 300,
+            minimum_bid_amount: 10_000,
+            number_of_cycles: Some(5),
+            bid_mint: None,
+            reserve_price: None,
+            min_bid_increment: Some(BidIncrement {
+                amount: 1_000,
+                basis_points: 0,
+            }),
+            buy_now_price: None,
+            kind: AuctionKind::English,
+            second_price_settlement: true,
+            winners_per_cycle: 1,
+            referral_share_bps: 0,
+            all_or_nothing: false,
+            finish_on_goal: false,
+        };
+        assert_eq!(
+            auction_config.clearing_price(50_000, Some(20_000)),
+            Ok(21_000)
+        );
+        assert_eq!(
+            auction_config.clearing_price(20_500, Some(20_000)),
+            Ok(20_500)
+        );
+        assert_eq!(auction_config.clearing_price(50_000, None), Ok(10_000));
+
+        auction_config.reserve_price = Some(ReservePrice {
+            amount: 30_000,
+            is_hidden: false,
+        });
+        assert_eq!(
+            auction_config.clearing_price(50_000, Some(20_000)),
+            Ok(30_000)
+        );
+        assert_eq!(
+            auction_config.clearing_price(50_000, Some(40_000)),
+            Ok(41_000)
+        );
+    }
+
+    #[test]
+    fn bid_book() {
+        let bidders = (0..4)
+            .map(|_| Pubkey::new_unique())
+            .collect::<Vec<Pubkey>>();
+        let bid = |bidder: usize, bid_amount: u64| BidData {
+            bidder_pubkey: bidders[bidder],
+            bid_amount,
+            memo: None,
+            referrer: None,
+        };
+        let bid_book = |cycle_state: &AuctionCycleState| {
+            cycle_state
+                .bid_history
+                .contents()
+                .iter()
+                .map(|bid| (bid.bidder_pubkey, bid.bid_amount))
+                .collect::<Vec<(Pubkey, u64)>>()
+        };
+        let winners_per_cycle = 3;
+        let mut cycle_state = AuctionCycleState {
+            end_time: 0,
+            bid_history: BidHistory::new(),
+            claimed_ranks: 0,
+            proxy_bid_max: None,
+            bid_count: 0,
+            settlement_records: SettlementRecordVec::new(),
+            settled_price: None,
+            paused_duration: 0,
+        };
+
+        assert!(cycle_state
+            .bid_to_outbid(&bidders[0], winners_per_cycle)
+            .is_none());
+        cycle_state
+            .insert_bid(bid(0, 100), winners_per_cycle)
+            .unwrap();
+        cycle_state
+            .insert_bid(bid(1, 300), winners_per_cycle)
+            .unwrap();
+        cycle_state
+            .insert_bid(bid(2, 200), winners_per_cycle)
+            .unwrap();
+        assert_eq!(
+            bid_book(&cycle_state),
+            vec![(bidders[0], 100), (bidders[2], 200), (bidders[1], 300)]
+        );
+
+        // the lowest bid has to be outbid by new bidders of a full book
+        let bid_to_outbid = cycle_state
+            .bid_to_outbid(&bidders[3], winners_per_cycle)
+            .unwrap();
+        assert_eq!(bid_to_outbid.bidder_pubkey, bidders[0]);
+        cycle_state
+            .insert_bid(bid(3, 250), winners_per_cycle)
+            .unwrap();
+        assert_eq!(
+            bid_book(&cycle_state),
+            vec![(bidders[2], 200), (bidders[3], 250), (bidders[1], 300)]
+        );
+
+        // winners raise their own bid
+        let bid_to_outbid = cycle_state
+            .bid_to_outbid(&bidders[2], winners_per_cycle)
+            .unwrap();
+        assert_eq!(bid_to_outbid.bid_amount, 200);
+        cycle_state
+            .insert_bid(bid(2, 300), winners_per_cycle)
+            .unwrap();
+        assert_eq!(
+            bid_book(&cycle_state),
+            vec![(bidders[3], 250), (bidders[2], 300), (bidders[1], 300)]
+        );
+
+        // earlier bids rank higher among equal bids
+        assert_eq!(
+            cycle_state
+                .winning_bid(0, winners_per_cycle)
+                .unwrap()
+                .bidder_pubkey,
+            bidders[1]
+        );
+        assert_eq!(
+            cycle_state
+                .winning_bid(2, winners_per_cycle)
+                .unwrap()
+                .bidder_pubkey,
+            bidders[3]
+        );
+        assert!(cycle_state.winning_bid(3, winners_per_cycle).is_none());
+
+        // a single winner is the last bid of the history
+        cycle_state.insert_bid(bid(0, 50), 1).unwrap();
+        assert_eq!(cycle_state.bid_history.len(), 4);
+        assert_eq!(cycle_state.winning_bids(1).len(), 1);
+        assert_eq!(
+            cycle_state
+                .bid_to_outbid(&bidders[1], 1)
+                .unwrap()
+                .bid_amount,
+            50
+        );
+
+        assert!(!cycle_state.is_reward_claimed(1));
+        cycle_state.set_reward_claimed(1);
+        assert!(cycle_state.is_reward_claimed(1));
+        assert!(!cycle_state.is_reward_claimed(0));
+    }
+
+    #[test]
+    fn proxy_bid_amount() {
+        let mut auction_config = AuctionConfig {
+            cycle_period: 86400,
+            encore_period: 300,
+            minimum_bid_amount: 10_000,
+            number_of_cycles: Some(5),
+            bid_mint: None,
+            reserve_price: None,
+            min_bid_increment: None,
+            buy_now_price: None,
+            kind: AuctionKind::English,
+            second_price_settlement: false,
+            winners_per_cycle: 1,
+            referral_share_bps: 0,
+            all_or_nothing: false,
+            finish_on_goal: false,
+        };
+        assert!(auction_config.supports_proxy_bids());
+        assert_eq!(auction_config.proxy_bid_amount(10_000, 50_000), 10_000);
+        assert_eq!(auction_config.proxy_bid_amount(60_000, 50_000), 50_000);
+
+        auction_config.reserve_price = Some(ReservePrice {
+            amount: 30_000,
+            is_hidden: true,
+        });
+        assert_eq!(auction_config.proxy_bid_amount(10_000, 50_000), 30_000);
+        assert_eq!(auction_config.proxy_bid_amount(40_000, 50_000), 40_000);
+        assert_eq!(auction_config.proxy_bid_amount(10_000, 20_000), 10_000);
+
+        auction_config.winners_per_cycle = 2;
+        assert!(!auction_config.supports_proxy_bids());
+    }
+
+    #[test]
+    fn referral_rewards() {
+        let mut auction_config = AuctionConfig {
+            cycle_period: 86400,
+            encore_period: 300,
+            minimum_bid_amount: 10_000,
+            number_of_cycles: Some(5),
+            bid_mint: None,
+            reserve_price: None,
+            min_bid_increment: None,
+            buy_now_price: None,
+            kind: AuctionKind::English,
+            second_price_settlement: false,
+            winners_per_cycle: 2,
+            referral_share_bps: 0,
+            all_or_nothing: false,
+            finish_on_goal: false,
+        };
+        assert_eq!(auction_config.referral_reward(1_000_000).unwrap(), 0);
+
+        auction_config.referral_share_bps = 250;
+        assert_eq!(auction_config.referral_reward(1_000_000).unwrap(), 25_000);
+        assert_eq!(auction_config.referral_reward(39).unwrap(), 0);
+        assert_eq!(
+            auction_config.referral_reward(u64::MAX).unwrap(),
+            u64::MAX / 40
+        );
+
+        auction_config.referral_share_bps = MAX_BASIS_POINTS;
+        assert_eq!(auction_config.referral_reward(u64::MAX).unwrap(), u64::MAX);
+
+        let referrer = Pubkey::new_unique();
+        let mut cycle_state = AuctionCycleState {
+            end_time: 0,
+            bid_history: BidHistory::new(),
+            claimed_ranks: 0,
+            proxy_bid_max: None,
+            bid_count: 0,
+            settlement_records: SettlementRecordVec::new(),
+            settled_price: None,
+            paused_duration: 0,
+        };
+        for (bid_amount, referrer) in [
+            (10_000, Some(referrer)),
+            (20_000, None),
+            (30_000, Some(referrer)),
+        ] {
+            cycle_state.bid_history.cyclic_push(BidData {
+                bidder_pubkey: Pubkey::new_unique(),
+                bid_amount,
+                memo: None,
+                referrer,
+            });
+        }
+        // only the referrers of the winning bids are rewarded
+        assert_eq!(
+            cycle_state.rewarded_referrers(&auction_config),
+            vec![referrer]
+        );
+
+        auction_config.referral_share_bps = 0;
+        assert!(cycle_state.rewarded_referrers(&auction_config).is_empty());
+    }
+
+    #[test]
+    fn protocol_fee_share_rounding() {
+        let amounts = [
+            0,
+            1,
+            19,
+            9_999,
+            10_000,
+            10_001,
+            1_000_000_007,
+            u64::MAX / 10_000,
+            u64::MAX / 2,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        for fee in [0, 1, 10, 499, 500, 9_999, MAX_BASIS_POINTS] {
+            for amount in amounts {
+                let share = protocol_fee_share(amount, fee);
+                // the fee is rounded down
+                let exact = u128::from(amount) * u128::from(fee);
+                assert!(u128::from(share) * u128::from(MAX_BASIS_POINTS) <= exact);
+                assert!((u128::from(share) + 1) * u128::from(MAX_BASIS_POINTS) > exact);
+                assert!(share <= amount);
+            }
+        }
+
+        assert_eq!(protocol_fee_share(10_000_000, 500), 500_000);
+        assert_eq!(protocol_fee_share(19, 500), 0);
+        assert_eq!(protocol_fee_share(20, 500), 1);
+        assert_eq!(protocol_fee_share(39, 500), 1);
+        assert_eq!(protocol_fee_share(u64::MAX, 500), u64::MAX / 20);
+        assert_eq!(protocol_fee_share(u64::MAX, MAX_BASIS_POINTS), u64::MAX);
+        // fees above 100% are capped
+        assert_eq!(protocol_fee_share(u64::MAX, u16::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn protocol_fee_state_migration() {
+        // fee in thousandths in a u8
+        let fee_state = ProtocolFeeState::try_from_slice(&[50]).unwrap();
+        assert_eq!(fee_state.fee, 500);
+        assert_eq!(fee_state.verified_fee, None);
+
+        let fee_state = ProtocolFeeState {
+            fee: 250,
+            verified_fee: Some(100),
+        };
+        let data = fee_state.try_to_vec().unwrap();
+        assert_eq!(data.len(), ProtocolFeeState::MAX_SERIALIZED_LEN);
+        let fee_state = ProtocolFeeState::try_from_slice(&data).unwrap();
+        assert_eq!(fee_state.fee, 250);
+        assert_eq!(fee_state.verified_fee, Some(100));
+
+        let fee_state = ProtocolFeeState {
+            fee: 250,
+            verified_fee: None,
+        };
+        let mut data = vec![0; ProtocolFeeState::MAX_SERIALIZED_LEN];
+        fee_state.serialize(&mut data.as_mut_slice()).unwrap();
+        // the account data is allocated for the maximum length
+        let fee_state = ProtocolFeeState::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(fee_state.fee, 250);
+        assert_eq!(fee_state.verified_fee, None);
+
+        assert!(ProtocolFeeState::try_from_slice(&[]).is_err());
+    }
+
+    #[test]
+    fn contract_bank_state_migration() {
+        let contract_admin = Pubkey::new_unique();
+        let withdraw_authority = Pubkey::new_unique();
+
+        let mut legacy_data = contract_admin.try_to_vec().unwrap();
+        legacy_data.extend(withdraw_authority.try_to_vec().unwrap());
+        assert_eq!(legacy_data.len(), ContractBankState::LEGACY_SERIALIZED_LEN);
+
+        let bank_state = ContractBankState::try_from_slice(&legacy_data).unwrap();
+        assert_eq!(bank_state.contract_admin, contract_admin);
+        assert_eq!(bank_state.withdraw_authority, withdraw_authority);
+        assert_eq!(bank_state.stats, ContractStats::default());
+        assert_eq!(bank_state.pending_contract_admin, None);
+
+        let stats = ContractStats {
+            fees_collected: 1_000_000,
+            fees_withdrawn: 500_000,
+            auctions_created: 3,
+            auctions_finished: 1,
+            auctions_deleted: 2,
+        };
+        let mut untransferable_data = legacy_data;
+        untransferable_data.extend(stats.try_to_vec().unwrap());
+        assert_eq!(
+            untransferable_data.len(),
+            ContractBankState::UNTRANSFERABLE_SERIALIZED_LEN
+        );
+
+        let bank_state = ContractBankState::try_from_slice(&untransferable_data).unwrap();
+        assert_eq!(bank_state.contract_admin, contract_admin);
+        assert_eq!(bank_state.stats, stats);
+        assert_eq!(bank_state.pending_contract_admin, None);
+
+        let pending_contract_admin = Some(Pubkey::new_unique());
+        let data = ContractBankState {
+            contract_admin,
+            withdraw_authority,
+            stats,
+            pending_contract_admin,
+        }
+        .try_to_vec()
+        .unwrap();
+        assert_eq!(data.len(), ContractBankState::MAX_SERIALIZED_LEN);
+
+        let bank_state = ContractBankState::try_from_slice(&data).unwrap();
+        assert_eq!(bank_state.contract_admin, contract_admin);
+        assert_eq!(bank_state.stats, stats);
+        assert_eq!(bank_state.pending_contract_admin, pending_contract_admin);
+    }
+
+    #[test]
+    fn auction_state_migration() {
+        let auction_owner = Pubkey::new_unique();
+        let description = AuctionDescription {
+            description: DescriptionString::try_from("X".repeat(10)).unwrap(),
+            socials: SocialsVec::new(),
+            goal_treasury_amount: Some(1_000_000),
+        };
+        let token_config = TokenConfig::Token(TokenData {
+            mint: Pubkey::new_unique(),
+            per_cycle_amount: 20_000,
+        });
+
+        // legacy root state padded to the legacy account length
+        let mut legacy_data = [7_u8; 32].try_to_vec().unwrap();
+        legacy_data.extend(auction_owner.try_to_vec().unwrap());
+        legacy_data.extend(description.try_to_vec().unwrap());
+        // cycle period, encore period, number of cycles, minimum bid amount
+        legacy_data.extend(86_400_i64.try_to_vec().unwrap());
+        legacy_data.extend(300_i64.try_to_vec().unwrap());
+        legacy_data.extend(Some(5_u64).try_to_vec().unwrap());
+        legacy_data.extend(10_000_u64.try_to_vec().unwrap());
+        legacy_data.extend(token_config.try_to_vec().unwrap());
+        // current cycle, idle streak, frozen, finished, filtered, verified
+        legacy_data.extend(3_u64.try_to_vec().unwrap());
+        legacy_data.extend(1_u32.try_to_vec().unwrap());
+        legacy_data.extend([0, 0, 1, 1]);
+        // all-time treasury, available funds, start time, unclaimed rewards
+        legacy_data.extend(500_000_u64.try_to_vec().unwrap());
+        legacy_data.extend(200_000_u64.try_to_vec().unwrap());
+        legacy_data.extend(1_650_000_000_i64.try_to_vec().unwrap());
+        legacy_data.extend(2_u16.try_to_vec().unwrap());
+        legacy_data.resize(AuctionRootState::LEGACY_ACCOUNT_LEN, 0);
+
+        let root_state = AuctionRootState::deserialize(&mut legacy_data.as_slice()).unwrap();
+        assert_eq!(root_state.auction_name, [7; 32]);
+        assert_eq!(root_state.auction_owner, auction_owner);
+        assert_eq!(root_state.description.goal_treasury_amount, Some(1_000_000));
+        assert_eq!(root_state.auction_config.cycle_period, 86_400);
+        assert_eq!(root_state.auction_config.encore_period, 300);
+        assert_eq!(root_state.auction_config.number_of_cycles, Some(5));
+        assert_eq!(root_state.auction_config.minimum_bid_amount, 10_000);
+        assert_eq!(root_state.auction_config.bid_mint, None);
+        assert!(matches!(
+            root_state.auction_config.kind,
+            AuctionKind::English
+        ));
+        assert_eq!(root_state.auction_config.winners_per_cycle, 1);
+        assert!(matches!(root_state.token_config, TokenConfig::Token(_)));
+        assert_eq!(root_state.status.current_auction_cycle, 3);
+        assert_eq!(root_state.status.current_idle_cycle_streak, 1);
+        assert!(!root_state.status.is_frozen);
+        assert!(root_state.status.is_filtered);
+        assert!(root_state.status.is_verified);
+        assert_eq!(root_state.status.paused_at, None);
+        assert_eq!(root_state.all_time_treasury, 500_000);
+        assert_eq!(root_state.available_funds, 200_000);
+        assert_eq!(root_state.start_time, 1_650_000_000);
+        assert_eq!(root_state.unclaimed_rewards, 2);
+        assert_eq!(root_state.unsettled_deposits, 0);
+        assert_eq!(root_state.beneficiary, auction_owner);
+        assert!(root_state.beneficiaries.is_empty());
+        assert_eq!(root_state.claimed_funds, 300_000);
+        assert_eq!(root_state.protocol_fee_override, None);
+
+        // the current layout is read from accounts of the current length
+        let mut data = root_state.try_to_vec().unwrap();
+        data.resize(
+            AuctionRootState::MAX_SERIALIZED_LEN + EXTRA_ROOT_STATE_BYTES,
+            0,
+        );
+        let root_state = AuctionRootState::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(root_state.auction_owner, auction_owner);
+        assert_eq!(root_state.beneficiary, auction_owner);
+        assert_eq!(root_state.claimed_funds, 300_000);
+
+        // legacy cycle states with a claimed reward
+        let bidder = Pubkey::new_unique();
+        let mut legacy_data = 0_i64.try_to_vec().unwrap();
+        legacy_data.extend(1_u32.try_to_vec().unwrap());
+        legacy_data.extend(bidder.try_to_vec().unwrap());
+        legacy_data.extend(50_000_u64.try_to_vec().unwrap());
+        legacy_data.resize(AuctionCycleState::LEGACY_ACCOUNT_LEN, 0);
+
+        let cycle_state = AuctionCycleState::deserialize(&mut legacy_data.as_slice()).unwrap();
+        assert_eq!(cycle_state.end_time, 0);
+        assert_eq!(cycle_state.bid_history.len(), 1);
+        let top_bid = cycle_state.bid_history.get_last_element().unwrap();
+        assert_eq!(top_bid.bidder_pubkey, bidder);
+        assert_eq!(top_bid.bid_amount, 50_000);
+        assert!(top_bid.memo.is_none());
+        assert!(top_bid.referrer.is_none());
+        assert!(cycle_state.is_reward_claimed(0));
+        assert_eq!(cycle_state.bid_count, 0);
+
+        // and without a claimed reward
+        legacy_data[..8].copy_from_slice(&100_000_i64.to_le_bytes());
+        let cycle_state = AuctionCycleState::deserialize(&mut legacy_data.as_slice()).unwrap();
+        assert_eq!(cycle_state.end_time, 100_000);
+        assert!(!cycle_state.is_reward_claimed(0));
+
+        let mut data = cycle_state.try_to_vec().unwrap();
+        data.resize(AuctionCycleState::MAX_SERIALIZED_LEN, 0);
+        let cycle_state = AuctionCycleState::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(cycle_state.end_time, 100_000);
+        assert_eq!(cycle_state.bid_history.len(), 1);
+    }
+
+    #[test]
+    fn vested_amount() {
+        let schedule = VestingSchedule {
+            cliff_period: 100,
+            vesting_period: 1_000,
+        };
+        let start_time = 10_000;
+        let amount = 5_000_000;
+
+        assert_eq!(schedule.vested_amount(amount, start_time, 9_000), 0);
+        assert_eq!(schedule.vested_amount(amount, start_time, start_time), 0);
+        assert_eq!(schedule.vested_amount(amount, start_time, 10_099), 0);
+        assert_eq!(schedule.vested_amount(amount, start_time, 10_100), 500_000);
+        assert_eq!(
+            schedule.vested_amount(amount, start_time, 10_500),
+            2_500_000
+        );
+        assert_eq!(
+            schedule.vested_amount(amount, start_time, 10_999),
+            4_995_000
+        );
+        assert_eq!(schedule.vested_amount(amount, start_time, 11_000), amount);
+        assert_eq!(schedule.vested_amount(amount, start_time, 20_000), amount);
+        assert_eq!(
+            schedule.vested_amount(u64::MAX, start_time, 10_500),
+            u64::MAX / 2
+        );
+    }
+
+    #[test]
+    fn beneficiary_split() {
+        let (shares, remainder) = split_among_beneficiaries(&[], 1_000_000).unwrap();
+        assert!(shares.is_empty());
+        assert_eq!(remainder, 1_000_000);
+
+        let beneficiaries = [
+            Beneficiary {
+                pubkey: Pubkey::new_unique(),
+                share_bps: 2_500,
+            },
+            Beneficiary {
+                pubkey: Pubkey::new_unique(),
+                share_bps: 1_000,
+            },
+        ];
+        let (shares, remainder) = split_among_beneficiaries(&beneficiaries, 1_000_000).unwrap();
+        assert_eq!(shares, vec![250_000, 100_000]);
+        assert_eq!(remainder, 650_000);
+
+        // rounding dust stays with the auction owner
+        let (shares, remainder) = split_among_beneficiaries(&beneficiaries, 39).unwrap();
+        assert_eq!(shares, vec![9, 3]);
+        assert_eq!(remainder, 27);
+
+        let (shares, remainder) = split_among_beneficiaries(&beneficiaries, u64::MAX).unwrap();
+        assert_eq!(shares, vec![u64::MAX / 4, u64::MAX / 10]);
+        assert_eq!(remainder, u64::MAX - shares[0] - shares[1]);
+
+        let whole = [Beneficiary {
+            pubkey: Pubkey::new_unique(),
+            share_bps: MAX_BASIS_POINTS,
+        }];
+        let (shares, remainder) = split_among_beneficiaries(&whole, 12_345).unwrap();
+        assert_eq!(shares, vec![12_345]);
+        assert_eq!(remainder, 0);
+    }
+
+    #[test]
+    fn locked_deposit() {
+        let bidders = (0..3)
+            .map(|_| Pubkey::new_unique())
+            .collect::<Vec<Pubkey>>();
+        let mut root_state = AuctionRootState {
+            auction_name: [1; 32],
+            auction_owner: Pubkey::new_unique(),
+            description: AuctionDescription {
+                description: "X".to_string().try_into().unwrap(),
+                socials: Vec::new().try_into().unwrap(),
+                goal_treasury_amount: None,
+            },
+            auction_config: AuctionConfig {
+                cycle_period: 86400,
+                encore_period: 300,
+                minimum_bid_amount: 10_000,
+                number_of_cycles: Some(5),
+                bid_mint: None,
+                reserve_price: None,
+                min_bid_increment: None,
+                buy_now_price: None,
+                kind: AuctionKind::English,
+                second_price_settlement: false,
+                winners_per_cycle: 1,
+                referral_share_bps: 0,
+                all_or_nothing: false,
+                finish_on_goal: false,
+            },
+            token_config: TokenConfig::Token(TokenData {
+                per_cycle_amount: 1,
+                mint: Pubkey::new_unique(),
+            }),
+            status: AuctionStatus {
+                is_finished: false,
+                is_frozen: false,
+                is_filtered: false,
+                is_verified: false,
+                current_auction_cycle: 1,
+                current_idle_cycle_streak: 0,
+                paused_at: None,
+            },
+            all_time_treasury: 0,
+            available_funds: 0,
+            start_time: 0,
+            unclaimed_rewards: 0,
+            unsettled_deposits: 0,
+            pending_owner: None,
+            beneficiary: Pubkey::new_unique(),
+            beneficiaries: BeneficiaryVec::new(),
+            escrowed_funds: 0,
+            vesting_schedule: None,
+            claimed_funds: 0,
+            protocol_fee_override: None,
+        };
+        let mut cycle_state = AuctionCycleState {
+            end_time: 0,
+            bid_history: BidHistory::new(),
+            claimed_ranks: 0,
+            proxy_bid_max: None,
+            bid_count: 0,
+            settlement_records: SettlementRecordVec::new(),
+            settled_price: None,
+            paused_duration: 0,
+        };
+        cycle_state.bid_history.cyclic_push(BidData {
+            bidder_pubkey: bidders[0],
+            bid_amount: 10_000,
+            memo: None,
+            referrer: None,
+        });
+        cycle_state.bid_history.cyclic_push(BidData {
+            bidder_pubkey: bidders[1],
+            bid_amount: 20_000,
+            memo: None,
+            referrer: None,
+        });
+        let locked = |root_state: &AuctionRootState, cycle_state: &AuctionCycleState, bidder| {
+            cycle_state
+                .locked_deposit(root_state, 1, &bidders[bidder])
+                .unwrap()
+        };
+
+        // only the top bid is locked in an ongoing cycle
+        assert_eq!(locked(&root_state, &cycle_state, 0), 0);
+        assert_eq!(locked(&root_state, &cycle_state, 1), 20_000);
+        assert_eq!(locked(&root_state, &cycle_state, 2), 0);
+
+        // a proxy bid locks its maximum amount
+        cycle_state.proxy_bid_max = Some(50_000);
+        assert_eq!(locked(&root_state, &cycle_state, 0), 0);
+        assert_eq!(locked(&root_state, &cycle_state, 1), 50_000);
+
+        // nothing is locked in the current cycle of a frozen auction
+        root_state.status.is_frozen = true;
+        assert_eq!(locked(&root_state, &cycle_state, 1), 0);
+
+        // the winner pays the top bid once the cycle is settled
+        root_state.status.current_auction_cycle = 2;
+        assert_eq!(locked(&root_state, &cycle_state, 0), 0);
+        assert_eq!(locked(&root_state, &cycle_state, 1), 20_000);
+
+        // or the clearing price in case of second-price settlement
+        root_state.auction_config.second_price_settlement = true;
+        assert_eq!(locked(&root_state, &cycle_state, 0), 0);
+        assert_eq!(locked(&root_state, &cycle_state, 1), 10_001);
+
+        // or the price recorded at settlement (e.g. the buy-now price)
+        cycle_state.settled_price = Some(20_000);
+        assert_eq!(locked(&root_state, &cycle_state, 0), 0);
+        assert_eq!(locked(&root_state, &cycle_state, 1), 20_000);
+    }
+
+    #[test]
+    fn dutch_price_curve() {
+        let price_curve = DutchPriceCurve {
+            start_price: 1_000_000,
+            floor_price: 200_000,
+        };
+        let cycle_period = 1_000;
+        let cycle_end_time = 11_000;
+        // before the start of the cycle
+        assert_eq!(
+            price_curve.price_at(cycle_end_time, cycle_period, 9_000),
+            1_000_000
+        );
+        assert_eq!(
+            price_curve.price_at(cycle_end_time, cycle_period, 10_000),
+            1_000_000
+        );
+        assert_eq!(
+            price_curve.price_at(cycle_end_time, cycle_period, 10_250),
+            800_000
+        );
+        assert_eq!(
+            price_curve.price_at(cycle_end_time, cycle_period, 10_999),
+            200_800
+        );
+        assert_eq!(
+            price_curve.price_at(cycle_end_time, cycle_period, 11_000),
+            200_000
+        );
+        // after the end of the cycle
+        assert_eq!(
+            price_curve.price_at(cycle_end_time, cycle_period, 20_000),
+            200_000
+        );
+
+        let price_curve = DutchPriceCurve {
+            start_price: u64::MAX,
+            floor_price: 0,
+        };
+        assert_eq!(
+            price_curve.price_at(cycle_end_time, cycle_period, 10_500),
+            u64::MAX - u64::MAX / 2
+        );
+    }
+
+    #[test]
+    fn stale_bid_commitment() {
+        let mut cycle_state = AuctionCycleState {
+            end_time: 10_000,
+            bid_history: BidHistory::new(),
+            claimed_ranks: 0,
+            proxy_bid_max: None,
+            bid_count: 0,
+            settlement_records: SettlementRecordVec::new(),
+            settled_price: None,
+            paused_duration: 0,
+        };
+        let bid_commitment = BidCommitment {
+            bidder_pubkey: Pubkey::new_unique(),
+            commitment: BidCommitment::hash(10_000, &[1; 32]),
+            deposit: 20_000,
+            end_time: 10_000,
+            paused_duration: 0,
+        };
+        assert!(!bid_commitment.is_stale(&cycle_state));
+
+        // resuming the auction shifts the deadline of the commitment
+        cycle_state.end_time += 500;
+        cycle_state.paused_duration += 500;
+        assert!(!bid_commitment.is_stale(&cycle_state));
+
+        // extending the cycle makes the commitment stale
+        cycle_state.end_time += 1_000;
+        assert!(bid_commitment.is_stale(&cycle_state));
+    }
 }
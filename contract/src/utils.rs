@@ -34,6 +34,18 @@ pub fn initialize_create_metadata_args(
     }
 }
 
+/// Returns the number of the child edition minted for the winner of the given
+/// rank (the top bid has rank 0) in an auction cycle.
+///
+/// Editions are numbered consecutively across cycles, thus the edition number
+/// equals the cycle number if there is a single winner per cycle.
+pub fn edition_number(cycle_number: u64, rank: u8, winners_per_cycle: u8) -> Option<u64> {
+    cycle_number
+        .checked_sub(1)?
+        .checked_mul(u64::from(winners_per_cycle))?
+        .checked_add(u64::from(rank) + 1)
+}
+
 pub fn unpuff_metadata(metadata_state_data: &mut MetadataStateData) {
     metadata_state_data.name.retain(|c| c != '\u{0}');
     metadata_state_data.uri.retain(|c| c != '\u{0}');
@@ -73,6 +85,17 @@ mod initialize_auction_tests {
         assert_eq!("something/with/long/path/0.json", longer_uri_args.data.uri);
     }
 
+    #[test]
+    fn edition_numbers() {
+        assert_eq!(edition_number(1, 0, 1), Some(1));
+        assert_eq!(edition_number(13, 0, 1), Some(13));
+        assert_eq!(edition_number(1, 2, 3), Some(3));
+        assert_eq!(edition_number(2, 0, 3), Some(4));
+        assert_eq!(edition_number(4, 1, 3), Some(11));
+        assert_eq!(edition_number(0, 0, 1), None);
+        assert_eq!(edition_number(u64::MAX, 0, 2), None);
+    }
+
     #[test]
     fn str_padding() {
         assert_eq!(
@@ -0,0 +1,183 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::tokio;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Proposing a new contract admin
+//   - Cancelling the proposal
+//   - Accepting the admin role by the proposed admin
+//   - Verifying an auction by the new admin
+//
+// Invalid use cases:
+//   - Proposing a new contract admin by a non-admin
+//   - Accepting the admin role without a proposal
+//   - Accepting the admin role by an account that was not proposed
+//   - Verifying an auction by the previous admin
+
+#[tokio::test]
+async fn test_process_admin_transfer() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let payer = testbench.clone_payer(); // payer is the contract admin
+    let new_admin = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let other_user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (contract_bank_pubkey, _) =
+        Pubkey::find_program_address(&contract_bank_seeds(), &CONTRACT_ID);
+
+    // Invalid use case
+    // Proposing a new contract admin by a non-admin
+    let non_admin_error = propose_contract_admin_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        Some(auction_owner.keypair.pubkey()),
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(non_admin_error, AuctionContractError::ContractAdminMismatch);
+
+    // Invalid use case
+    // Accepting the admin role without a proposal
+    let no_proposal_error = accept_contract_admin_transaction(&mut testbench, &new_admin.keypair)
+        .await
+        .unwrap()
+        .err()
+        .unwrap();
+    assert_eq!(
+        no_proposal_error,
+        AuctionContractError::PendingAdminMismatch
+    );
+
+    // Proposing a new contract admin
+    propose_contract_admin_transaction(&mut testbench, &payer, Some(new_admin.keypair.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+
+    let contract_bank_state = testbench
+        .get_and_deserialize_account_data::<ContractBankState>(&contract_bank_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(contract_bank_state.contract_admin, payer.pubkey());
+    assert_eq!(
+        contract_bank_state.pending_contract_admin,
+        Some(new_admin.keypair.pubkey())
+    );
+
+    // Cancelling the proposal
+    propose_contract_admin_transaction(&mut testbench, &payer, None)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let cancelled_error = accept_contract_admin_transaction(&mut testbench, &new_admin.keypair)
+        .await
+        .unwrap()
+        .err()
+        .unwrap();
+    assert_eq!(cancelled_error, AuctionContractError::PendingAdminMismatch);
+
+    // Proposing the new contract admin again
+    propose_contract_admin_transaction(&mut testbench, &payer, Some(new_admin.keypair.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Invalid use case
+    // Accepting the admin role by an account that was not proposed
+    let not_proposed_error = accept_contract_admin_transaction(&mut testbench, &other_user.keypair)
+        .await
+        .unwrap()
+        .err()
+        .unwrap();
+    assert_eq!(
+        not_proposed_error,
+        AuctionContractError::PendingAdminMismatch
+    );
+
+    // Accepting the admin role by the proposed admin
+    accept_contract_admin_transaction(&mut testbench, &new_admin.keypair)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let contract_bank_state = testbench
+        .get_and_deserialize_account_data::<ContractBankState>(&contract_bank_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        contract_bank_state.contract_admin,
+        new_admin.keypair.pubkey()
+    );
+    assert_eq!(contract_bank_state.pending_contract_admin, None);
+    // The withdraw authority is reassigned separately
+    assert_eq!(contract_bank_state.withdraw_authority, payer.pubkey());
+
+    // Invalid use case
+    // Verifying an auction by the previous admin
+    let previous_admin_error = verify_auction_transaction(&mut testbench, auction_id, &payer)
+        .await
+        .unwrap()
+        .err()
+        .unwrap();
+    assert_eq!(
+        previous_admin_error,
+        AuctionContractError::ContractAdminMismatch
+    );
+
+    // Verifying an auction by the new admin
+    verify_auction_transaction(&mut testbench, auction_id, &new_admin.keypair)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let (auction_root_state_pubkey, _) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state.status.is_verified);
+}
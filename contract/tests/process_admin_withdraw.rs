@@ -35,6 +35,16 @@ async fn test_process_admin_withdraw() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1000),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let payer = testbench.clone_payer();
@@ -0,0 +1,424 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::{claim_funds, ClaimFundsArgs, TokenType};
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_testbench::tokio;
+use solana_sdk::signer::Signer;
+use std::convert::TryInto;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Initializing an auction with beneficiaries
+//   - Splitting the claimed funds among the beneficiaries and the owner
+//   - Modifying the beneficiaries of an auction
+//   - Paying the claimed funds to a beneficiary other than the owner
+//
+// Invalid use cases:
+//   - Initializing an auction with shares exceeding 100%
+//   - Initializing an auction with the owner as a beneficiary
+//   - Claiming funds with mismatching beneficiary accounts
+//   - Modifying the beneficiaries to contain duplicates
+//   - Setting a beneficiary that already receives a share
+//   - Claiming funds to an account other than the beneficiary
+
+#[tokio::test]
+async fn test_process_beneficiaries() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let beneficiary_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let beneficiary_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    // Invalid use case
+    // Initializing an auction with shares exceeding 100%
+    let exceeding_shares: BeneficiaryVec = vec![
+        Beneficiary {
+            pubkey: beneficiary_1.keypair.pubkey(),
+            share_bps: 6_000,
+        },
+        Beneficiary {
+            pubkey: beneficiary_2.keypair.pubkey(),
+            share_bps: 5_000,
+        },
+    ]
+    .try_into()
+    .unwrap();
+    let exceeding_shares_error = initialize_new_auction_with_beneficiaries(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+        exceeding_shares,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        exceeding_shares_error,
+        AuctionContractError::InvalidBeneficiaries
+    );
+
+    // Invalid use case
+    // Initializing an auction with the owner as a beneficiary
+    let owner_beneficiary: BeneficiaryVec = vec![Beneficiary {
+        pubkey: auction_owner.keypair.pubkey(),
+        share_bps: 1_000,
+    }]
+    .try_into()
+    .unwrap();
+    let owner_beneficiary_error = initialize_new_auction_with_beneficiaries(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+        owner_beneficiary,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        owner_beneficiary_error,
+        AuctionContractError::InvalidBeneficiaries
+    );
+
+    // Initializing the auction with beneficiaries
+    let beneficiaries: BeneficiaryVec = vec![
+        Beneficiary {
+            pubkey: beneficiary_1.keypair.pubkey(),
+            share_bps: 2_500,
+        },
+        Beneficiary {
+            pubkey: beneficiary_2.keypair.pubkey(),
+            share_bps: 1_000,
+        },
+    ]
+    .try_into()
+    .unwrap();
+    initialize_new_auction_with_beneficiaries(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+        beneficiaries,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let (auction_root_state_pubkey, _) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        auction_root_state.beneficiary,
+        auction_owner.keypair.pubkey()
+    );
+
+    // Raise some funds to claim
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 100_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &user.keypair,
+        auction_id,
+        &user.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Invalid use case
+    // Claiming funds with mismatching beneficiary accounts
+    let claim_amount = 10_000_000;
+    let swapped_claim_args = ClaimFundsArgs {
+        payer_pubkey: user.keypair.pubkey(),
+        beneficiary_pubkey: auction_owner.keypair.pubkey(),
+        auction_id,
+        cycle_number: get_current_cycle_number(&mut testbench, &auction_root_state_pubkey)
+            .await
+            .unwrap(),
+        amount: claim_amount,
+        bid_mint: None,
+        beneficiaries: vec![
+            beneficiary_2.keypair.pubkey(),
+            beneficiary_1.keypair.pubkey(),
+        ],
+    };
+    let mismatch_error = testbench
+        .process_transaction(&[claim_funds(&swapped_claim_args)], &user.keypair, None)
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .err()
+        .unwrap();
+    assert_eq!(mismatch_error, AuctionContractError::BeneficiaryMismatch);
+
+    // Splitting the claimed funds among the beneficiaries and the owner
+    let beneficiary_1_balance_before = testbench
+        .get_account_lamports(&beneficiary_1.keypair.pubkey())
+        .await
+        .unwrap();
+    let beneficiary_2_balance_before = testbench
+        .get_account_lamports(&beneficiary_2.keypair.pubkey())
+        .await
+        .unwrap();
+
+    let owner_balance_change = claim_funds_transaction(
+        &mut testbench,
+        &user.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        claim_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap() as u64;
+
+    let beneficiary_1_balance_change = testbench
+        .get_account_lamports(&beneficiary_1.keypair.pubkey())
+        .await
+        .unwrap()
+        - beneficiary_1_balance_before;
+    let beneficiary_2_balance_change = testbench
+        .get_account_lamports(&beneficiary_2.keypair.pubkey())
+        .await
+        .unwrap()
+        - beneficiary_2_balance_before;
+
+    let payout = owner_balance_change + beneficiary_1_balance_change + beneficiary_2_balance_change;
+    assert!(payout < claim_amount);
+    assert_eq!(beneficiary_1_balance_change, payout / 4);
+    assert_eq!(beneficiary_2_balance_change, payout / 10);
+
+    // Invalid use case
+    // Modifying the beneficiaries to contain duplicates
+    let duplicate_beneficiaries: BeneficiaryVec = vec![
+        Beneficiary {
+            pubkey: beneficiary_2.keypair.pubkey(),
+            share_bps: 1_000,
+        },
+        Beneficiary {
+            pubkey: beneficiary_2.keypair.pubkey(),
+            share_bps: 1_000,
+        },
+    ]
+    .try_into()
+    .unwrap();
+    let duplicate_error = modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        ModifyAuctionData {
+            new_description: None,
+            new_socials: None,
+            new_encore_period: None,
+            new_min_bid_increment: None,
+            new_beneficiaries: Some(duplicate_beneficiaries),
+            new_beneficiary: None,
+        },
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(duplicate_error, AuctionContractError::InvalidBeneficiaries);
+
+    // Modifying the beneficiaries of the auction
+    let new_beneficiaries: BeneficiaryVec = vec![Beneficiary {
+        pubkey: beneficiary_2.keypair.pubkey(),
+        share_bps: 5_000,
+    }]
+    .try_into()
+    .unwrap();
+    modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        ModifyAuctionData {
+            new_description: None,
+            new_socials: None,
+            new_encore_period: None,
+            new_min_bid_increment: None,
+            new_beneficiaries: Some(new_beneficiaries),
+            new_beneficiary: None,
+        },
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.beneficiaries.contents().len(), 1);
+
+    let beneficiary_1_balance_before = testbench
+        .get_account_lamports(&beneficiary_1.keypair.pubkey())
+        .await
+        .unwrap();
+    let beneficiary_2_balance_before = testbench
+        .get_account_lamports(&beneficiary_2.keypair.pubkey())
+        .await
+        .unwrap();
+
+    let owner_balance_change = claim_funds_transaction(
+        &mut testbench,
+        &user.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        claim_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap() as u64;
+
+    assert_eq!(
+        testbench
+            .get_account_lamports(&beneficiary_1.keypair.pubkey())
+            .await
+            .unwrap(),
+        beneficiary_1_balance_before
+    );
+    let beneficiary_2_balance_change = testbench
+        .get_account_lamports(&beneficiary_2.keypair.pubkey())
+        .await
+        .unwrap()
+        - beneficiary_2_balance_before;
+    assert_eq!(
+        beneficiary_2_balance_change,
+        (owner_balance_change + beneficiary_2_balance_change) / 2
+    );
+
+    // Invalid use case
+    // Setting a beneficiary that already receives a share
+    let shared_beneficiary_error = modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        ModifyAuctionData {
+            new_description: None,
+            new_socials: None,
+            new_encore_period: None,
+            new_min_bid_increment: None,
+            new_beneficiaries: None,
+            new_beneficiary: Some(beneficiary_2.keypair.pubkey()),
+        },
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        shared_beneficiary_error,
+        AuctionContractError::InvalidBeneficiaries
+    );
+
+    // Paying the claimed funds to a beneficiary other than the owner
+    modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        ModifyAuctionData {
+            new_description: None,
+            new_socials: None,
+            new_encore_period: None,
+            new_min_bid_increment: None,
+            new_beneficiaries: None,
+            new_beneficiary: Some(beneficiary_1.keypair.pubkey()),
+        },
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Invalid use case
+    // Claiming funds to an account other than the beneficiary
+    let owner_claim_error = claim_funds_transaction(
+        &mut testbench,
+        &user.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        claim_amount,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        owner_claim_error,
+        AuctionContractError::AuctionBeneficiaryMismatch
+    );
+
+    // Any payer may claim the funds on behalf of the beneficiary
+    let owner_balance_before = testbench
+        .get_account_lamports(&auction_owner.keypair.pubkey())
+        .await
+        .unwrap();
+    let beneficiary_2_balance_before = testbench
+        .get_account_lamports(&beneficiary_2.keypair.pubkey())
+        .await
+        .unwrap();
+
+    let beneficiary_1_balance_change = claim_funds_transaction(
+        &mut testbench,
+        &user.keypair,
+        auction_id,
+        &beneficiary_1.keypair.pubkey(),
+        claim_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap() as u64;
+
+    assert_eq!(
+        testbench
+            .get_account_lamports(&auction_owner.keypair.pubkey())
+            .await
+            .unwrap(),
+        owner_balance_before
+    );
+    let beneficiary_2_balance_change = testbench
+        .get_account_lamports(&beneficiary_2.keypair.pubkey())
+        .await
+        .unwrap()
+        - beneficiary_2_balance_before;
+    assert!(beneficiary_1_balance_change > 0);
+    assert_eq!(
+        beneficiary_2_balance_change,
+        (beneficiary_1_balance_change + beneficiary_2_balance_change) / 2
+    );
+}
@@ -3,13 +3,15 @@
 mod test_factory;
 use test_factory::*;
 
+use agsol_common::MaxSerializedLen;
 use agsol_gold_contract::instruction::factory::*;
 use agsol_gold_contract::pda::*;
 use agsol_gold_contract::state::*;
 use agsol_gold_contract::AuctionContractError;
 use agsol_gold_contract::ID as CONTRACT_ID;
-use agsol_gold_contract::UNIVERSAL_BID_FLOOR;
+use agsol_gold_contract::{MAX_BID_MEMO_LEN, UNIVERSAL_BID_FLOOR};
 use agsol_testbench::{tokio, Testbench};
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signer::Signer;
 
@@ -19,12 +21,21 @@ use solana_sdk::signer::Signer;
 //   - Bidding on nft auction
 //   - Bidding more than current top bid
 //   - Triggering encore period with a bid
+//   - Bidding the buy-now price (settles the cycle immediately)
+//   - Bidding the buy-now price with second-price settlement (winner pays the whole bid)
+//   - Bidding the current price of a Dutch auction (settles the cycle immediately)
+//   - Bidding with a memo
 //   - (Test for bidding on token auctions in `process_tokens.rs`)
 //
 // Invalid use cases:
 //   - Bidding less than the minimum bid
 //   - Bidding less than current top bid
 //   - Bidding on auction after its current cycle has ended
+//   - Bidding less than the current price of a Dutch auction
+//   - Bidding against a top bid that has changed since
+//   - Bidding on a cycle that has been closed since
+//   - Bidding with a memo that is too long
+//   - Withdrawing the winning buy-now bid with second-price settlement
 //   - (Test for bidding on ended auctions in `process_close_auction_cycle.rs`)
 
 #[tokio::test]
@@ -36,6 +47,16 @@ async fn test_process_bid() {
         encore_period: 30,
         minimum_bid_amount: 50_000_000,
         number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
     let auction_id = [2; 32];
 
@@ -65,6 +86,9 @@ async fn test_process_bid() {
         .get_account_lamports(&user_1.keypair.pubkey())
         .await
         .unwrap();
+    let bid_deposit_rent = testbench
+        .rent
+        .minimum_balance(BidDeposit::MAX_SERIALIZED_LEN);
 
     // Invalid use case
     // Test bid lower than minimum_bid
@@ -101,7 +125,10 @@ async fn test_process_bid() {
     .await;
 
     // Assert balances
-    assert_eq!(-balance_change as u64, bid_amount + TRANSACTION_FEE);
+    assert_eq!(
+        -balance_change as u64,
+        bid_amount + bid_deposit_rent + TRANSACTION_FEE
+    );
 
     // Check if treasury is updated
     assert_eq!(
@@ -131,23 +158,26 @@ async fn test_process_bid() {
     )
     .await;
 
-    // Assert balances
+    // Assert balances (the outbid bid stays deposited until withdrawn)
     assert_eq!(
-        initial_balance - TRANSACTION_FEE,
+        initial_balance - bid_amount - bid_deposit_rent - TRANSACTION_FEE,
         testbench
             .get_account_lamports(&user_1.keypair.pubkey())
             .await
             .unwrap()
     );
 
-    assert_eq!(-balance_change as u64, bid_amount_higher + TRANSACTION_FEE);
+    assert_eq!(
+        -balance_change as u64,
+        bid_amount_higher + bid_deposit_rent + TRANSACTION_FEE
+    );
 
     // Check if treasury is updated
     assert_eq!(
         get_bank_balance_without_rent(&mut testbench, auction_id)
             .await
             .unwrap(),
-        bid_amount_higher
+        bid_amount + bid_amount_higher
     );
 
     // Invalid use case
@@ -217,6 +247,16 @@ async fn test_encore_bid() {
         encore_period: 200,
         minimum_bid_amount: 50_000_000,
         number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     initialize_new_auction(
@@ -281,13 +321,558 @@ async fn test_encore_bid() {
     );
 }
 
+#[tokio::test]
+async fn test_buy_now_bid() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [2; 32];
+    let buy_now_price = 200_000_000;
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: Some(buy_now_price),
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    let bid_amount = 100_000_000;
+    place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_amount)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Bid the buy-now price
+    place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, buy_now_price)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Check that the previous top bidder can withdraw their bid
+    let bid_deposit_rent = testbench
+        .rent
+        .minimum_balance(BidDeposit::MAX_SERIALIZED_LEN);
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        bid_amount + bid_deposit_rent - TRANSACTION_FEE
+    );
+
+    // Check that the cycle was settled without waiting for its end
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+    assert_eq!(auction_root_state.unclaimed_rewards, 1);
+    assert_eq!(auction_root_state.available_funds, buy_now_price);
+    assert_eq!(auction_root_state.all_time_treasury, buy_now_price);
+
+    let settled_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    let winning_bid = settled_cycle_state.bid_history.get_last_element().unwrap();
+    assert_eq!(winning_bid.bidder_pubkey, user_2.keypair.pubkey());
+    assert_eq!(winning_bid.bid_amount, buy_now_price);
+
+    let (_, next_cycle_state) = get_auction_cycle_state(&mut testbench, &auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(next_cycle_state.bid_history.is_empty());
+
+    // The reward of the settled cycle can be claimed right away
+    claim_rewards_transaction(
+        &mut testbench,
+        &user_2.keypair,
+        auction_id,
+        &user_2.keypair.pubkey(),
+        1,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_buy_now_bid_with_second_price_settlement() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [2; 32];
+    let buy_now_price = 200_000_000;
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: Some(BidIncrement {
+            amount: 10_000_000,
+            basis_points: 0,
+        }),
+        buy_now_price: Some(buy_now_price),
+        kind: AuctionKind::English,
+        second_price_settlement: true,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    let bid_amount = 100_000_000;
+    place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_amount)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Bid the buy-now price
+    place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, buy_now_price)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // The winner pays the whole buy-now bid instead of the clearing price
+    let settled_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(settled_cycle_state.settled_price, Some(buy_now_price));
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+    assert_eq!(auction_root_state.available_funds, buy_now_price);
+
+    // Invalid use case
+    // Withdrawing the winning buy-now bid with second-price settlement
+    let withdraw_winning_bid_error = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        &user_2.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        withdraw_winning_bid_error,
+        AuctionContractError::NothingToWithdraw
+    );
+
+    // The outbid bidder can still withdraw their bid
+    withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // The whole buy-now price can be claimed from the auction bank
+    claim_funds_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        buy_now_price,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.available_funds, 0);
+    assert_eq!(auction_root_state.unsettled_deposits, 0);
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_dutch_auction_bid() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [3; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::Dutch(DutchPriceCurve {
+            start_price: 1_000_000_000,
+            floor_price: 100_000_000,
+        }),
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    // Let the price decay to about the half of the price range
+    testbench.warp_n_seconds(500).await.unwrap();
+
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    let current_price = auction_config
+        .current_price(
+            auction_cycle_state.end_time,
+            testbench.block_time().await.unwrap(),
+        )
+        .unwrap();
+    assert!(current_price < 1_000_000_000);
+    assert!(current_price > 100_000_000);
+
+    // Bidding less than the current price
+    let lower_bid_error = place_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user.keypair,
+        auction_config.minimum_bid_amount,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(lower_bid_error, AuctionContractError::InvalidBidAmount);
+
+    // Bidding the current price (the price can only decrease until the
+    // transaction is processed)
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, current_price)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Check that the cycle was settled without waiting for its end
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+    assert_eq!(auction_root_state.unclaimed_rewards, 1);
+    assert_eq!(auction_root_state.available_funds, current_price);
+
+    let settled_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    let winning_bid = settled_cycle_state.bid_history.get_last_element().unwrap();
+    assert_eq!(winning_bid.bidder_pubkey, user.keypair.pubkey());
+    assert_eq!(winning_bid.bid_amount, current_price);
+}
+
+#[tokio::test]
+async fn test_stale_bid() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [4; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    // The bid of user 2 is made while there are no bids yet
+    let bid_amount = 100_000_000;
+    let stale_bid_args = PlaceBidArgs {
+        bidder_pubkey: user_2.keypair.pubkey(),
+        auction_id,
+        cycle_number: 1,
+        amount: bid_amount,
+        expected_top_bid: Some(0),
+        bid_log_page: 0,
+        memo: None,
+        referrer: None,
+        bid_mint: None,
+        bidder_token_account: None,
+        settle_cycle: None,
+    };
+
+    place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, 50_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Bidding against a top bid that has changed since
+    let stale_top_bid_error = testbench
+        .process_transaction(&[place_bid(&stale_bid_args)], &user_2.keypair, None)
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .err()
+        .unwrap();
+    assert_eq!(stale_top_bid_error, AuctionContractError::StaleBid);
+
+    // Bidding on a cycle that has been closed since
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let stale_bid_args = PlaceBidArgs {
+        expected_top_bid: Some(50_000_000),
+        ..stale_bid_args
+    };
+    let stale_cycle_error = testbench
+        .process_transaction(&[place_bid(&stale_bid_args)], &user_2.keypair, None)
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .err()
+        .unwrap();
+    assert_eq!(stale_cycle_error, AuctionContractError::StaleBid);
+
+    // The bid is accepted on the new cycle
+    place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, bid_amount)
+        .await
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_bid_memo() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [5; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    // Bidding with a memo that is too long (bypassing the length check of
+    // the client)
+    let long_memo = "x".repeat(MAX_BID_MEMO_LEN + 1);
+    let place_bid_args = PlaceBidArgs {
+        bidder_pubkey: user.keypair.pubkey(),
+        auction_id,
+        cycle_number: 1,
+        amount: 50_000_000,
+        expected_top_bid: Some(0),
+        bid_log_page: 0,
+        memo: Some(BidMemo::try_from_slice(&long_memo.try_to_vec().unwrap()).unwrap()),
+        referrer: None,
+        bid_mint: None,
+        bidder_token_account: None,
+        settle_cycle: None,
+    };
+    let long_memo_error = testbench
+        .process_transaction(&[place_bid(&place_bid_args)], &user.keypair, None)
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .err()
+        .unwrap();
+    assert_eq!(long_memo_error, AuctionContractError::StringTooLong);
+
+    let memo = "for the dev fund!";
+    let place_bid_args = PlaceBidArgs {
+        bidder_pubkey: user.keypair.pubkey(),
+        auction_id,
+        cycle_number: 1,
+        amount: 50_000_000,
+        expected_top_bid: Some(0),
+        bid_log_page: 0,
+        memo: Some(BidMemo::try_from(memo).unwrap()),
+        referrer: None,
+        bid_mint: None,
+        bidder_token_account: None,
+        settle_cycle: None,
+    };
+    testbench
+        .process_transaction(&[place_bid(&place_bid_args)], &user.keypair, None)
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .unwrap();
+
+    // The memo is stored in the bid history and in the bid log
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.memo.unwrap().contents(), memo);
+
+    let (bid_log_page_pubkey, _) = Pubkey::find_program_address(
+        &bid_log_page_seeds(
+            &auction_root_state_pubkey,
+            &1_u64.to_le_bytes(),
+            &0_u64.to_le_bytes(),
+        ),
+        &CONTRACT_ID,
+    );
+    let bid_log_page = testbench
+        .get_and_deserialize_account_data::<BidLogPage>(&bid_log_page_pubkey)
+        .await
+        .unwrap();
+    let logged_bid = bid_log_page.bids.get_last_element().unwrap();
+    assert_eq!(logged_bid.memo.as_ref().unwrap().contents(), memo);
+
+    // Bids without a memo are placed as before
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 60_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(top_bid.memo.is_none());
+}
+
 async fn assert_auction_state(
     testbench: &mut Testbench,
     auction_id: [u8; 32],
     expected_top_bidder: &Pubkey,
     bid_amount: u64,
 ) {
-    let (_auction_root_state_pubkey, auction_cycle_state_pubkey) =
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
         get_state_pubkeys(testbench, auction_id).await.unwrap();
     let (auction_bank_pubkey, _) =
         Pubkey::find_program_address(&auction_bank_seeds(&auction_id), &CONTRACT_ID);
@@ -301,10 +886,16 @@ async fn assert_auction_state(
         assert_eq!(top_bid.bid_amount, bid_amount);
     }
 
-    // Assert fund holding account balance
+    // Assert that the fund holding account holds every bid deposit
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state.unsettled_deposits >= bid_amount);
+
     let min_balance = testbench.rent.minimum_balance(0);
     assert_eq!(
-        min_balance + bid_amount,
+        min_balance + auction_root_state.unsettled_deposits,
         testbench
             .get_account_lamports(&auction_bank_pubkey)
             .await
@@ -0,0 +1,315 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::BID_LOG_PAGE_LENGTH;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::{tokio, Testbench};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Logging every bid of a cycle in the order of placement
+//   - Creating a new bid log page once the last one is full
+//   - Starting a new bid log on the next cycle
+//   - Logging proxy bids and their counter-bids
+//   - Logging revealed sealed bids
+
+#[tokio::test]
+async fn test_process_bid_log() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    // More bids than the bid history and a single log page can hold
+    let bid_count = BID_LOG_PAGE_LENGTH + 8;
+    let mut placed_bids = Vec::with_capacity(bid_count);
+    for i in 0..bid_count {
+        let user = if i % 2 == 0 { &user_1 } else { &user_2 };
+        let bid_amount = 50_000_000 + i as u64 * 1_000_000;
+        place_bid_transaction(&mut testbench, auction_id, &user.keypair, bid_amount)
+            .await
+            .unwrap()
+            .unwrap();
+        placed_bids.push((user.keypair.pubkey(), bid_amount));
+    }
+
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_cycle_state.bid_count, bid_count as u64);
+    assert_eq!(auction_cycle_state.bid_log_page(), 1);
+
+    let mut logged_bids = Vec::with_capacity(bid_count);
+    for page in 0..2_u64 {
+        let (bid_log_page_pubkey, _) = Pubkey::find_program_address(
+            &bid_log_page_seeds(
+                &auction_root_state_pubkey,
+                &1_u64.to_le_bytes(),
+                &page.to_le_bytes(),
+            ),
+            &CONTRACT_ID,
+        );
+        let bid_log_page = testbench
+            .get_and_deserialize_account_data::<BidLogPage>(&bid_log_page_pubkey)
+            .await
+            .unwrap();
+        logged_bids.extend(
+            bid_log_page
+                .bids
+                .contents()
+                .iter()
+                .map(|bid| (bid.bidder_pubkey, bid.bid_amount)),
+        );
+    }
+    assert_eq!(logged_bids, placed_bids);
+
+    // The next cycle starts a new bid log
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, 50_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let (_, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_cycle_state.bid_count, 1);
+
+    let (bid_log_page_pubkey, _) = Pubkey::find_program_address(
+        &bid_log_page_seeds(
+            &auction_root_state_pubkey,
+            &2_u64.to_le_bytes(),
+            &0_u64.to_le_bytes(),
+        ),
+        &CONTRACT_ID,
+    );
+    let bid_log_page = testbench
+        .get_and_deserialize_account_data::<BidLogPage>(&bid_log_page_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(bid_log_page.bids.len(), 1);
+    assert_eq!(
+        bid_log_page.bids.get_last_element().unwrap().bidder_pubkey,
+        user_2.keypair.pubkey()
+    );
+}
+
+async fn get_logged_bids(
+    testbench: &mut Testbench,
+    auction_root_state_pubkey: &Pubkey,
+) -> Vec<BidData> {
+    let (bid_log_page_pubkey, _) = Pubkey::find_program_address(
+        &bid_log_page_seeds(
+            auction_root_state_pubkey,
+            &1_u64.to_le_bytes(),
+            &0_u64.to_le_bytes(),
+        ),
+        &CONTRACT_ID,
+    );
+    testbench
+        .get_and_deserialize_account_data::<BidLogPage>(&bid_log_page_pubkey)
+        .await
+        .unwrap()
+        .bids
+        .contents()
+        .to_vec()
+}
+
+#[tokio::test]
+async fn test_bid_log_proxy_and_sealed_bids() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let english_auction_id = [1; 32];
+    let sealed_auction_id = [2; 32];
+    let mut auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        english_auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    auction_config.kind = AuctionKind::Sealed(SealedBidConfig { reveal_period: 500 });
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        sealed_auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    // Logging proxy bids and their counter-bids
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, english_auction_id)
+            .await
+            .unwrap();
+
+    place_proxy_bid_transaction(
+        &mut testbench,
+        english_auction_id,
+        &user_1.keypair,
+        500_000_000,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    // Countered by the proxy bid
+    place_bid_transaction(
+        &mut testbench,
+        english_auction_id,
+        &user_2.keypair,
+        100_000_000,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    // Countered by the proxy bid as well
+    place_proxy_bid_transaction(
+        &mut testbench,
+        english_auction_id,
+        &user_2.keypair,
+        200_000_000,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_cycle_state.bid_count, 5);
+    let logged_bids = get_logged_bids(&mut testbench, &auction_root_state_pubkey).await;
+    let recorded_bids = auction_cycle_state.bid_history.contents();
+    assert_eq!(logged_bids.len(), recorded_bids.len());
+    for (logged_bid, recorded_bid) in logged_bids.iter().zip(recorded_bids) {
+        assert_eq!(logged_bid.bidder_pubkey, recorded_bid.bidder_pubkey);
+        assert_eq!(logged_bid.bid_amount, recorded_bid.bid_amount);
+    }
+    assert_eq!(
+        logged_bids.last().unwrap().bidder_pubkey,
+        user_1.keypair.pubkey()
+    );
+
+    // Logging revealed sealed bids
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, sealed_auction_id)
+            .await
+            .unwrap();
+
+    let (bid_amount, salt) = (150_000_000, [1; 32]);
+    commit_bid_transaction(
+        &mut testbench,
+        sealed_auction_id,
+        &user_1.keypair,
+        bid_amount,
+        salt,
+        bid_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    warp_to_cycle_end(&mut testbench, sealed_auction_id)
+        .await
+        .unwrap();
+    reveal_bid_transaction(
+        &mut testbench,
+        sealed_auction_id,
+        &user_1.keypair,
+        bid_amount,
+        salt,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_cycle_state.bid_count, 1);
+    let logged_bids = get_logged_bids(&mut testbench, &auction_root_state_pubkey).await;
+    assert_eq!(logged_bids.len(), 1);
+    assert_eq!(logged_bids[0].bidder_pubkey, user_1.keypair.pubkey());
+    assert_eq!(logged_bids[0].bid_amount, bid_amount);
+}
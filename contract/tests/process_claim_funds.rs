@@ -3,7 +3,7 @@
 mod test_factory;
 use test_factory::*;
 
-use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::instruction::factory::{claim_funds, ClaimFundsArgs, TokenType};
 use agsol_gold_contract::pda::*;
 use agsol_gold_contract::state::*;
 use agsol_gold_contract::AuctionContractError;
@@ -22,6 +22,7 @@ use solana_sdk::signer::Signer;
 //   - Claiming from an auction with insufficient treasury
 //   - Claiming funds received in the current cycle
 //   - Claiming all funds (including auction bank rent) from ongoing auction
+//   - Claiming funds with a fake protocol fee state account
 
 #[tokio::test]
 async fn test_process_claim_funds() {
@@ -33,6 +34,16 @@ async fn test_process_claim_funds() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(2),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
@@ -53,7 +64,7 @@ async fn test_process_claim_funds() {
     .unwrap()
     .unwrap();
 
-    let fee_multiplier = get_protocol_fee_multiplier(&mut testbench).await;
+    let fee_state = get_protocol_fee_state(&mut testbench).await;
 
     let payer = testbench.clone_payer();
 
@@ -151,6 +162,34 @@ async fn test_process_claim_funds() {
     assert_eq!(auction_root_state.available_funds, bid_amount);
     assert_eq!(auction_root_state.all_time_treasury, bid_amount);
 
+    // Invalid use case
+    // Claiming funds with a fake protocol fee state account
+    let claim_funds_args = ClaimFundsArgs {
+        payer_pubkey: payer.pubkey(),
+        beneficiary_pubkey: auction_owner.keypair.pubkey(),
+        auction_id,
+        cycle_number: get_current_cycle_number(&mut testbench, &auction_root_state_pubkey)
+            .await
+            .unwrap(),
+        amount: claim_amount,
+        bid_mint: None,
+        beneficiaries: get_beneficiaries(&mut testbench, &auction_root_state_pubkey)
+            .await
+            .unwrap(),
+    };
+    let mut claim_funds_ix = claim_funds(&claim_funds_args);
+    // The protocol fee state is the 7th account
+    claim_funds_ix.accounts[6].pubkey = user_1.keypair.pubkey();
+    let fake_fee_state_error = testbench
+        .process_transaction(&[claim_funds_ix], &payer, None)
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .err()
+        .unwrap();
+
+    assert_eq!(fake_fee_state_error, AuctionContractError::InvalidSeeds);
+
     // This should be successful because the auction cycle of the bid has ended
     let owner_balance_change = claim_funds_transaction(
         &mut testbench,
@@ -163,7 +202,7 @@ async fn test_process_claim_funds() {
     .unwrap()
     .unwrap();
 
-    let protocol_fee = (claim_amount as f64 * fee_multiplier) as u64;
+    let protocol_fee = protocol_fee_share(claim_amount, fee_state.fee);
     assert_eq!(claim_amount - protocol_fee, owner_balance_change as u64);
 
     let auction_root_state = testbench
@@ -203,7 +242,7 @@ async fn test_process_claim_funds() {
     .unwrap()
     .unwrap();
 
-    let protocol_fee = (claim_amount as f64 * fee_multiplier) as u64;
+    let protocol_fee = protocol_fee_share(claim_amount, fee_state.fee);
     assert_eq!(claim_amount - protocol_fee, owner_balance_change as u64);
 
     // Claiming ALL funds from the auction should be an error because it has not ended yet.
@@ -245,7 +284,7 @@ async fn test_process_claim_funds() {
         .await
         .unwrap();
 
-    let protocol_fee = (claim_amount as f64 * fee_multiplier) as u64;
+    let protocol_fee = protocol_fee_share(claim_amount, fee_state.fee);
     assert_eq!(claim_amount - protocol_fee, owner_balance_change as u64);
 
     assert_eq!(
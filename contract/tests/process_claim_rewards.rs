@@ -3,6 +3,7 @@ mod test_factory;
 
 use test_factory::*;
 
+use agsol_common::MaxSerializedLen;
 use agsol_gold_contract::instruction::factory::TokenType;
 use agsol_gold_contract::pda::*;
 use agsol_gold_contract::state::*;
@@ -21,12 +22,14 @@ const CLAIM_REWARDS_COST_NFT: u64 = 12_799_440;
 // Valid use cases:
 //   - Claiming rewards from nft auctions
 //   - Claiming rewards from nft auctions chronologically and non-chronologically
+//   - Claiming rewards of multiple winners per cycle by rank
 //   - (Test for claiming rewards from token auctions in `process_tokens.rs`)
 //
 // Invalid use cases:
 //   - Claiming rewards from ongoing cycle with and without placed bets
 //   - Claiming rewards using other than the top bidder account
 //   - Claiming already claimed rewards
+//   - Claiming rewards of a rank without a winner
 
 #[tokio::test]
 async fn test_process_claim_rewards_nft() {
@@ -38,6 +41,16 @@ async fn test_process_claim_rewards_nft() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1000),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
@@ -241,6 +254,16 @@ async fn test_process_claim_rewards_nft_non_chronological() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1000),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
@@ -423,3 +446,230 @@ async fn test_process_claim_rewards_nft_non_chronological() {
     let master_edition = EditionPda::new(EditionType::Master, &auction_id);
     assert_metadata_uri(&mut testbench, &master_edition, "3.json").await;
 }
+
+#[tokio::test]
+async fn test_process_claim_rewards_multiple_winners() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 60,
+        encore_period: 1,
+        minimum_bid_amount: 50_000_000, // lamports
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 2,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_3 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let payer = TestUser::new(&mut testbench)
+        .await
+        .unwrap()
+        .unwrap()
+        .keypair;
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&auction_id), &CONTRACT_ID);
+
+    // Fill the bid book
+    place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, 100_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, 200_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        300_000_000
+    );
+
+    // The outbid lowest winner can withdraw their bid
+    place_bid_transaction(&mut testbench, auction_id, &user_3.keypair, 150_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    let bid_deposit_rent = testbench
+        .rent
+        .minimum_balance(BidDeposit::MAX_SERIALIZED_LEN);
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        100_000_000 + bid_deposit_rent - TRANSACTION_FEE
+    );
+
+    // Winners raising their bid only pay the difference
+    let balance_change =
+        place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, 250_000_000)
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(-balance_change as u64, 50_000_000 + TRANSACTION_FEE);
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        400_000_000
+    );
+
+    // Close the cycle
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &payer,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.available_funds, 400_000_000);
+    assert_eq!(auction_root_state.unclaimed_rewards, 2);
+
+    // Invalid use case
+    // Claiming rewards of a rank using another winner's account
+    let claim_reward_invalid_winner_error = claim_ranked_rewards_transaction(
+        &mut testbench,
+        &payer,
+        auction_id,
+        &user_2.keypair.pubkey(),
+        1,
+        1,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        claim_reward_invalid_winner_error,
+        AuctionContractError::TopBidderAccountMismatch
+    );
+
+    // Invalid use case
+    // Claiming rewards of a rank without a winner
+    let claim_reward_invalid_rank_error = claim_ranked_rewards_transaction(
+        &mut testbench,
+        &payer,
+        auction_id,
+        &user_1.keypair.pubkey(),
+        1,
+        2,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        claim_reward_invalid_rank_error,
+        AuctionContractError::InvalidRewardRank
+    );
+
+    // Claim rewards of both winners
+    claim_ranked_rewards_transaction(
+        &mut testbench,
+        &payer,
+        auction_id,
+        &user_3.keypair.pubkey(),
+        1,
+        1,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    claim_ranked_rewards_transaction(
+        &mut testbench,
+        &payer,
+        auction_id,
+        &user_2.keypair.pubkey(),
+        1,
+        0,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.unclaimed_rewards, 0);
+
+    // Each winner receives a separate edition
+    for (edition, winner) in [(1, &user_2), (2, &user_3)] {
+        let child_edition = EditionPda::new(EditionType::Child(edition), &auction_id);
+        let winner_nft_account = testbench
+            .get_token_account(&child_edition.holding)
+            .await
+            .unwrap();
+        assert_eq!(winner_nft_account.mint, child_edition.mint);
+        assert_eq!(winner_nft_account.owner, winner.keypair.pubkey());
+        assert_eq!(winner_nft_account.amount, 1);
+        assert_metadata_uri(&mut testbench, &child_edition, "1.json").await;
+    }
+
+    // Invalid use case
+    // Trying to claim rewards again
+    let repeated_claim_reward_error = claim_ranked_rewards_transaction(
+        &mut testbench,
+        &payer,
+        auction_id,
+        &user_3.keypair.pubkey(),
+        1,
+        1,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        repeated_claim_reward_error,
+        AuctionContractError::RewardAlreadyClaimed
+    );
+}
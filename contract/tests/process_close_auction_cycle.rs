@@ -3,6 +3,7 @@ mod test_factory;
 
 use test_factory::*;
 
+use agsol_common::MaxSerializedLen;
 use agsol_gold_contract::instruction::factory::TokenType;
 use agsol_gold_contract::pda::*;
 use agsol_gold_contract::state::*;
@@ -14,7 +15,6 @@ use agsol_token_metadata::instruction::CreateMetadataAccountArgs;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::Signer;
 
-const CLOSE_CYCLE_COST: u64 = 3_758_400;
 const CLOSE_LAST_CYCLE_COST: u64 = 0;
 
 // This file includes the following tests:
@@ -25,6 +25,8 @@ const CLOSE_LAST_CYCLE_COST: u64 = 0;
 //   - Closing cycle on auction with no bid placed
 //   - Closing cycles until auction is moved to the secondary pool
 //   - Bidding on idle auction which is consequently moved to the primary pool
+//   - Closing cycle with a top bid below the reserve price (bid can be withdrawn)
+//   - Closing second-price settled cycle (winner pays the second-highest bid)
 //
 // Invalid use cases:
 //   - Bidding on finished auction
@@ -40,6 +42,16 @@ async fn test_process_close_auction_cycle() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1000),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let (auction_root_state_pubkey, _) =
@@ -167,7 +179,10 @@ async fn test_process_close_auction_cycle() {
     .unwrap()
     .unwrap();
 
-    assert_eq!(-balance_change as u64, CLOSE_CYCLE_COST + TRANSACTION_FEE,);
+    assert_eq!(
+        -balance_change as u64,
+        close_cycle_cost(&testbench) + TRANSACTION_FEE
+    );
 
     // Check if idle cycle streak has been reset
     let auction_root_state = testbench
@@ -220,6 +235,271 @@ async fn test_process_close_auction_cycle() {
     );
 }
 
+#[tokio::test]
+async fn test_close_cycle_below_reserve_price() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 60,
+        encore_period: 1,
+        minimum_bid_amount: 50_000_000, // lamports
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: Some(ReservePrice {
+            amount: 100_000_000,
+            is_hidden: true,
+        }),
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    let auction_cycle_payer = TestUser::new(&mut testbench)
+        .await
+        .unwrap()
+        .unwrap()
+        .keypair;
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    // Place bid below the reserve price
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let bid_amount = 60_000_000;
+    place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_amount)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Close cycle
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+
+    let balance_change = close_cycle_transaction(
+        &mut testbench,
+        &auction_cycle_payer,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(-balance_change as u64, TRANSACTION_FEE);
+
+    // Check that the bid can be withdrawn
+    let bid_deposit_rent = testbench
+        .rent
+        .minimum_balance(BidDeposit::MAX_SERIALIZED_LEN);
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        bid_amount + bid_deposit_rent - TRANSACTION_FEE
+    );
+
+    // Check that the cycle was treated as an idle one
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 1);
+    assert_eq!(auction_root_state.status.current_idle_cycle_streak, 1);
+    assert_eq!(auction_root_state.unclaimed_rewards, 0);
+    assert_eq!(auction_root_state.available_funds, 0);
+    assert_eq!(auction_root_state.all_time_treasury, 0);
+    assert_eq!(auction_root_state.unsettled_deposits, 0);
+    assert!(get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Place bid reaching the reserve price
+    let reserve_bid_amount = 100_000_000;
+    place_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        reserve_bid_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_cycle_payer,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+    assert_eq!(auction_root_state.status.current_idle_cycle_streak, 0);
+    assert_eq!(auction_root_state.unclaimed_rewards, 1);
+    assert_eq!(auction_root_state.available_funds, reserve_bid_amount);
+}
+
+#[tokio::test]
+async fn test_close_cycle_second_price_settlement() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 60,
+        encore_period: 1,
+        minimum_bid_amount: 50_000_000, // lamports
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: Some(BidIncrement {
+            amount: 10_000_000,
+            basis_points: 0,
+        }),
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: true,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    let auction_cycle_payer = TestUser::new(&mut testbench)
+        .await
+        .unwrap()
+        .unwrap()
+        .keypair;
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let (auction_root_state_pubkey, _) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, 100_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    let winning_bid_amount = 500_000_000;
+    place_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        winning_bid_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Close cycle
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_cycle_payer,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // The winner pays the second-highest bid plus the minimum increment and
+    // can withdraw the rest of their bid
+    let clearing_price = 110_000_000;
+    let bid_deposit_rent = testbench
+        .rent
+        .minimum_balance(BidDeposit::MAX_SERIALIZED_LEN);
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        &user_2.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        winning_bid_amount - clearing_price + bid_deposit_rent - TRANSACTION_FEE
+    );
+
+    withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+    assert_eq!(auction_root_state.unclaimed_rewards, 1);
+    assert_eq!(auction_root_state.available_funds, clearing_price);
+    assert_eq!(auction_root_state.all_time_treasury, clearing_price);
+    assert_eq!(auction_root_state.unsettled_deposits, 0);
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        clearing_price
+    );
+}
+
 #[tokio::test]
 async fn test_close_cycle_on_finished_auction() {
     let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
@@ -230,6 +510,16 @@ async fn test_close_cycle_on_finished_auction() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
@@ -329,6 +619,16 @@ async fn test_close_cycle_child_metadata_change_not_repeating() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(3),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let auction_cycle_payer = TestUser::new(&mut testbench)
@@ -463,6 +763,16 @@ async fn test_child_close_cycle_metadata_change_repeating() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(3),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let auction_cycle_payer = TestUser::new(&mut testbench)
@@ -611,6 +921,16 @@ async fn test_process_close_idle_rapid_auction() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1000),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let (auction_root_state_pubkey, _) =
@@ -0,0 +1,187 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::*;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::tokio;
+use agsol_testbench::Testbench;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Counting the created auctions
+//   - Counting the finished auctions when their last cycle is closed
+//   - Counting the protocol fees collected on claiming funds
+//   - Counting the lamports withdrawn from the contract bank
+//   - Counting the protocol fees collected on deleting auctions
+//   - Counting the deleted finished and ongoing auctions (without counting
+//     them as finished again)
+
+async fn get_contract_stats(testbench: &mut Testbench) -> ContractStats {
+    let (contract_bank_pubkey, _) =
+        Pubkey::find_program_address(&contract_bank_seeds(), &CONTRACT_ID);
+    testbench
+        .get_and_deserialize_account_data::<ContractBankState>(&contract_bank_pubkey)
+        .await
+        .unwrap()
+        .stats
+}
+
+#[tokio::test]
+async fn test_process_contract_stats() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_config = AuctionConfig {
+        cycle_period: 60,
+        encore_period: 0,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(1),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    let payer = testbench.clone_payer();
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    assert_eq!(
+        get_contract_stats(&mut testbench).await,
+        ContractStats::default()
+    );
+
+    // Counting the created auctions
+    let finished_auction_id = [1; 32];
+    let ongoing_auction_id = [2; 32];
+    for auction_id in [finished_auction_id, ongoing_auction_id] {
+        initialize_new_auction(
+            &mut testbench,
+            &auction_owner.keypair,
+            &auction_config,
+            auction_id,
+            TokenType::Nft,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    }
+
+    let stats = get_contract_stats(&mut testbench).await;
+    assert_eq!(stats.auctions_created, 2);
+
+    // Counting the finished auctions when their last cycle is closed
+    let bid_amount = 100_000_000;
+    place_bid_transaction(
+        &mut testbench,
+        finished_auction_id,
+        &user.keypair,
+        bid_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    warp_to_cycle_end(&mut testbench, finished_auction_id)
+        .await
+        .unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &payer,
+        finished_auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let stats = get_contract_stats(&mut testbench).await;
+    assert_eq!(stats.auctions_finished, 1);
+    assert_eq!(stats.auctions_deleted, 0);
+
+    claim_rewards_transaction(
+        &mut testbench,
+        &user.keypair,
+        finished_auction_id,
+        &user.keypair.pubkey(),
+        1,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Counting the protocol fees collected on claiming funds
+    let claim_amount = 10_000_000;
+    claim_funds_transaction(
+        &mut testbench,
+        &payer,
+        finished_auction_id,
+        &auction_owner.keypair.pubkey(),
+        claim_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let fee_state = get_protocol_fee_state(&mut testbench).await;
+    let mut fees_collected = protocol_fee_share(claim_amount, fee_state.fee);
+    let stats = get_contract_stats(&mut testbench).await;
+    assert_eq!(stats.fees_collected, fees_collected);
+    assert_eq!(stats.fees_withdrawn, 0);
+
+    // Counting the lamports withdrawn from the contract bank
+    let withdraw_amount = 10_000;
+    let admin_withdraw_args = AdminWithdrawArgs {
+        withdraw_authority: payer.pubkey(), // payer is the withdraw authority
+        amount: withdraw_amount,
+    };
+    testbench
+        .process_transaction(&[admin_withdraw(&admin_withdraw_args)], &payer, None)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let stats = get_contract_stats(&mut testbench).await;
+    assert_eq!(stats.fees_collected, fees_collected);
+    assert_eq!(stats.fees_withdrawn, withdraw_amount);
+
+    // Counting the protocol fees collected on deleting auctions and the
+    // deleted auctions
+    for auction_id in [finished_auction_id, ongoing_auction_id] {
+        let (auction_bank_pubkey, _) =
+            Pubkey::find_program_address(&auction_bank_seeds(&auction_id), &CONTRACT_ID);
+        let auction_bank_balance = testbench
+            .get_account_lamports(&auction_bank_pubkey)
+            .await
+            .unwrap();
+        fees_collected += protocol_fee_share(auction_bank_balance, fee_state.fee);
+
+        delete_auction_transaction(&mut testbench, &auction_owner.keypair, auction_id)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    let stats = get_contract_stats(&mut testbench).await;
+    assert_eq!(
+        stats,
+        ContractStats {
+            fees_collected,
+            fees_withdrawn: withdraw_amount,
+            auctions_created: 2,
+            auctions_finished: 1,
+            auctions_deleted: 2,
+        }
+    );
+}
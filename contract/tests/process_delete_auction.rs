@@ -7,7 +7,7 @@ use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::keypair::Keypair;
 use solana_sdk::signer::Signer;
 
-use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::instruction::factory::{delete_auction, DeleteAuctionArgs, TokenType};
 use agsol_gold_contract::pda::*;
 use agsol_gold_contract::state::*;
 use agsol_gold_contract::AuctionContractError;
@@ -29,10 +29,12 @@ use std::str::FromStr;
 //   - Deleting an auction with its bank deallocated by claiming all its funds
 //   - Deleting finished and ongoing auctions
 //   - Claiming funds on deleting auctions
+//   - Paying the remaining funds to a beneficiary other than the owner
 //
 // Invalid use cases:
 //   - Deleting an auction without the owner's signature
 //   - Deleting an auction with unclaimed rewards
+//   - Deleting an auction with a mismatching beneficiary account
 #[tokio::test]
 async fn test_delete_auction_immediately() {
     let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
@@ -43,6 +45,16 @@ async fn test_delete_auction_immediately() {
         encore_period: 0,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     initialize_new_auction(
@@ -110,6 +122,16 @@ async fn test_delete_auction_with_unclaimed_rewards() {
         encore_period: 0,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     initialize_new_auction(
@@ -211,6 +233,16 @@ async fn test_delete_small_auction() {
         encore_period: 0,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let payer = testbench.clone_payer();
@@ -286,6 +318,16 @@ async fn test_delete_claimed_auction() {
         encore_period: 0,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles,
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let payer = testbench.clone_payer();
@@ -377,6 +419,16 @@ async fn test_delete_just_long_enough_finished_auction() {
         encore_period: 0,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(RECOMMENDED_CYCLE_STATES_DELETED_PER_CALL),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let payer = testbench.clone_payer();
@@ -480,8 +532,8 @@ async fn test_delete_just_long_enough_finished_auction() {
     );
 
     // Test that all state balances are claimed correctly
-    let fee_multiplier = get_protocol_fee_multiplier(&mut testbench).await;
-    let protocol_fee = (auction_bank_balance as f64 * fee_multiplier) as u64;
+    let fee_state = get_protocol_fee_state(&mut testbench).await;
+    let protocol_fee = protocol_fee_share(auction_bank_balance, fee_state.fee);
     assert_eq!(
         protocol_fee + auction_cycle_balance_sum,
         contract_balance_after - contract_balance_before
@@ -502,6 +554,16 @@ async fn test_delete_long_ongoing_auction() {
         encore_period: 0,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(RECOMMENDED_CYCLE_STATES_DELETED_PER_CALL + 2),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let payer = testbench.clone_payer();
@@ -607,6 +669,132 @@ async fn test_delete_long_ongoing_auction() {
     assert!(are_given_cycle_states_deleted(&mut testbench, &auction_root_state_pubkey, 1, 1).await);
 }
 
+#[tokio::test]
+async fn test_delete_auction_pays_beneficiary() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 60,
+        encore_period: 0,
+        minimum_bid_amount: 50_000_000, // lamports
+        number_of_cycles: Some(1),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    let payer = testbench.clone_payer();
+    let beneficiary = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        ModifyAuctionData {
+            new_description: None,
+            new_socials: None,
+            new_encore_period: None,
+            new_min_bid_increment: None,
+            new_beneficiaries: None,
+            new_beneficiary: Some(beneficiary.keypair.pubkey()),
+        },
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    close_and_claim_n_cycles(&mut testbench, auction_id, &auction_owner, &payer, 1).await;
+
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&auction_id), &CONTRACT_ID);
+    let (auction_bank_pubkey, _) =
+        Pubkey::find_program_address(&auction_bank_seeds(&auction_id), &CONTRACT_ID);
+
+    // Invalid use case
+    // Deleting an auction with a mismatching beneficiary account
+    let delete_auction_args = DeleteAuctionArgs {
+        auction_owner_pubkey: auction_owner.keypair.pubkey(),
+        beneficiary_pubkey: auction_owner.keypair.pubkey(),
+        auction_id,
+        current_auction_cycle: 1,
+        num_of_cycles_to_delete: RECOMMENDED_CYCLE_STATES_DELETED_PER_CALL,
+        bid_mint: None,
+        beneficiaries: Vec::new(),
+    };
+    let beneficiary_mismatch_error = testbench
+        .process_transaction(
+            &[delete_auction(&delete_auction_args)],
+            &auction_owner.keypair,
+            None,
+        )
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .err()
+        .unwrap();
+    assert_eq!(
+        beneficiary_mismatch_error,
+        AuctionContractError::AuctionBeneficiaryMismatch
+    );
+
+    // Paying the remaining funds to a beneficiary other than the owner
+    let auction_bank_balance = testbench
+        .get_account_lamports(&auction_bank_pubkey)
+        .await
+        .unwrap();
+    let auction_root_balance = testbench
+        .get_account_lamports(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    let beneficiary_balance_before = testbench
+        .get_account_lamports(&beneficiary.keypair.pubkey())
+        .await
+        .unwrap();
+
+    let owner_balance_change =
+        delete_auction_transaction(&mut testbench, &auction_owner.keypair, auction_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+    let beneficiary_balance_change = testbench
+        .get_account_lamports(&beneficiary.keypair.pubkey())
+        .await
+        .unwrap()
+        - beneficiary_balance_before;
+
+    let fee_state = get_protocol_fee_state(&mut testbench).await;
+    let protocol_fee = protocol_fee_share(auction_bank_balance, fee_state.fee);
+    assert_eq!(
+        beneficiary_balance_change,
+        auction_bank_balance - protocol_fee
+    );
+    // The owner only gets the rent of the root state back
+    assert_eq!(
+        owner_balance_change as u64,
+        auction_root_balance - TRANSACTION_FEE
+    );
+}
+
 async fn does_nth_cycle_state_exist(
     testbench: &mut Testbench,
     auction_root_state_pubkey: &Pubkey,
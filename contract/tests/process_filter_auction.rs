@@ -28,6 +28,16 @@ async fn test_process_filter_auction() {
         encore_period: 30,
         minimum_bid_amount: 50_000_000,
         number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     initialize_new_auction(
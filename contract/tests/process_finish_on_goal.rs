@@ -0,0 +1,151 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Closing cycles of an open-ended auction below its goal
+//   - Finishing the auction when the goal is reached
+//   - Moving the funded auction to the secondary pool
+//
+// Invalid use cases:
+//   - Initializing an auction finishing on its goal without a goal
+//   - Bidding on an auction that finished on its goal
+
+#[tokio::test]
+async fn test_process_finish_on_goal() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: None,
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: true,
+    };
+    let goal = 150_000_000;
+
+    // Invalid use case
+    // Initializing an auction finishing on its goal without a goal
+    let no_goal_error = initialize_new_auction_with_goal(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        None,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(no_goal_error, AuctionContractError::InvalidFundingGoal);
+
+    initialize_new_auction_with_goal(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        Some(goal),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (auction_root_state_pubkey, _) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+    let (auction_pool_pubkey, _) =
+        Pubkey::find_program_address(&auction_pool_seeds(), &CONTRACT_ID);
+    let (secondary_pool_pubkey, _) =
+        Pubkey::find_program_address(&secondary_pool_seeds(), &CONTRACT_ID);
+
+    // Closing the first cycle below the goal
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 100_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(!auction_root_state.status.is_finished);
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+
+    // Reaching the goal in the second cycle
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 100_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state.status.is_finished);
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+    assert_eq!(auction_root_state.all_time_treasury, 200_000_000);
+
+    let auction_pool = testbench
+        .get_and_deserialize_account_data::<AuctionPool>(&auction_pool_pubkey)
+        .await
+        .unwrap();
+    let secondary_pool = testbench
+        .get_and_deserialize_account_data::<AuctionPool>(&secondary_pool_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_pool.pool.is_empty());
+    assert_eq!(secondary_pool.pool[0], auction_id);
+
+    // Invalid use case
+    // Bidding on an auction that finished on its goal
+    let finished_error =
+        place_bid_transaction(&mut testbench, auction_id, &user.keypair, 100_000_000)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(finished_error, AuctionContractError::AuctionEnded);
+}
@@ -7,8 +7,8 @@ use agsol_gold_contract::instruction::factory::TokenType;
 use agsol_gold_contract::pda::*;
 use agsol_gold_contract::state::*;
 use agsol_gold_contract::utils::unpuff_metadata;
-use agsol_gold_contract::AuctionContractError;
 use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_gold_contract::{AuctionContractError, MAX_WINNERS_PER_CYCLE};
 use agsol_testbench::tokio;
 
 use agsol_token_metadata::ID as META_ID;
@@ -31,6 +31,13 @@ const AUCTION_CREATION_COST: u64 = 24_102_480 + TRANSACTION_FEE;
 //   - Creating auction with too long cycle period
 //   - Creating auction with negative encore period
 //   - Creating auction with too long encore period
+//   - Creating auction with a bid mint not owned by the token program
+//   - Creating auction with a reserve price lower than minimum_bid_amount
+//   - Creating auction with a buy-now price lower than minimum_bid_amount
+//   - Creating auction with a Dutch price floor lower than minimum_bid_amount
+//   - Creating sealed-bid auction with a non-positive reveal period
+//   - Creating Dutch auction with second-price settlement
+//   - Creating auction with too many winners per cycle
 //   - Create auction with an id already taken by the same user
 //   - Create auction with an id already taken by another user
 //   - (Test for trying to initialize an auction with a full pool in `process_reallocate_pool.rs`)
@@ -47,6 +54,16 @@ async fn test_process_initialize_auction() {
         encore_period: 30,
         minimum_bid_amount: 50_000_000,
         number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     // Invalid use case
@@ -176,6 +193,167 @@ async fn test_process_initialize_auction() {
 
     auction_config.encore_period = 0;
 
+    // Invalid use case
+    // Creating auction with a bid mint not owned by the token program
+    auction_config.bid_mint = Some(Pubkey::new_unique());
+    let invalid_bid_mint_error = initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        invalid_bid_mint_error,
+        AuctionContractError::InvalidAccountOwner
+    );
+
+    auction_config.bid_mint = None;
+
+    // Invalid use case
+    // Creating auction with a reserve price lower than minimum_bid_amount
+    auction_config.reserve_price = Some(ReservePrice {
+        amount: auction_config.minimum_bid_amount - 1,
+        is_hidden: false,
+    });
+    let invalid_reserve_price_error = initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        invalid_reserve_price_error,
+        AuctionContractError::InvalidReservePrice
+    );
+
+    auction_config.reserve_price = None;
+
+    // Invalid use case
+    // Creating auction with a buy-now price lower than minimum_bid_amount
+    auction_config.buy_now_price = Some(auction_config.minimum_bid_amount - 1);
+    let invalid_buy_now_price_error = initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        invalid_buy_now_price_error,
+        AuctionContractError::InvalidBuyNowPrice
+    );
+
+    auction_config.buy_now_price = None;
+
+    // Invalid use case
+    // Creating auction with a Dutch price floor lower than minimum_bid_amount
+    auction_config.kind = AuctionKind::Dutch(DutchPriceCurve {
+        start_price: auction_config.minimum_bid_amount * 10,
+        floor_price: auction_config.minimum_bid_amount - 1,
+    });
+    let invalid_price_curve_error = initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        invalid_price_curve_error,
+        AuctionContractError::InvalidPriceCurve
+    );
+
+    // Invalid use case
+    // Creating sealed-bid auction with a non-positive reveal period
+    auction_config.kind = AuctionKind::Sealed(SealedBidConfig { reveal_period: 0 });
+    let invalid_reveal_period_error = initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        invalid_reveal_period_error,
+        AuctionContractError::InvalidRevealPeriod
+    );
+
+    // Invalid use case
+    // Creating Dutch auction with second-price settlement
+    auction_config.kind = AuctionKind::Dutch(DutchPriceCurve {
+        start_price: auction_config.minimum_bid_amount * 10,
+        floor_price: auction_config.minimum_bid_amount,
+    });
+    auction_config.second_price_settlement = true;
+    let unsupported_auction_kind_error = initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        unsupported_auction_kind_error,
+        AuctionContractError::UnsupportedAuctionKind
+    );
+
+    auction_config.kind = AuctionKind::English;
+    auction_config.second_price_settlement = false;
+
+    // Invalid use case
+    // Creating auction with too many winners per cycle
+    auction_config.winners_per_cycle = MAX_WINNERS_PER_CYCLE + 1;
+    let invalid_winners_per_cycle_error = initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        invalid_winners_per_cycle_error,
+        AuctionContractError::InvalidWinnersPerCycle
+    );
+
+    auction_config.winners_per_cycle = 1;
+
     // Create a valid auction
     let balance_change = initialize_new_auction(
         &mut testbench,
@@ -0,0 +1,149 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_common::MaxSerializedLen;
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::EXTRA_ROOT_STATE_BYTES;
+use agsol_testbench::tokio;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Migrating an auction holding the current layout leaves it untouched
+//
+// Invalid use cases:
+//   - Migrating the state of a non-existent cycle
+
+#[tokio::test]
+async fn test_process_migrate_auction() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 50_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let auction_root_state_before = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    let auction_cycle_state_before = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    let root_state_lamports_before = testbench
+        .get_account_lamports(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+
+    // Valid use case
+    // Migrating an auction holding the current layout leaves it untouched
+    let payer_balance_before = testbench
+        .get_account_lamports(&user.keypair.pubkey())
+        .await
+        .unwrap();
+    migrate_auction_transaction(&mut testbench, auction_id, &user.keypair, 1, None)
+        .await
+        .unwrap()
+        .unwrap();
+    let payer_balance_after = testbench
+        .get_account_lamports(&user.keypair.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(payer_balance_before - payer_balance_after, TRANSACTION_FEE);
+
+    let root_state_data = testbench
+        .get_account_data(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        root_state_data.len(),
+        AuctionRootState::MAX_SERIALIZED_LEN + EXTRA_ROOT_STATE_BYTES
+    );
+    let cycle_state_data = testbench
+        .get_account_data(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        cycle_state_data.len(),
+        AuctionCycleState::MAX_SERIALIZED_LEN
+    );
+    assert_eq!(
+        testbench
+            .get_account_lamports(&auction_root_state_pubkey)
+            .await
+            .unwrap(),
+        root_state_lamports_before
+    );
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        auction_root_state.unsettled_deposits,
+        auction_root_state_before.unsettled_deposits
+    );
+    assert_eq!(
+        auction_root_state.status.current_auction_cycle,
+        auction_root_state_before.status.current_auction_cycle
+    );
+    assert_eq!(
+        auction_cycle_state.bid_history.len(),
+        auction_cycle_state_before.bid_history.len()
+    );
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, 50_000_000);
+
+    // Invalid use case
+    // Migrating the state of a non-existent cycle
+    let missing_cycle_result =
+        migrate_auction_transaction(&mut testbench, auction_id, &user.keypair, 2, None)
+            .await
+            .unwrap();
+    assert!(missing_cycle_result.is_err());
+}
@@ -9,6 +9,7 @@ use agsol_gold_contract::AuctionContractError;
 use agsol_gold_contract::ID as CONTRACT_ID;
 use agsol_testbench::tokio;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
 
 // This file includes the following tests:
 //
@@ -16,10 +17,16 @@ use solana_program::pubkey::Pubkey;
 //   - Modifying auction description
 //   - Modifying auction socials
 //   - Modifying auction encore period within valid bounds
+//   - Modifying auction minimum bid increment (enforced on bids)
+//   - Removing auction minimum bid increment
 //
 // Invalid use cases:
 //   - Modifying auction without owner signature
 //   - Modifying auction encore period to invalid value
+//   - Modifying auction minimum bid increment to invalid value
+//   - Bidding below the minimum bid increment
+//   - Modifying the minimum bid increment of a second-price auction while
+//     the current cycle has bids
 
 #[tokio::test]
 async fn test_process_modify_auction() {
@@ -31,6 +38,16 @@ async fn test_process_modify_auction() {
         encore_period: 30,
         minimum_bid_amount: 50_000_000,
         number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     initialize_new_auction(
@@ -57,6 +74,9 @@ async fn test_process_modify_auction() {
         ),
         new_socials: None,
         new_encore_period: None,
+        new_min_bid_increment: None,
+        new_beneficiaries: None,
+        new_beneficiary: None,
     };
 
     // Invalid use case
@@ -112,6 +132,9 @@ async fn test_process_modify_auction() {
             .unwrap(),
         ),
         new_encore_period: None,
+        new_min_bid_increment: None,
+        new_beneficiaries: None,
+        new_beneficiary: None,
     };
 
     modify_auction_transaction(
@@ -152,6 +175,9 @@ async fn test_process_modify_auction() {
         new_description: None,
         new_socials: None,
         new_encore_period: Some(20000),
+        new_min_bid_increment: None,
+        new_beneficiaries: None,
+        new_beneficiary: None,
     };
 
     let invalid_new_encore_period_error = modify_auction_transaction(
@@ -175,6 +201,9 @@ async fn test_process_modify_auction() {
         new_description: None,
         new_socials: None,
         new_encore_period: Some(0),
+        new_min_bid_increment: None,
+        new_beneficiaries: None,
+        new_beneficiary: None,
     };
 
     modify_auction_transaction(
@@ -196,4 +225,230 @@ async fn test_process_modify_auction() {
         auction_root_state.auction_config.encore_period,
         modify_data.new_encore_period.unwrap()
     );
+
+    // modify minimum bid increment
+
+    // Invalid use case
+    // Trying to modify the relative bid increment over 100%
+    let modify_data = ModifyAuctionData {
+        new_description: None,
+        new_socials: None,
+        new_encore_period: None,
+        new_min_bid_increment: Some(Some(BidIncrement {
+            amount: 0,
+            basis_points: 10_001,
+        })),
+        new_beneficiaries: None,
+        new_beneficiary: None,
+    };
+
+    let invalid_bid_increment_error = modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        modify_data,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        invalid_bid_increment_error,
+        AuctionContractError::InvalidBidIncrement
+    );
+
+    // now with valid bid increment
+    let modify_data = ModifyAuctionData {
+        new_description: None,
+        new_socials: None,
+        new_encore_period: None,
+        new_min_bid_increment: Some(Some(BidIncrement {
+            amount: 5_000_000,
+            basis_points: 2_000, // 20%
+        })),
+        new_beneficiaries: None,
+        new_beneficiary: None,
+    };
+
+    modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        modify_data,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+
+    let min_bid_increment = auction_root_state.auction_config.min_bid_increment.unwrap();
+    assert_eq!(min_bid_increment.amount, 5_000_000);
+    assert_eq!(min_bid_increment.basis_points, 2_000);
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 50_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Invalid use case
+    // Bidding below the minimum bid increment
+    let invalid_bid_error =
+        place_bid_transaction(&mut testbench, auction_id, &user.keypair, 55_000_000)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+
+    assert_eq!(invalid_bid_error, AuctionContractError::InvalidBidAmount);
+
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 60_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Removing the minimum bid increment
+    let modify_data = ModifyAuctionData {
+        new_description: None,
+        new_socials: None,
+        new_encore_period: None,
+        new_min_bid_increment: Some(None),
+        new_beneficiaries: None,
+        new_beneficiary: None,
+    };
+
+    modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        modify_data,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state
+        .auction_config
+        .min_bid_increment
+        .is_none());
+
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 60_000_001)
+        .await
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_modify_second_price_bid_increment() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: Some(BidIncrement {
+            amount: 5_000_000,
+            basis_points: 0,
+        }),
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: true,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&auction_id), &CONTRACT_ID);
+
+    let modify_data = ModifyAuctionData {
+        new_description: None,
+        new_socials: None,
+        new_encore_period: None,
+        new_min_bid_increment: Some(Some(BidIncrement {
+            amount: 20_000_000,
+            basis_points: 0,
+        })),
+        new_beneficiaries: None,
+        new_beneficiary: None,
+    };
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 50_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Invalid use case
+    // Modifying the minimum bid increment while the current cycle has bids
+    let locked_increment_error = modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        modify_data.clone(),
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        locked_increment_error,
+        AuctionContractError::InvalidBidIncrement
+    );
+
+    // The increment may change once the next cycle starts without bids
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        modify_data,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    let min_bid_increment = auction_root_state.auction_config.min_bid_increment.unwrap();
+    assert_eq!(min_bid_increment.amount, 20_000_000);
 }
@@ -0,0 +1,261 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_testbench::tokio;
+use solana_sdk::signer::Signer;
+use std::convert::TryInto;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Proposing a new owner
+//   - Cancelling the proposal
+//   - Accepting the ownership by the proposed owner
+//   - Paying the claimed funds to the new owner
+//   - Modifying the auction by the new owner
+//
+// Invalid use cases:
+//   - Proposing a new owner by a non-owner
+//   - Accepting the ownership without a proposal
+//   - Accepting the ownership by an account that was not proposed
+//   - Claiming funds to the previous owner
+//   - Modifying the auction by the previous owner
+
+#[tokio::test]
+async fn test_process_owner_transfer() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let new_owner = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (auction_root_state_pubkey, _) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    // Raise some funds to claim
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 50_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Invalid use case
+    // Proposing a new owner by a non-owner
+    let non_owner_error = propose_owner_transfer_transaction(
+        &mut testbench,
+        auction_id,
+        &user.keypair,
+        Some(user.keypair.pubkey()),
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(non_owner_error, AuctionContractError::AuctionOwnerMismatch);
+
+    // Invalid use case
+    // Accepting the ownership without a proposal
+    let no_proposal_error =
+        accept_owner_transfer_transaction(&mut testbench, auction_id, &new_owner.keypair)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(
+        no_proposal_error,
+        AuctionContractError::PendingOwnerMismatch
+    );
+
+    // Proposing a new owner and cancelling the proposal
+    propose_owner_transfer_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        Some(user.keypair.pubkey()),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        auction_root_state.pending_owner,
+        Some(user.keypair.pubkey())
+    );
+
+    propose_owner_transfer_transaction(&mut testbench, auction_id, &auction_owner.keypair, None)
+        .await
+        .unwrap()
+        .unwrap();
+    let cancelled_error =
+        accept_owner_transfer_transaction(&mut testbench, auction_id, &user.keypair)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(cancelled_error, AuctionContractError::PendingOwnerMismatch);
+
+    // Proposing the new owner
+    propose_owner_transfer_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        Some(new_owner.keypair.pubkey()),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Invalid use case
+    // Accepting the ownership by an account that was not proposed
+    let not_proposed_error =
+        accept_owner_transfer_transaction(&mut testbench, auction_id, &user.keypair)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(
+        not_proposed_error,
+        AuctionContractError::PendingOwnerMismatch
+    );
+
+    // Accepting the ownership
+    accept_owner_transfer_transaction(&mut testbench, auction_id, &new_owner.keypair)
+        .await
+        .unwrap()
+        .unwrap();
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.auction_owner, new_owner.keypair.pubkey());
+    assert_eq!(auction_root_state.pending_owner, None);
+    assert_eq!(auction_root_state.beneficiary, new_owner.keypair.pubkey());
+
+    // Invalid use case
+    // Claiming funds to the previous owner and modifying the auction by the
+    // previous owner
+    let old_owner_claim_error = claim_funds_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        10_000_000,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        old_owner_claim_error,
+        AuctionContractError::AuctionBeneficiaryMismatch
+    );
+
+    let modify_data = ModifyAuctionData {
+        new_description: Some("Managed by a new owner".try_into().unwrap()),
+        new_socials: None,
+        new_encore_period: None,
+        new_min_bid_increment: None,
+        new_beneficiaries: None,
+        new_beneficiary: None,
+    };
+    let old_owner_modify_error = modify_auction_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        modify_data.clone(),
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        old_owner_modify_error,
+        AuctionContractError::AuctionOwnerMismatch
+    );
+
+    let old_owner_propose_error = propose_owner_transfer_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        Some(auction_owner.keypair.pubkey()),
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        old_owner_propose_error,
+        AuctionContractError::AuctionOwnerMismatch
+    );
+
+    // Claiming funds to the new owner and modifying the auction by the new
+    // owner
+    let new_owner_balance_change = claim_funds_transaction(
+        &mut testbench,
+        &new_owner.keypair,
+        auction_id,
+        &new_owner.keypair.pubkey(),
+        10_000_000,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(new_owner_balance_change > 0);
+
+    modify_auction_transaction(&mut testbench, auction_id, &new_owner.keypair, modify_data)
+        .await
+        .unwrap()
+        .unwrap();
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        auction_root_state.description.description.contents(),
+        "Managed by a new owner"
+    );
+}
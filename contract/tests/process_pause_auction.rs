@@ -0,0 +1,187 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_testbench::tokio;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Pausing an auction
+//   - Resuming a paused auction, shifting the end of the current cycle
+//   - Bidding and closing the cycle after resuming
+//
+// Invalid use cases:
+//   - Pausing an auction by a non-owner
+//   - Pausing an already paused auction
+//   - Bidding on a paused auction
+//   - Closing the cycle of a paused auction
+//   - Resuming an auction that is not paused
+
+#[tokio::test]
+async fn test_process_pause_auction() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 50_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Invalid use case
+    // Pausing an auction by a non-owner
+    let non_owner_error = pause_auction_transaction(&mut testbench, auction_id, &user.keypair)
+        .await
+        .unwrap()
+        .err()
+        .unwrap();
+    assert_eq!(non_owner_error, AuctionContractError::AuctionOwnerMismatch);
+
+    // Invalid use case
+    // Resuming an auction that is not paused
+    let not_paused_error =
+        resume_auction_transaction(&mut testbench, auction_id, &auction_owner.keypair)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(not_paused_error, AuctionContractError::AuctionNotPaused);
+
+    // Pausing the auction
+    pause_auction_transaction(&mut testbench, auction_id, &auction_owner.keypair)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state.status.paused_at.is_some());
+    assert!(!auction_root_state.status.is_frozen);
+
+    // Invalid use case
+    // Pausing an already paused auction
+    let already_paused_error =
+        pause_auction_transaction(&mut testbench, auction_id, &auction_owner.keypair)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(already_paused_error, AuctionContractError::AuctionPaused);
+
+    // Invalid use case
+    // Bidding on a paused auction
+    let paused_bid_error =
+        place_bid_transaction(&mut testbench, auction_id, &user.keypair, 60_000_000)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(paused_bid_error, AuctionContractError::AuctionPaused);
+
+    // Invalid use case
+    // Closing the cycle of a paused auction
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    let paused_end_time = auction_cycle_state.end_time;
+    let remaining_time = paused_end_time - testbench.block_time().await.unwrap();
+
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    let paused_close_error = close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &user.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(paused_close_error, AuctionContractError::AuctionPaused);
+
+    // Resuming the auction shifts the end of the current cycle
+    resume_auction_transaction(&mut testbench, auction_id, &auction_owner.keypair)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state.status.paused_at.is_none());
+
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    let current_time = testbench.block_time().await.unwrap();
+    assert!(auction_cycle_state.end_time > paused_end_time);
+    assert!(current_time < auction_cycle_state.end_time);
+    assert!(auction_cycle_state.end_time - current_time <= remaining_time);
+
+    // The bids are accepted again after resuming
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, 60_000_000)
+        .await
+        .unwrap()
+        .unwrap();
+
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &user.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(
+        get_current_cycle_number(&mut testbench, &auction_root_state_pubkey)
+            .await
+            .unwrap(),
+        2
+    );
+}
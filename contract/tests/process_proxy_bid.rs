@@ -0,0 +1,357 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_common::MaxSerializedLen;
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::tokio;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Placing a proxy bid (opens at the minimum bid)
+//   - Outbidding a proxy bid below its maximum (proxy bid counters)
+//   - Placing a proxy bid below the maximum of another proxy bid
+//   - Exhausting a proxy bid with a normal bid
+//   - Exhausting a proxy bid with another proxy bid
+//   - Withdrawing the deposit of an outbid proxy bid
+//   - Withdrawing the unused deposit after the cycle is closed
+//
+// Invalid use cases:
+//   - Withdrawing the deposit of a proxy bid backing the top bid
+//   - Placing a proxy bid on an auction with multiple winners per cycle
+
+#[tokio::test]
+async fn test_process_proxy_bid() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: Some(BidIncrement {
+            amount: 10_000_000,
+            basis_points: 0,
+        }),
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_3 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_4 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_5 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+    let bid_deposit_rent = testbench
+        .rent
+        .minimum_balance(BidDeposit::MAX_SERIALIZED_LEN);
+
+    // Placing a proxy bid opens at the minimum bid
+    let max_1 = 300_000_000;
+    let balance_change =
+        place_proxy_bid_transaction(&mut testbench, auction_id, &user_1.keypair, max_1)
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(
+        -balance_change as u64,
+        max_1 + bid_deposit_rent + TRANSACTION_FEE
+    );
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_1.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, 50_000_000);
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        max_1
+    );
+
+    // Outbidding the proxy bid below its maximum (the outbid bid is deposited
+    // nevertheless)
+    let bid_2 = 100_000_000;
+    let balance_change = place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, bid_2)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        -balance_change as u64,
+        bid_2 + bid_deposit_rent + TRANSACTION_FEE
+    );
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_1.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, 110_000_000);
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        max_1 + bid_2
+    );
+
+    // Placing a proxy bid below the maximum of the top proxy bid
+    let max_3 = 200_000_000;
+    place_proxy_bid_transaction(&mut testbench, auction_id, &user_3.keypair, max_3)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_1.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, 210_000_000);
+
+    // The deposit of the outbid proxy bid may be withdrawn right away
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_3.keypair,
+        &user_3.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        max_3 + bid_deposit_rent - TRANSACTION_FEE
+    );
+
+    let (bid_deposit_3_pubkey, _) = Pubkey::find_program_address(
+        &bid_deposit_seeds(&auction_cycle_state_pubkey, &user_3.keypair.pubkey()),
+        &CONTRACT_ID,
+    );
+    assert!(!is_existing_account(&mut testbench, &bid_deposit_3_pubkey)
+        .await
+        .unwrap());
+
+    // Withdrawing the deposit of the proxy bid backing the top bid
+    let withdraw_error = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(withdraw_error, AuctionContractError::ProxyBidActive);
+
+    // Exhausting the proxy bid with a normal bid
+    let bid_2_raised = 400_000_000;
+    let balance_change =
+        place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, bid_2_raised)
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(-balance_change as u64, bid_2_raised + TRANSACTION_FEE);
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_2.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, bid_2_raised);
+
+    // Withdrawing the whole deposit of the exhausted proxy bid
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        max_1 + bid_deposit_rent - TRANSACTION_FEE
+    );
+
+    // Withdrawing the outbid first bid of the top bidder
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        &user_2.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(balance_change as u64, bid_2 - TRANSACTION_FEE);
+
+    // Exhausting a proxy bid with another proxy bid
+    let max_4 = 600_000_000;
+    place_proxy_bid_transaction(&mut testbench, auction_id, &user_4.keypair, max_4)
+        .await
+        .unwrap()
+        .unwrap();
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_4.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, 410_000_000);
+
+    let max_5 = 800_000_000;
+    place_proxy_bid_transaction(&mut testbench, auction_id, &user_5.keypair, max_5)
+        .await
+        .unwrap()
+        .unwrap();
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_5.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, 610_000_000);
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        bid_2_raised + max_4 + max_5
+    );
+
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_4.keypair,
+        &user_4.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        max_4 + bid_deposit_rent - TRANSACTION_FEE
+    );
+
+    // Withdrawing the unused deposit after the cycle is closed
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+    assert_eq!(auction_root_state.available_funds, 610_000_000);
+    assert_eq!(
+        auction_root_state.unsettled_deposits,
+        bid_2_raised + max_5 - 610_000_000
+    );
+
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_5.keypair,
+        &user_5.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        max_5 - 610_000_000 + bid_deposit_rent - TRANSACTION_FEE
+    );
+}
+
+#[tokio::test]
+async fn test_proxy_bid_unsupported() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [2; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 2,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    // Placing a proxy bid on an auction with multiple winners per cycle
+    let proxy_bid_error =
+        place_proxy_bid_transaction(&mut testbench, auction_id, &user.keypair, 100_000_000)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(
+        proxy_bid_error,
+        AuctionContractError::UnsupportedAuctionKind
+    );
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_common::MaxSerializedLen;
+use agsol_gold_contract::instruction::factory::*;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::tokio;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Bidding with a referrer
+//   - Rewarding the referrer of the winning bid upon closing the cycle
+//   - Claiming the accrued referral rewards
+//
+// Invalid use cases:
+//   - Initializing an auction with a referral share above 100%
+//   - Bidders referring themselves
+//   - Claiming referral rewards when there is nothing to claim
+
+#[tokio::test]
+async fn test_process_referral_rewards() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let mut auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 10_001,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    // Invalid use case
+    // Initializing an auction with a referral share above 100%
+    let invalid_share_error = initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        invalid_share_error,
+        AuctionContractError::InvalidReferralShare
+    );
+
+    auction_config.referral_share_bps = 1_000;
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let bidder = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let referrer = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    // Invalid use case
+    // Bidders referring themselves
+    let bid_amount = 50_000_000;
+    let mut place_bid_args = PlaceBidArgs {
+        bidder_pubkey: bidder.keypair.pubkey(),
+        auction_id,
+        cycle_number: 1,
+        amount: bid_amount,
+        expected_top_bid: Some(0),
+        bid_log_page: 0,
+        memo: None,
+        referrer: Some(bidder.keypair.pubkey()),
+        bid_mint: None,
+        bidder_token_account: None,
+        settle_cycle: None,
+    };
+    let self_referral_error = testbench
+        .process_transaction(&[place_bid(&place_bid_args)], &bidder.keypair, None)
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .err()
+        .unwrap();
+    assert_eq!(self_referral_error, AuctionContractError::InvalidReferrer);
+
+    // Bidding with a referrer
+    place_bid_args.referrer = Some(referrer.keypair.pubkey());
+    testbench
+        .process_transaction(&[place_bid(&place_bid_args)], &bidder.keypair, None)
+        .await
+        .unwrap()
+        .map_err(to_auction_error)
+        .unwrap();
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.referrer, Some(referrer.keypair.pubkey()));
+
+    // The referrer of the winning bid is rewarded upon closing the cycle
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let referral_reward = bid_amount / 10;
+    let (referral_state_pubkey, _) = Pubkey::find_program_address(
+        &referral_state_seeds(&referrer.keypair.pubkey()),
+        &CONTRACT_ID,
+    );
+    let referral_state = testbench
+        .get_and_deserialize_account_data::<ReferralState>(&referral_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(referral_state.referrer_pubkey, referrer.keypair.pubkey());
+    assert_eq!(referral_state.accrued_rewards, referral_reward);
+    assert_eq!(referral_state.all_time_rewards, referral_reward);
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        auction_root_state.available_funds,
+        bid_amount - referral_reward
+    );
+    assert_eq!(auction_root_state.all_time_treasury, bid_amount);
+
+    // Claiming the accrued referral rewards
+    let balance_change = claim_referral_rewards_transaction(&mut testbench, &referrer.keypair)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(balance_change, (referral_reward - TRANSACTION_FEE) as i64);
+
+    let referral_state = testbench
+        .get_and_deserialize_account_data::<ReferralState>(&referral_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(referral_state.accrued_rewards, 0);
+    assert_eq!(referral_state.all_time_rewards, referral_reward);
+    // The referral state account stays rent exempt
+    let referral_state_lamports = testbench
+        .get_account_lamports(&referral_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        referral_state_lamports,
+        testbench
+            .rent
+            .minimum_balance(ReferralState::MAX_SERIALIZED_LEN)
+    );
+
+    // Invalid use case
+    // Claiming referral rewards when there is nothing to claim
+    let nothing_to_claim_error =
+        claim_referral_rewards_transaction(&mut testbench, &referrer.keypair)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(
+        nothing_to_claim_error,
+        AuctionContractError::NothingToWithdraw
+    );
+}
@@ -0,0 +1,329 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_testbench::tokio;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Escrowing the proceeds of an all-or-nothing auction
+//   - Refunding the winners of an auction that failed to reach its goal
+//   - Claiming funds once the goal is reached
+//
+// Invalid use cases:
+//   - Initializing an all-or-nothing auction without a goal
+//   - Initializing an all-or-nothing auction without a finite number of cycles
+//   - Claiming escrowed funds
+//   - Refunding the winners of an ongoing auction
+//   - Refunding a bidder who did not win the cycle
+//   - Refunding a winner twice
+//   - Refunding the winners of an auction that reached its goal
+
+#[tokio::test]
+async fn test_process_refund_failed_goal() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let mut auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: None,
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: true,
+        finish_on_goal: false,
+    };
+    let goal = 1_000_000_000;
+
+    // Invalid use case
+    // Initializing an all-or-nothing auction without a finite number of cycles
+    let infinite_error = initialize_new_auction_with_goal(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        Some(goal),
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(infinite_error, AuctionContractError::InvalidFundingGoal);
+
+    // Invalid use case
+    // Initializing an all-or-nothing auction without a goal
+    auction_config.number_of_cycles = Some(2);
+    let no_goal_error = initialize_new_auction_with_goal(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        None,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(no_goal_error, AuctionContractError::InvalidFundingGoal);
+
+    initialize_new_auction_with_goal(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        Some(goal),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    // Escrowing the proceeds of the first cycle
+    let bid_1 = 100_000_000;
+    place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_1)
+        .await
+        .unwrap()
+        .unwrap();
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &user_2.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.escrowed_funds, bid_1);
+    assert_eq!(auction_root_state.available_funds, bid_1);
+
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        auction_cycle_state.settlement_records.contents(),
+        &[SettlementRecord {
+            bidder_pubkey: user_1.keypair.pubkey(),
+            amount: bid_1,
+        }]
+    );
+
+    // Invalid use case
+    // Claiming escrowed funds
+    let escrowed_claim_error = claim_funds_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        10_000_000,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        escrowed_claim_error,
+        AuctionContractError::InvalidClaimAmount
+    );
+
+    // Invalid use case
+    // Refunding the winners of an ongoing auction
+    let ongoing_error = refund_failed_goal_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(ongoing_error, AuctionContractError::FundingGoalNotFailed);
+
+    // Finishing the auction without reaching the goal
+    let bid_2 = 200_000_000;
+    place_bid_transaction(&mut testbench, auction_id, &user_2.keypair, bid_2)
+        .await
+        .unwrap()
+        .unwrap();
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &user_2.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state.status.is_finished);
+    assert!(auction_root_state.is_goal_failed());
+    assert_eq!(auction_root_state.escrowed_funds, bid_1 + bid_2);
+
+    // Invalid use case
+    // Refunding a bidder who did not win the cycle
+    let not_winner_error = refund_failed_goal_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        &user_1.keypair.pubkey(),
+        2,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(not_winner_error, AuctionContractError::NothingToWithdraw);
+
+    // Refunding the winner of the first cycle on their behalf
+    let user_1_balance_before = testbench
+        .get_account_lamports(&user_1.keypair.pubkey())
+        .await
+        .unwrap();
+    refund_failed_goal_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        testbench
+            .get_account_lamports(&user_1.keypair.pubkey())
+            .await
+            .unwrap(),
+        user_1_balance_before + bid_1
+    );
+
+    // Invalid use case
+    // Refunding a winner twice
+    let refunded_error = refund_failed_goal_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(refunded_error, AuctionContractError::NothingToWithdraw);
+
+    // Refunding the winner of the last cycle
+    refund_failed_goal_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        &user_2.keypair.pubkey(),
+        2,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.escrowed_funds, 0);
+
+    // Reaching the goal of another auction
+    let auction_id = [2; 32];
+    initialize_new_auction_with_goal(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        Some(bid_2),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let (auction_root_state_pubkey, _) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_2)
+        .await
+        .unwrap()
+        .unwrap();
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &user_2.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state.is_goal_reached());
+    assert_eq!(auction_root_state.escrowed_funds, 0);
+
+    // Invalid use case
+    // Refunding the winners of an auction that reached its goal
+    let reached_error = refund_failed_goal_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(reached_error, AuctionContractError::FundingGoalNotFailed);
+
+    // Claiming funds once the goal is reached
+    let owner_balance_change = claim_funds_transaction(
+        &mut testbench,
+        &user_2.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        10_000_000,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(owner_balance_change > 0);
+}
@@ -0,0 +1,514 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_common::MaxSerializedLen;
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::tokio;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Committing sealed bids
+//   - Revealing the highest bid (becomes the top bid)
+//   - Revealing a lower bid (deposit is refunded)
+//   - Closing the cycle after the reveal window
+//   - Revealing a stale bid after an idle cycle extension (deposit is refunded)
+//   - Committing again after an idle cycle extension (stale deposit is refunded)
+//   - Revealing a bid committed before the auction was paused after resuming
+//
+// Invalid use cases:
+//   - Placing an open bid on a sealed-bid auction
+//   - Committing twice to the same cycle
+//   - Revealing before the end of the cycle
+//   - Closing the cycle during the reveal window
+//   - Revealing with a wrong salt
+//   - Revealing a bid higher than the deposit (deposit is refunded)
+//   - Revealing a bid while the auction is paused
+//   - Replacing a commitment after the auction is resumed
+
+#[tokio::test]
+async fn test_process_sealed_bid() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let reveal_period = 500;
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::Sealed(SealedBidConfig { reveal_period }),
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_3 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+    let bid_commitment_rent = testbench
+        .rent
+        .minimum_balance(BidCommitment::MAX_SERIALIZED_LEN);
+    let bid_deposit_rent = testbench
+        .rent
+        .minimum_balance(BidDeposit::MAX_SERIALIZED_LEN);
+
+    // Placing an open bid on a sealed-bid auction
+    let open_bid_error =
+        place_bid_transaction(&mut testbench, auction_id, &user_1.keypair, 100_000_000)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(open_bid_error, AuctionContractError::UnsupportedAuctionKind);
+
+    // Commit sealed bids
+    let (bid_1, salt_1, deposit_1) = (200_000_000, [1; 32], 300_000_000);
+    let (bid_2, salt_2, deposit_2) = (150_000_000, [2; 32], 500_000_000);
+    let (bid_3, salt_3, deposit_3) = (400_000_000, [3; 32], 300_000_000);
+
+    let balance_change = commit_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        bid_1,
+        salt_1,
+        deposit_1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        -balance_change as u64,
+        deposit_1 + bid_commitment_rent + TRANSACTION_FEE
+    );
+
+    commit_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        bid_2,
+        salt_2,
+        deposit_2,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    commit_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user_3.keypair,
+        bid_3,
+        salt_3,
+        deposit_3,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Committing twice to the same cycle
+    assert!(commit_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        bid_1,
+        salt_1,
+        deposit_1,
+    )
+    .await
+    .unwrap()
+    .is_err());
+
+    // Revealing before the end of the cycle
+    let early_reveal_error =
+        reveal_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_1, salt_1)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(
+        early_reveal_error,
+        AuctionContractError::AuctionIsInProgress
+    );
+
+    // Closing the cycle during the reveal window
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    let close_cycle_error = close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(close_cycle_error, AuctionContractError::AuctionIsInProgress);
+
+    // Revealing with a wrong salt
+    let wrong_salt_error =
+        reveal_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_1, salt_2)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(
+        wrong_salt_error,
+        AuctionContractError::BidCommitmentMismatch
+    );
+
+    // Revealing the highest bid
+    let balance_change =
+        reveal_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_1, salt_1)
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        deposit_1 - bid_1 + bid_commitment_rent - bid_deposit_rent - TRANSACTION_FEE
+    );
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        bid_1
+    );
+
+    let (bid_commitment_pubkey, _) = Pubkey::find_program_address(
+        &bid_commitment_seeds(&auction_cycle_state_pubkey, &user_1.keypair.pubkey()),
+        &CONTRACT_ID,
+    );
+    assert!(!is_existing_account(&mut testbench, &bid_commitment_pubkey)
+        .await
+        .unwrap());
+
+    // Revealing a lower bid refunds the whole deposit
+    let balance_change =
+        reveal_bid_transaction(&mut testbench, auction_id, &user_2.keypair, bid_2, salt_2)
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        deposit_2 + bid_commitment_rent - TRANSACTION_FEE
+    );
+
+    // Revealing a bid higher than the deposit refunds the whole deposit
+    let balance_change =
+        reveal_bid_transaction(&mut testbench, auction_id, &user_3.keypair, bid_3, salt_3)
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        deposit_3 + bid_commitment_rent - TRANSACTION_FEE
+    );
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_1.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, bid_1);
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        bid_1
+    );
+
+    // Close the cycle after the reveal window
+    testbench.warp_n_seconds(reveal_period).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 2);
+    assert_eq!(auction_root_state.available_funds, bid_1);
+    assert_eq!(auction_root_state.unsettled_deposits, 0);
+}
+
+#[tokio::test]
+async fn test_process_sealed_bid_idle_extension() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let reveal_period = 500;
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::Sealed(SealedBidConfig { reveal_period }),
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+    let bid_commitment_rent = testbench
+        .rent
+        .minimum_balance(BidCommitment::MAX_SERIALIZED_LEN);
+
+    let (bid_1, salt_1, deposit_1) = (200_000_000, [1; 32], 300_000_000);
+    let (bid_2, salt_2, deposit_2) = (150_000_000, [2; 32], 200_000_000);
+
+    for (user, bid, salt, deposit) in [
+        (&user_1, bid_1, salt_1, deposit_1),
+        (&user_2, bid_2, salt_2, deposit_2),
+    ] {
+        commit_bid_transaction(
+            &mut testbench,
+            auction_id,
+            &user.keypair,
+            bid,
+            salt,
+            deposit,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    }
+
+    // Nobody reveals, the cycle is extended as an idle cycle
+    let end_time = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .end_time;
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    testbench.warp_n_seconds(reveal_period).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.status.current_auction_cycle, 1);
+    let extended_end_time = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .end_time;
+    assert_eq!(extended_end_time, end_time + auction_config.cycle_period);
+
+    // Revealing a stale bid refunds the whole deposit
+    let balance_change =
+        reveal_bid_transaction(&mut testbench, auction_id, &user_1.keypair, bid_1, salt_1)
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(
+        balance_change as u64,
+        deposit_1 + bid_commitment_rent - TRANSACTION_FEE
+    );
+    assert!(get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Committing again refunds the stale deposit
+    let (new_bid_2, new_salt_2, new_deposit_2) = (250_000_000, [4; 32], 300_000_000);
+    let balance_change = commit_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        new_bid_2,
+        new_salt_2,
+        new_deposit_2,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        -balance_change as u64,
+        new_deposit_2 - deposit_2 + TRANSACTION_FEE
+    );
+
+    // The new commitment can be revealed after the extended cycle
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    reveal_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        new_bid_2,
+        new_salt_2,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_2.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, new_bid_2);
+}
+
+#[tokio::test]
+async fn test_process_sealed_bid_pause() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::Sealed(SealedBidConfig { reveal_period: 500 }),
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (_, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    let (bid, salt, deposit) = (200_000_000, [1; 32], 300_000_000);
+    commit_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user.keypair,
+        bid,
+        salt,
+        deposit,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    pause_auction_transaction(&mut testbench, auction_id, &auction_owner.keypair)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Revealing a bid while the auction is paused
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    let paused_reveal_error =
+        reveal_bid_transaction(&mut testbench, auction_id, &user.keypair, bid, salt)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(paused_reveal_error, AuctionContractError::AuctionPaused);
+
+    resume_auction_transaction(&mut testbench, auction_id, &auction_owner.keypair)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // The commitment is not stale after resuming, thus it cannot be replaced
+    assert!(commit_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &user.keypair,
+        bid,
+        salt,
+        deposit,
+    )
+    .await
+    .unwrap()
+    .is_err());
+
+    // The bid is revealed after the shifted end of the cycle
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    reveal_bid_transaction(&mut testbench, auction_id, &user.keypair, bid, salt)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, bid);
+}
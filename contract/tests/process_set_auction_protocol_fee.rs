@@ -0,0 +1,226 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::tokio;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Claiming funds from a verified auction with the verified fee tier set
+//   - Overriding the protocol fee of an auction
+//   - Removing the protocol fee override of an auction
+//
+// Invalid use cases:
+//   - Overriding the protocol fee of an auction without admin signature
+//   - Overriding the protocol fee of an auction above 5%
+//   - Setting the verified fee tier above 5%
+
+#[tokio::test]
+async fn test_process_set_auction_protocol_fee() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 100,
+        encore_period: 30,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let payer = testbench.clone_payer();
+
+    let (protocol_fee_state_pubkey, _) =
+        Pubkey::find_program_address(&protocol_fee_state_seeds(), &CONTRACT_ID);
+    let (contract_bank_pubkey, _) =
+        Pubkey::find_program_address(&contract_bank_seeds(), &CONTRACT_ID);
+    let (auction_root_state_pubkey, _) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    // Set up some claimable funds to test on
+    let bid_amount = 100_000_000;
+    place_bid_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        bid_amount,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+
+    close_cycle_transaction(
+        &mut testbench,
+        &payer,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Invalid use case
+    // Setting the verified fee tier above 5%
+    let verified_fee_too_high_error =
+        set_protocol_fee_transaction(&mut testbench, &payer, 500, Some(520))
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+
+    assert_eq!(
+        verified_fee_too_high_error,
+        AuctionContractError::InvalidProtocolFee
+    );
+
+    // Setting the verified fee tier
+    let verified_fee = 250;
+    set_protocol_fee_transaction(&mut testbench, &payer, 500, Some(verified_fee))
+        .await
+        .unwrap()
+        .unwrap();
+
+    let fee_state = get_protocol_fee_state(&mut testbench).await;
+    assert_eq!(fee_state.fee, 500);
+    assert_eq!(fee_state.verified_fee, Some(verified_fee));
+
+    // The auction is not verified yet, thus the global fee applies
+    let claim_amount = 10_000_000;
+    claim_and_assert_split(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        claim_amount,
+        &contract_bank_pubkey,
+        &protocol_fee_state_pubkey,
+        500,
+    )
+    .await;
+
+    // Claiming funds from a verified auction
+    verify_auction_transaction(&mut testbench, auction_id, &payer)
+        .await
+        .unwrap()
+        .unwrap();
+
+    claim_and_assert_split(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        claim_amount,
+        &contract_bank_pubkey,
+        &protocol_fee_state_pubkey,
+        verified_fee,
+    )
+    .await;
+
+    // Invalid use case
+    // Overriding the protocol fee of an auction without admin signature
+    let override_without_admin_signature_error = set_auction_protocol_fee_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        Some(0),
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        override_without_admin_signature_error,
+        AuctionContractError::ContractAdminMismatch
+    );
+
+    // Invalid use case
+    // Overriding the protocol fee of an auction above 5%
+    let override_too_high_error =
+        set_auction_protocol_fee_transaction(&mut testbench, auction_id, &payer, Some(520))
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+
+    assert_eq!(
+        override_too_high_error,
+        AuctionContractError::InvalidProtocolFee
+    );
+
+    // Overriding the protocol fee of the auction
+    let fee_override = 0;
+    set_auction_protocol_fee_transaction(&mut testbench, auction_id, &payer, Some(fee_override))
+        .await
+        .unwrap()
+        .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.protocol_fee_override, Some(fee_override));
+
+    claim_and_assert_split(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        claim_amount,
+        &contract_bank_pubkey,
+        &protocol_fee_state_pubkey,
+        fee_override,
+    )
+    .await;
+
+    // Removing the protocol fee override of the auction
+    set_auction_protocol_fee_transaction(&mut testbench, auction_id, &payer, None)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.protocol_fee_override, None);
+
+    claim_and_assert_split(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        claim_amount,
+        &contract_bank_pubkey,
+        &protocol_fee_state_pubkey,
+        verified_fee,
+    )
+    .await;
+}
@@ -32,6 +32,16 @@ async fn test_process_set_protocol_fee() {
         encore_period: 30,
         minimum_bid_amount: 50_000_000,
         number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     initialize_new_auction(
@@ -86,15 +96,15 @@ async fn test_process_set_protocol_fee() {
         claim_amount,
         &contract_bank_pubkey,
         &protocol_fee_state_pubkey,
-        50,
+        500,
     )
     .await;
 
     // Invalid use case
     // Setting protocol fee without admin signature
-    let new_fee = 52;
+    let new_fee = 520;
     let set_fee_without_admin_signature =
-        set_protocol_fee_transaction(&mut testbench, &auction_owner.keypair, new_fee)
+        set_protocol_fee_transaction(&mut testbench, &auction_owner.keypair, new_fee, None)
             .await
             .unwrap()
             .err()
@@ -107,9 +117,9 @@ async fn test_process_set_protocol_fee() {
 
     // Invalid use case
     // Setting protocol fee to higher than 5%
-    let new_fee = 52;
+    let new_fee = 520;
     let protocol_fee_too_damn_high_error =
-        set_protocol_fee_transaction(&mut testbench, &payer, new_fee)
+        set_protocol_fee_transaction(&mut testbench, &payer, new_fee, None)
             .await
             .unwrap()
             .err()
@@ -121,8 +131,8 @@ async fn test_process_set_protocol_fee() {
     );
 
     // Creating protocol fee account by setting it to the default value
-    let new_fee = 50;
-    set_protocol_fee_transaction(&mut testbench, &payer, new_fee)
+    let new_fee = 500;
+    set_protocol_fee_transaction(&mut testbench, &payer, new_fee, None)
         .await
         .unwrap()
         .unwrap();
@@ -140,8 +150,8 @@ async fn test_process_set_protocol_fee() {
     .await;
 
     // Setting protocol fee to another value
-    let new_fee = 10;
-    set_protocol_fee_transaction(&mut testbench, &payer, new_fee)
+    let new_fee = 100;
+    set_protocol_fee_transaction(&mut testbench, &payer, new_fee, None)
         .await
         .unwrap()
         .unwrap();
@@ -27,7 +27,6 @@ use agsol_testbench::{tokio, TestbenchError};
 // Invalid use cases:
 //   - Creating token auction with 0 per_cycle_amount
 
-const CLOSE_CYCLE_COST: u64 = 3_758_400;
 const CLAIM_REWARDS_COST_TOKEN: u64 = 2_039_280;
 
 #[tokio::test]
@@ -46,6 +45,16 @@ async fn test_process_tokens() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1000),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     // Invalid use case
@@ -192,6 +201,16 @@ async fn test_process_tokens_existing_mint() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1000),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let token_mint_pubkey = testbench
@@ -309,6 +328,16 @@ async fn test_process_claim_rewards_token_non_chronological() {
         encore_period: 1,
         minimum_bid_amount: 50_000_000, // lamports
         number_of_cycles: Some(1000),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
     };
 
     let (auction_root_state_pubkey, _) =
@@ -354,7 +383,10 @@ async fn test_process_claim_rewards_token_non_chronological() {
     .unwrap()
     .unwrap();
 
-    assert_eq!(-balance_change as u64, CLOSE_CYCLE_COST + TRANSACTION_FEE,);
+    assert_eq!(
+        -balance_change as u64,
+        close_cycle_cost(&testbench) + TRANSACTION_FEE
+    );
 
     // Place bid on second cycle
     let bid_amount = 50_000_000;
@@ -377,7 +409,10 @@ async fn test_process_claim_rewards_token_non_chronological() {
     .unwrap()
     .unwrap();
 
-    assert_eq!(-balance_change as u64, CLOSE_CYCLE_COST + TRANSACTION_FEE,);
+    assert_eq!(
+        -balance_change as u64,
+        close_cycle_cost(&testbench) + TRANSACTION_FEE
+    );
 
     // Check that no tokens have been claimed yet
     let token_data = get_token_data(&mut testbench, &auction_root_state_pubkey)
@@ -0,0 +1,177 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_gold_contract::instruction::factory::TokenType;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_testbench::tokio;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Claiming the funds vested after the cliff
+//   - Claiming the remaining funds after the vesting period
+//
+// Invalid use cases:
+//   - Initializing an auction with a cliff longer than the vesting period
+//   - Claiming funds before the cliff
+//   - Claiming more funds than vested
+
+#[tokio::test]
+async fn test_process_vesting() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: None,
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    // Invalid use case
+    // Initializing an auction with a cliff longer than the vesting period
+    let invalid_schedule_error = initialize_new_auction_with_vesting(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        Some(VestingSchedule {
+            cliff_period: 5000,
+            vesting_period: 4000,
+        }),
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(
+        invalid_schedule_error,
+        AuctionContractError::InvalidVestingSchedule
+    );
+
+    let vesting_schedule = VestingSchedule {
+        cliff_period: 1500,
+        vesting_period: 4000,
+    };
+    initialize_new_auction_with_vesting(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        Some(vesting_schedule),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let (auction_root_state_pubkey, _) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.vesting_schedule, Some(vesting_schedule));
+
+    // Raise some funds to claim
+    let bid_amount = 1_000_000_000;
+    place_bid_transaction(&mut testbench, auction_id, &user.keypair, bid_amount)
+        .await
+        .unwrap()
+        .unwrap();
+    warp_to_cycle_end(&mut testbench, auction_id).await.unwrap();
+    close_cycle_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    // Invalid use case
+    // Claiming funds before the cliff
+    let cliff_error = claim_funds_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        10_000_000,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(cliff_error, AuctionContractError::InvalidClaimAmount);
+
+    // Claiming the funds vested after the cliff (about half of the funds)
+    testbench.warp_n_seconds(1000).await.unwrap();
+    let first_claim = 400_000_000;
+    claim_funds_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        first_claim,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.claimed_funds, first_claim);
+
+    // Invalid use case
+    // Claiming more funds than vested
+    let not_vested_error = claim_funds_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        200_000_000,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(not_vested_error, AuctionContractError::InvalidClaimAmount);
+
+    // Claiming the remaining funds after the vesting period
+    testbench.warp_n_seconds(2000).await.unwrap();
+    let second_claim = bid_amount - first_claim - 10_000_000;
+    claim_funds_transaction(
+        &mut testbench,
+        &auction_owner.keypair,
+        auction_id,
+        &auction_owner.keypair.pubkey(),
+        second_claim,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.claimed_funds, first_claim + second_claim);
+}
@@ -0,0 +1,245 @@
+#![cfg(feature = "test-bpf")]
+mod test_factory;
+use test_factory::*;
+
+use agsol_common::MaxSerializedLen;
+use agsol_gold_contract::instruction::factory::*;
+use agsol_gold_contract::pda::*;
+use agsol_gold_contract::state::*;
+use agsol_gold_contract::AuctionContractError;
+use agsol_gold_contract::ID as CONTRACT_ID;
+use agsol_testbench::tokio;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+// This file includes the following tests:
+//
+// Valid use cases:
+//   - Placing bids built from the same cycle state (no top bidder account needed)
+//   - Withdrawing an outbid bid on behalf of the bidder
+//   - Withdrawing the bids of a frozen auction to finish deleting it
+//   - (Test for withdrawing the surplus of a second-price settled bid in
+//     `process_close_auction_cycle.rs`)
+//   - (Test for withdrawing proxy bid deposits in `process_proxy_bid.rs`)
+//
+// Invalid use cases:
+//   - Withdrawing the deposit backing the top bid
+//   - Withdrawing an already withdrawn deposit
+//   - Deleting a frozen auction before its deposits are withdrawn
+
+#[tokio::test]
+async fn test_process_withdraw_refund() {
+    let (mut testbench, auction_owner) = test_factory::testbench_setup().await.unwrap().unwrap();
+
+    let auction_id = [1; 32];
+    let auction_config = AuctionConfig {
+        cycle_period: 1000,
+        encore_period: 200,
+        minimum_bid_amount: 50_000_000,
+        number_of_cycles: Some(10),
+        bid_mint: None,
+        reserve_price: None,
+        min_bid_increment: None,
+        buy_now_price: None,
+        kind: AuctionKind::English,
+        second_price_settlement: false,
+        winners_per_cycle: 1,
+        referral_share_bps: 0,
+        all_or_nothing: false,
+        finish_on_goal: false,
+    };
+
+    initialize_new_auction(
+        &mut testbench,
+        &auction_owner.keypair,
+        &auction_config,
+        auction_id,
+        TokenType::Nft,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let user_1 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_2 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+    let user_3 = TestUser::new(&mut testbench).await.unwrap().unwrap();
+
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(&mut testbench, auction_id).await.unwrap();
+    let bid_deposit_rent = testbench
+        .rent
+        .minimum_balance(BidDeposit::MAX_SERIALIZED_LEN);
+
+    // Both bids are built before either of them is processed
+    let (bid_1, bid_2) = (100_000_000, 200_000_000);
+    let bid_instructions: Vec<_> = [(&user_1, bid_1), (&user_2, bid_2)]
+        .iter()
+        .map(|(user, amount)| {
+            let place_bid_args = PlaceBidArgs {
+                bidder_pubkey: user.keypair.pubkey(),
+                auction_id,
+                cycle_number: 1,
+                amount: *amount,
+                expected_top_bid: None,
+                bid_log_page: 0,
+                memo: None,
+                referrer: None,
+                bid_mint: None,
+                bidder_token_account: None,
+                settle_cycle: None,
+            };
+            (place_bid(&place_bid_args), *user)
+        })
+        .collect();
+
+    for (instruction, user) in bid_instructions {
+        testbench
+            .process_transaction(&[instruction], &user.keypair, None)
+            .await
+            .unwrap()
+            .map_err(to_auction_error)
+            .unwrap();
+    }
+
+    let top_bid = get_top_bid(&mut testbench, &auction_cycle_state_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(top_bid.bidder_pubkey, user_2.keypair.pubkey());
+    assert_eq!(top_bid.bid_amount, bid_2);
+    assert_eq!(
+        get_bank_balance_without_rent(&mut testbench, auction_id)
+            .await
+            .unwrap(),
+        bid_1 + bid_2
+    );
+
+    // Withdrawing the deposit backing the top bid
+    let withdraw_error = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_2.keypair,
+        &user_2.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .err()
+    .unwrap();
+    assert_eq!(withdraw_error, AuctionContractError::NothingToWithdraw);
+
+    // Withdrawing the outbid bid on behalf of the bidder
+    let user_1_balance_before = testbench
+        .get_account_lamports(&user_1.keypair.pubkey())
+        .await
+        .unwrap();
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_3.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(-balance_change as u64, TRANSACTION_FEE);
+
+    let user_1_balance_after = testbench
+        .get_account_lamports(&user_1.keypair.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        user_1_balance_after - user_1_balance_before,
+        bid_1 + bid_deposit_rent
+    );
+
+    let (bid_deposit_1_pubkey, _) = Pubkey::find_program_address(
+        &bid_deposit_seeds(&auction_cycle_state_pubkey, &user_1.keypair.pubkey()),
+        &CONTRACT_ID,
+    );
+    assert!(!is_existing_account(&mut testbench, &bid_deposit_1_pubkey)
+        .await
+        .unwrap());
+
+    // Withdrawing an already withdrawn deposit
+    assert!(withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &user_1.keypair,
+        &user_1.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .is_err());
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.unsettled_deposits, bid_2);
+
+    // Deleting the auction freezes it, but waits for the top bid to be
+    // withdrawn
+    delete_auction_transaction(&mut testbench, &auction_owner.keypair, auction_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert!(auction_root_state.status.is_frozen);
+    assert!(
+        is_existing_account(&mut testbench, &auction_cycle_state_pubkey)
+            .await
+            .unwrap()
+    );
+
+    // Deleting the frozen auction fails until the top bid is withdrawn
+    let delete_error =
+        delete_auction_transaction(&mut testbench, &auction_owner.keypair, auction_id)
+            .await
+            .unwrap()
+            .err()
+            .unwrap();
+    assert_eq!(delete_error, AuctionContractError::UnwithdrawnDeposits);
+
+    // The top bid of a frozen auction can be withdrawn
+    let balance_change = withdraw_refund_transaction(
+        &mut testbench,
+        auction_id,
+        &auction_owner.keypair,
+        &user_2.keypair.pubkey(),
+        1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(-balance_change as u64, TRANSACTION_FEE);
+
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(auction_root_state.unsettled_deposits, 0);
+
+    // Finish deleting the auction
+    delete_auction_transaction(&mut testbench, &auction_owner.keypair, auction_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(
+        !is_existing_account(&mut testbench, &auction_root_state_pubkey)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !is_existing_account(&mut testbench, &auction_cycle_state_pubkey)
+            .await
+            .unwrap()
+    );
+}
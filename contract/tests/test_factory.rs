@@ -21,9 +21,9 @@ use agsol_gold_contract::state::*;
 use agsol_gold_contract::utils::unpuff_metadata;
 use agsol_gold_contract::AuctionContractError;
 use agsol_gold_contract::ID as CONTRACT_ID;
-use agsol_gold_contract::{DEFAULT_PROTOCOL_FEE, RECOMMENDED_CYCLE_STATES_DELETED_PER_CALL};
+use agsol_gold_contract::RECOMMENDED_CYCLE_STATES_DELETED_PER_CALL;
 
-use agsol_common::MaxLenString;
+use agsol_common::{MaxLenString, MaxSerializedLen};
 use agsol_testbench::solana_program_test::{self, processor};
 use agsol_testbench::{
     Testbench, TestbenchError, TestbenchProgram, TestbenchResult, TestbenchTransactionResult,
@@ -144,6 +144,31 @@ pub async fn get_token_data(
     }
 }
 
+pub async fn get_bid_mint(
+    testbench: &mut Testbench,
+    auction_root_state_pubkey: &Pubkey,
+) -> TestbenchResultOption<Pubkey> {
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(auction_root_state_pubkey)
+        .await?;
+    Ok(auction_root_state.auction_config.bid_mint)
+}
+
+pub async fn get_beneficiaries(
+    testbench: &mut Testbench,
+    auction_root_state_pubkey: &Pubkey,
+) -> TestbenchResult<Vec<Pubkey>> {
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(auction_root_state_pubkey)
+        .await?;
+    Ok(auction_root_state
+        .beneficiaries
+        .contents()
+        .iter()
+        .map(|beneficiary| beneficiary.pubkey)
+        .collect())
+}
+
 pub async fn get_current_cycle_number(
     testbench: &mut Testbench,
     auction_root_state_pubkey: &Pubkey,
@@ -188,6 +213,13 @@ pub async fn get_bank_balance_without_rent(
     Ok(auction_bank_lamports)
 }
 
+/// Rent paid for the cycle state account created when a cycle is closed.
+pub fn close_cycle_cost(testbench: &Testbench) -> u64 {
+    testbench
+        .rent
+        .minimum_balance(AuctionCycleState::MAX_SERIALIZED_LEN)
+}
+
 pub async fn get_top_bidder_pubkey(
     testbench: &mut Testbench,
     auction_cycle_state_pubkey: &Pubkey,
@@ -240,6 +272,13 @@ pub async fn close_cycle_transaction(
         TokenType::Nft => None,
     };
 
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await?;
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await?;
+
     let close_auction_cycle_args = CloseAuctionCycleArgs {
         payer_pubkey: payer_keypair.pubkey(),
         auction_owner_pubkey: *auction_owner_pubkey,
@@ -248,6 +287,7 @@ pub async fn close_cycle_transaction(
         next_cycle_num,
         token_type,
         existing_token_mint,
+        referrers: auction_cycle_state.rewarded_referrers(&auction_root_state.auction_config),
     };
 
     let close_auction_cycle_ix = close_auction_cycle(&close_auction_cycle_args);
@@ -263,18 +303,22 @@ pub async fn delete_auction_transaction(
     auction_owner_keypair: &Keypair,
     auction_id: [u8; 32],
 ) -> AuctionTransactionResult {
-    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
-        get_state_pubkeys(testbench, auction_id).await?;
+    let (auction_root_state_pubkey, _) = get_state_pubkeys(testbench, auction_id).await?;
 
     let current_auction_cycle =
         get_current_cycle_number(testbench, &auction_root_state_pubkey).await?;
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await?;
 
     let delete_auction_args = DeleteAuctionArgs {
         auction_owner_pubkey: auction_owner_keypair.pubkey(),
-        top_bidder_pubkey: get_top_bidder_pubkey(testbench, &auction_cycle_state_pubkey).await?,
+        beneficiary_pubkey: auction_root_state.beneficiary,
         auction_id,
         current_auction_cycle,
         num_of_cycles_to_delete: RECOMMENDED_CYCLE_STATES_DELETED_PER_CALL,
+        bid_mint: get_bid_mint(testbench, &auction_root_state_pubkey).await?,
+        beneficiaries: get_beneficiaries(testbench, &auction_root_state_pubkey).await?,
     };
     let delete_auction_ix = delete_auction(&delete_auction_args);
 
@@ -317,11 +361,13 @@ pub async fn verify_auction_transaction(
 pub async fn set_protocol_fee_transaction(
     testbench: &mut Testbench,
     contract_admin_keypair: &Keypair,
-    new_fee: u8,
+    new_fee: u16,
+    new_verified_fee: Option<u16>,
 ) -> AuctionTransactionResult {
     let set_fee_args = SetProtocolFeeArgs {
         contract_admin_pubkey: contract_admin_keypair.pubkey(),
         new_fee,
+        new_verified_fee,
     };
     let set_fee_ix = set_protocol_fee(&set_fee_args);
 
@@ -331,15 +377,37 @@ pub async fn set_protocol_fee_transaction(
         .map(|transaction_result| transaction_result.map_err(to_auction_error))
 }
 
+pub async fn set_auction_protocol_fee_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    contract_admin_keypair: &Keypair,
+    new_fee: Option<u16>,
+) -> AuctionTransactionResult {
+    let set_fee_args = SetAuctionProtocolFeeArgs {
+        contract_admin_pubkey: contract_admin_keypair.pubkey(),
+        auction_id,
+        new_fee,
+    };
+    let set_fee_ix = set_auction_protocol_fee(&set_fee_args);
+
+    testbench
+        .process_transaction(&[set_fee_ix], contract_admin_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
 pub async fn modify_auction_transaction(
     testbench: &mut Testbench,
     auction_id: [u8; 32],
     auction_owner_keypair: &Keypair,
     modify_data: ModifyAuctionData,
 ) -> AuctionTransactionResult {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&auction_id), &CONTRACT_ID);
     let modify_args = ModifyAuctionArgs {
         auction_owner_pubkey: auction_owner_keypair.pubkey(),
         auction_id,
+        cycle_number: get_current_cycle_number(testbench, &auction_root_state_pubkey).await?,
         modify_data,
     };
     let modify_instruction = modify_auction(&modify_args);
@@ -350,17 +418,140 @@ pub async fn modify_auction_transaction(
         .map(|transaction_result| transaction_result.map_err(to_auction_error))
 }
 
-pub async fn get_protocol_fee_multiplier(testbench: &mut Testbench) -> f64 {
+pub async fn pause_auction_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    auction_owner_keypair: &Keypair,
+) -> AuctionTransactionResult {
+    let pause_args = PauseAuctionArgs {
+        auction_owner_pubkey: auction_owner_keypair.pubkey(),
+        auction_id,
+    };
+    let pause_instruction = pause_auction(&pause_args);
+
+    testbench
+        .process_transaction(&[pause_instruction], auction_owner_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn resume_auction_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    auction_owner_keypair: &Keypair,
+) -> AuctionTransactionResult {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&auction_id), &CONTRACT_ID);
+    let resume_args = ResumeAuctionArgs {
+        auction_owner_pubkey: auction_owner_keypair.pubkey(),
+        auction_id,
+        cycle_number: get_current_cycle_number(testbench, &auction_root_state_pubkey).await?,
+    };
+    let resume_instruction = resume_auction(&resume_args);
+
+    testbench
+        .process_transaction(&[resume_instruction], auction_owner_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn migrate_auction_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    payer_keypair: &Keypair,
+    cycle_number: u64,
+    top_bidder_pubkey: Option<Pubkey>,
+) -> AuctionTransactionResult {
+    let migrate_args = MigrateAuctionArgs {
+        payer_pubkey: payer_keypair.pubkey(),
+        auction_id,
+        cycle_number,
+        top_bidder_pubkey,
+    };
+    let migrate_instruction = migrate_auction(&migrate_args);
+
+    testbench
+        .process_transaction(&[migrate_instruction], payer_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn propose_owner_transfer_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    auction_owner_keypair: &Keypair,
+    new_owner_pubkey: Option<Pubkey>,
+) -> AuctionTransactionResult {
+    let propose_args = ProposeOwnerTransferArgs {
+        auction_owner_pubkey: auction_owner_keypair.pubkey(),
+        auction_id,
+        new_owner_pubkey,
+    };
+    let propose_instruction = propose_owner_transfer(&propose_args);
+
+    testbench
+        .process_transaction(&[propose_instruction], auction_owner_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn accept_owner_transfer_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    new_owner_keypair: &Keypair,
+) -> AuctionTransactionResult {
+    let accept_args = AcceptOwnerTransferArgs {
+        new_owner_pubkey: new_owner_keypair.pubkey(),
+        auction_id,
+    };
+    let accept_instruction = accept_owner_transfer(&accept_args);
+
+    testbench
+        .process_transaction(&[accept_instruction], new_owner_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn propose_contract_admin_transaction(
+    testbench: &mut Testbench,
+    contract_admin_keypair: &Keypair,
+    new_contract_admin_pubkey: Option<Pubkey>,
+) -> AuctionTransactionResult {
+    let propose_args = ProposeContractAdminArgs {
+        contract_admin_pubkey: contract_admin_keypair.pubkey(),
+        new_contract_admin_pubkey,
+    };
+    let propose_instruction = propose_contract_admin(&propose_args);
+
+    testbench
+        .process_transaction(&[propose_instruction], contract_admin_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn accept_contract_admin_transaction(
+    testbench: &mut Testbench,
+    new_contract_admin_keypair: &Keypair,
+) -> AuctionTransactionResult {
+    let accept_args = AcceptContractAdminArgs {
+        new_contract_admin_pubkey: new_contract_admin_keypair.pubkey(),
+    };
+    let accept_instruction = accept_contract_admin(&accept_args);
+
+    testbench
+        .process_transaction(&[accept_instruction], new_contract_admin_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn get_protocol_fee_state(testbench: &mut Testbench) -> ProtocolFeeState {
     let (protocol_fee_state_pubkey, _) =
         Pubkey::find_program_address(&protocol_fee_state_seeds(), &CONTRACT_ID);
 
-    let fee_state = testbench
+    testbench
         .get_and_deserialize_account_data::<ProtocolFeeState>(&protocol_fee_state_pubkey)
         .await
-        .unwrap_or(ProtocolFeeState {
-            fee: DEFAULT_PROTOCOL_FEE,
-        });
-    fee_state.fee as f64 / 1_000.0
+        .unwrap_or_default()
 }
 
 pub async fn claim_and_assert_split(
@@ -370,7 +561,7 @@ pub async fn claim_and_assert_split(
     claim_amount: u64,
     contract_bank_pubkey: &Pubkey,
     protocol_fee_state_pubkey: &Pubkey,
-    expected_split: u8,
+    expected_split: u16,
 ) {
     let contract_balance_before = testbench
         .get_account_lamports(contract_bank_pubkey)
@@ -395,21 +586,21 @@ pub async fn claim_and_assert_split(
     let fee_state = testbench
         .get_and_deserialize_account_data::<ProtocolFeeState>(protocol_fee_state_pubkey)
         .await
-        .unwrap_or(ProtocolFeeState {
-            fee: DEFAULT_PROTOCOL_FEE,
-        });
+        .unwrap_or_default();
+    let (auction_root_state_pubkey, _) = get_state_pubkeys(testbench, auction_id).await.unwrap();
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await
+        .unwrap();
+    let applied_fee = fee_state.effective_fee(&auction_root_state);
 
-    assert_eq!(expected_split, fee_state.fee);
+    assert_eq!(expected_split, applied_fee);
 
-    let fee_float = fee_state.fee as f64 / 1_000.0;
-    let protocol_fee = claim_amount as f64 * fee_float;
+    let protocol_fee = protocol_fee_share(claim_amount, applied_fee);
 
+    assert_eq!(claim_amount - protocol_fee, owner_balance_change as u64);
     assert_eq!(
-        claim_amount - protocol_fee as u64,
-        owner_balance_change as u64
-    );
-    assert_eq!(
-        protocol_fee as u64,
+        protocol_fee,
         contract_balance_after - contract_balance_before
     );
 }
@@ -418,7 +609,7 @@ pub async fn claim_funds_transaction(
     testbench: &mut Testbench,
     payer_keypair: &Keypair,
     auction_id: [u8; 32],
-    auction_owner_pubkey: &Pubkey,
+    auction_beneficiary_pubkey: &Pubkey,
     amount: u64,
 ) -> AuctionTransactionResult {
     let (auction_root_state_pubkey, _) =
@@ -426,26 +617,33 @@ pub async fn claim_funds_transaction(
 
     let claim_funds_args = ClaimFundsArgs {
         payer_pubkey: payer_keypair.pubkey(),
-        auction_owner_pubkey: *auction_owner_pubkey,
+        beneficiary_pubkey: *auction_beneficiary_pubkey,
         auction_id,
         cycle_number: get_current_cycle_number(testbench, &auction_root_state_pubkey).await?,
         amount,
+        bid_mint: get_bid_mint(testbench, &auction_root_state_pubkey).await?,
+        beneficiaries: get_beneficiaries(testbench, &auction_root_state_pubkey).await?,
     };
 
     let claim_funds_ix = claim_funds(&claim_funds_args);
 
-    let owner_balance_before = testbench.get_account_lamports(auction_owner_pubkey).await?;
+    let beneficiary_balance_before = testbench
+        .get_account_lamports(auction_beneficiary_pubkey)
+        .await?;
 
     let testbench_result = testbench
         .process_transaction(&[claim_funds_ix], payer_keypair, None)
         .await
         .map(|transaction_result| transaction_result.map_err(to_auction_error));
 
-    let owner_balance_after = testbench.get_account_lamports(auction_owner_pubkey).await?;
-    let owner_balance_change = owner_balance_after as i64 - owner_balance_before as i64;
+    let beneficiary_balance_after = testbench
+        .get_account_lamports(auction_beneficiary_pubkey)
+        .await?;
+    let beneficiary_balance_change =
+        beneficiary_balance_after as i64 - beneficiary_balance_before as i64;
 
     testbench_result.map(|transaction_result| {
-        transaction_result.map(|_signer_balance_change| owner_balance_change)
+        transaction_result.map(|_signer_balance_change| beneficiary_balance_change)
     })
 }
 
@@ -456,9 +654,33 @@ pub async fn claim_rewards_transaction(
     top_bidder_pubkey: &Pubkey,
     cycle_number: u64,
     token_type: TokenType,
+) -> AuctionTransactionResult {
+    claim_ranked_rewards_transaction(
+        testbench,
+        payer_keypair,
+        auction_id,
+        top_bidder_pubkey,
+        cycle_number,
+        0,
+        token_type,
+    )
+    .await
+}
+
+pub async fn claim_ranked_rewards_transaction(
+    testbench: &mut Testbench,
+    payer_keypair: &Keypair,
+    auction_id: [u8; 32],
+    winner_pubkey: &Pubkey,
+    cycle_number: u64,
+    rank: u8,
+    token_type: TokenType,
 ) -> AuctionTransactionResult {
     let (auction_root_state_pubkey, _) =
         Pubkey::find_program_address(&auction_root_state_seeds(&auction_id), &CONTRACT_ID);
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await?;
 
     let existing_token_mint = match token_type {
         TokenType::Token => {
@@ -472,9 +694,11 @@ pub async fn claim_rewards_transaction(
 
     let claim_rewards_args = ClaimRewardsArgs {
         payer_pubkey: payer_keypair.pubkey(),
-        top_bidder_pubkey: *top_bidder_pubkey,
+        top_bidder_pubkey: *winner_pubkey,
         auction_id,
         cycle_number,
+        rank,
+        winners_per_cycle: auction_root_state.auction_config.winners_per_cycle,
         token_type,
         existing_token_mint,
     };
@@ -509,12 +733,38 @@ pub async fn place_bid_transaction(
     let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
         get_state_pubkeys(testbench, auction_id).await?;
 
+    let auction_root_state = testbench
+        .get_and_deserialize_account_data::<AuctionRootState>(&auction_root_state_pubkey)
+        .await?;
+    let settle_cycle = if auction_root_state.auction_config.settles_on_bid(amount) {
+        match auction_root_state.token_config {
+            TokenConfig::Nft(_) => Some(TokenType::Nft),
+            TokenConfig::Token(_) => Some(TokenType::Token),
+        }
+    } else {
+        None
+    };
+
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await?;
+    let expected_top_bid = auction_cycle_state
+        .bid_history
+        .get_last_element()
+        .map_or(0, |top_bid| top_bid.bid_amount);
+
     let place_bid_args = PlaceBidArgs {
         bidder_pubkey: user_keypair.pubkey(),
         auction_id,
         cycle_number: get_current_cycle_number(testbench, &auction_root_state_pubkey).await?,
-        top_bidder_pubkey: get_top_bidder_pubkey(testbench, &auction_cycle_state_pubkey).await?,
         amount,
+        expected_top_bid: Some(expected_top_bid),
+        bid_log_page: auction_cycle_state.bid_log_page(),
+        memo: None,
+        referrer: None,
+        bid_mint: get_bid_mint(testbench, &auction_root_state_pubkey).await?,
+        bidder_token_account: None,
+        settle_cycle,
     };
     let bid_instruction = place_bid(&place_bid_args);
 
@@ -524,6 +774,157 @@ pub async fn place_bid_transaction(
         .map(|transaction_result| transaction_result.map_err(to_auction_error))
 }
 
+pub async fn commit_bid_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    user_keypair: &Keypair,
+    amount: u64,
+    salt: [u8; 32],
+    deposit: u64,
+) -> AuctionTransactionResult {
+    let (auction_root_state_pubkey, _) = get_state_pubkeys(testbench, auction_id).await?;
+
+    let commit_bid_args = CommitBidArgs {
+        bidder_pubkey: user_keypair.pubkey(),
+        auction_id,
+        cycle_number: get_current_cycle_number(testbench, &auction_root_state_pubkey).await?,
+        amount,
+        salt,
+        deposit,
+    };
+    let commit_bid_instruction = commit_bid(&commit_bid_args);
+
+    testbench
+        .process_transaction(&[commit_bid_instruction], user_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn reveal_bid_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    user_keypair: &Keypair,
+    amount: u64,
+    salt: [u8; 32],
+) -> AuctionTransactionResult {
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(testbench, auction_id).await?;
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await?;
+
+    let reveal_bid_args = RevealBidArgs {
+        bidder_pubkey: user_keypair.pubkey(),
+        auction_id,
+        cycle_number: get_current_cycle_number(testbench, &auction_root_state_pubkey).await?,
+        amount,
+        salt,
+        bid_log_page: auction_cycle_state.bid_log_page(),
+    };
+    let reveal_bid_instruction = reveal_bid(&reveal_bid_args);
+
+    testbench
+        .process_transaction(&[reveal_bid_instruction], user_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn place_proxy_bid_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    user_keypair: &Keypair,
+    max_amount: u64,
+) -> AuctionTransactionResult {
+    let (auction_root_state_pubkey, auction_cycle_state_pubkey) =
+        get_state_pubkeys(testbench, auction_id).await?;
+    let auction_cycle_state = testbench
+        .get_and_deserialize_account_data::<AuctionCycleState>(&auction_cycle_state_pubkey)
+        .await?;
+
+    let place_proxy_bid_args = PlaceProxyBidArgs {
+        bidder_pubkey: user_keypair.pubkey(),
+        auction_id,
+        cycle_number: get_current_cycle_number(testbench, &auction_root_state_pubkey).await?,
+        max_amount,
+        bid_log_page: auction_cycle_state.bid_log_page(),
+    };
+    let place_proxy_bid_instruction = place_proxy_bid(&place_proxy_bid_args);
+
+    testbench
+        .process_transaction(&[place_proxy_bid_instruction], user_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn withdraw_refund_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    payer_keypair: &Keypair,
+    bidder_pubkey: &Pubkey,
+    cycle_number: u64,
+) -> AuctionTransactionResult {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&auction_id), &CONTRACT_ID);
+
+    let withdraw_refund_args = WithdrawRefundArgs {
+        payer_pubkey: payer_keypair.pubkey(),
+        bidder_pubkey: *bidder_pubkey,
+        auction_id,
+        cycle_number,
+        bid_mint: get_bid_mint(testbench, &auction_root_state_pubkey).await?,
+    };
+    let withdraw_refund_instruction = withdraw_refund(&withdraw_refund_args);
+
+    testbench
+        .process_transaction(&[withdraw_refund_instruction], payer_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn refund_failed_goal_transaction(
+    testbench: &mut Testbench,
+    auction_id: [u8; 32],
+    payer_keypair: &Keypair,
+    bidder_pubkey: &Pubkey,
+    cycle_number: u64,
+) -> AuctionTransactionResult {
+    let (auction_root_state_pubkey, _) =
+        Pubkey::find_program_address(&auction_root_state_seeds(&auction_id), &CONTRACT_ID);
+
+    let refund_failed_goal_args = RefundFailedGoalArgs {
+        payer_pubkey: payer_keypair.pubkey(),
+        bidder_pubkey: *bidder_pubkey,
+        auction_id,
+        cycle_number,
+        bid_mint: get_bid_mint(testbench, &auction_root_state_pubkey).await?,
+    };
+    let refund_failed_goal_instruction = refund_failed_goal(&refund_failed_goal_args);
+
+    testbench
+        .process_transaction(&[refund_failed_goal_instruction], payer_keypair, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn claim_referral_rewards_transaction(
+    testbench: &mut Testbench,
+    referrer_keypair: &Keypair,
+) -> AuctionTransactionResult {
+    let claim_referral_rewards_args = ClaimReferralRewardsArgs {
+        referrer_pubkey: referrer_keypair.pubkey(),
+    };
+    let claim_referral_rewards_instruction = claim_referral_rewards(&claim_referral_rewards_args);
+
+    testbench
+        .process_transaction(
+            &[claim_referral_rewards_instruction],
+            referrer_keypair,
+            None,
+        )
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
 pub async fn initialize_new_auction_custom(
     testbench: &mut Testbench,
     auction_owner: &Keypair,
@@ -545,6 +946,8 @@ pub async fn initialize_new_auction_custom(
         },
         create_token_args,
         auction_start_timestamp: None,
+        beneficiaries: BeneficiaryVec::new(),
+        vesting_schedule: None,
     };
     let instruction = initialize_auction(&initialize_auction_args);
 
@@ -561,12 +964,78 @@ pub async fn initialize_new_auction(
     auction_id: [u8; 32],
     token_type: TokenType,
 ) -> AuctionTransactionResult {
-    let initialize_auction_args = InitializeAuctionArgs::new_test(
+    initialize_new_auction_with_beneficiaries(
+        testbench,
+        auction_owner,
+        auction_config,
+        auction_id,
+        token_type,
+        BeneficiaryVec::new(),
+    )
+    .await
+}
+
+pub async fn initialize_new_auction_with_beneficiaries(
+    testbench: &mut Testbench,
+    auction_owner: &Keypair,
+    auction_config: &AuctionConfig,
+    auction_id: [u8; 32],
+    token_type: TokenType,
+    beneficiaries: BeneficiaryVec,
+) -> AuctionTransactionResult {
+    let mut initialize_auction_args = InitializeAuctionArgs::new_test(
         auction_owner.pubkey(),
         *auction_config,
         auction_id,
         token_type,
     );
+    initialize_auction_args.beneficiaries = beneficiaries;
+    let instruction = initialize_auction(&initialize_auction_args);
+
+    testbench
+        .process_transaction(&[instruction], auction_owner, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn initialize_new_auction_with_goal(
+    testbench: &mut Testbench,
+    auction_owner: &Keypair,
+    auction_config: &AuctionConfig,
+    auction_id: [u8; 32],
+    goal_treasury_amount: Option<u64>,
+) -> AuctionTransactionResult {
+    let mut initialize_auction_args = InitializeAuctionArgs::new_test(
+        auction_owner.pubkey(),
+        *auction_config,
+        auction_id,
+        TokenType::Nft,
+    );
+    initialize_auction_args
+        .auction_description
+        .goal_treasury_amount = goal_treasury_amount;
+    let instruction = initialize_auction(&initialize_auction_args);
+
+    testbench
+        .process_transaction(&[instruction], auction_owner, None)
+        .await
+        .map(|transaction_result| transaction_result.map_err(to_auction_error))
+}
+
+pub async fn initialize_new_auction_with_vesting(
+    testbench: &mut Testbench,
+    auction_owner: &Keypair,
+    auction_config: &AuctionConfig,
+    auction_id: [u8; 32],
+    vesting_schedule: Option<VestingSchedule>,
+) -> AuctionTransactionResult {
+    let mut initialize_auction_args = InitializeAuctionArgs::new_test(
+        auction_owner.pubkey(),
+        *auction_config,
+        auction_id,
+        TokenType::Nft,
+    );
+    initialize_auction_args.vesting_schedule = vesting_schedule;
     let instruction = initialize_auction(&initialize_auction_args);
 
     testbench
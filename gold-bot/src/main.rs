@@ -231,6 +231,9 @@ async fn close_cycle(
         next_cycle_num: pool_record.current_cycle_number,
         token_type,
         existing_token_mint,
+        referrers: pool_record
+            .cycle_state
+            .rewarded_referrers(&pool_record.root_state.auction_config),
     };
     let close_auction_cycle_ix = close_auction_cycle(&close_auction_cycle_args);
 
@@ -101,8 +101,11 @@ impl PoolRecord {
     ///
     ///  - Bid triggered encore period which extended the cycle
     ///
-    /// Both errors can be recognized if the error is AuctionIsInProgress
-    /// (code: 0x1f9 = 505)
+    ///  - Auction was paused by its owner
+    ///
+    /// The first two errors can be recognized if the error is
+    /// AuctionIsInProgress (code: 0x1f9 = 505), the last one if the error is
+    /// AuctionPaused (code: 0x227 = 551)
     pub async fn report_error(
         &mut self,
         client: &mut RpcClient,
@@ -111,7 +114,10 @@ impl PoolRecord {
         self.update_root_state(client).await?;
         self.update_cycle_state(client).await?;
 
-        if error.to_string().ends_with("custom program error: 0x1f9") {
+        let error_message = error.to_string();
+        if error_message.ends_with("custom program error: 0x1f9")
+            || error_message.ends_with("custom program error: 0x227")
+        {
             return Ok(false);
         }
 
@@ -144,8 +150,8 @@ type HashedIdSet = HashSet<AuctionId>;
 pub struct ManagedPool {
     /// Hashmap containing all auctions and their data
     pub hashed_pool: HashedPool,
-    /// Hashset containing ids of inactive (frozen, filtered, finished)
-    /// auctions
+    /// Hashset containing ids of inactive (frozen, paused, filtered,
+    /// finished) auctions
     pub inactive_auctions: HashedIdSet,
     /// Hashset containing ids of erroneous auctions
     pub error_auctions: HashedIdSet,
@@ -162,8 +168,10 @@ impl ManagedPool {
 
     /// Returns a mutable reference to a pool record if it is active
     ///
-    ///  - Returns none if auction is not active (frozen, filtered, finished,
-    ///  erroneous)
+    ///  - Returns none if auction is not active (frozen, paused, filtered,
+    ///  finished, erroneous)
+    ///
+    ///  - Paused auctions are re-evaluated, since they might be resumed
     ///
     ///  - Returns none if auction cycle is not over yet
     pub async fn get_or_insert_auction(
@@ -172,21 +180,38 @@ impl ManagedPool {
         auction_id: AuctionId,
         block_time: UnixTimestamp,
     ) -> Result<Option<&mut PoolRecord>, anyhow::Error> {
-        // if previously identified as inactive or uncallable, return none
-        if self.inactive_auctions.get(&auction_id).is_some()
-            || self.error_auctions.get(&auction_id).is_some()
-        {
+        // if previously identified as uncallable, return none
+        if self.error_auctions.get(&auction_id).is_some() {
             return Ok(None);
         }
 
+        // if previously identified as inactive, return none unless it was a
+        // paused auction that has been resumed since
+        if self.inactive_auctions.get(&auction_id).is_some() {
+            match self.hashed_pool.get_mut(&auction_id) {
+                Some(pool_record) if pool_record.root_state.status.paused_at.is_some() => {
+                    pool_record.update_root_state(connection).await?;
+                    if pool_record.root_state.status.paused_at.is_some() {
+                        return Ok(None);
+                    }
+                    // the cycle end time was shifted upon resuming
+                    pool_record.update_cycle_state(connection).await?;
+                    self.inactive_auctions.remove(&auction_id);
+                }
+                _ => return Ok(None),
+            }
+        }
+
         // fetch or insert pool record
         let pool_record = match self.hashed_pool.entry(auction_id) {
             Vacant(entry) => entry.insert(PoolRecord::new(connection, &auction_id).await?),
             Occupied(entry) => entry.into_mut(),
         };
 
-        // if frozen or inactive or filtered, register it and return none
+        // if frozen or paused or inactive or filtered, register it and return
+        // none
         if pool_record.root_state.status.is_frozen
+            || pool_record.root_state.status.paused_at.is_some()
             || pool_record.root_state.status.is_finished
             || pool_record.root_state.status.is_filtered
         {
@@ -194,8 +219,13 @@ impl ManagedPool {
             return Ok(None);
         }
 
-        // if cycle not over yet, return none
-        if block_time < pool_record.cycle_state.end_time {
+        // if cycle not over yet (including the reveal window of sealed-bid
+        // auctions), return none
+        let cycle_close_time = pool_record
+            .root_state
+            .auction_config
+            .cycle_close_time(pool_record.cycle_state.end_time);
+        if block_time < cycle_close_time {
             return Ok(None);
         }
         Ok(Some(pool_record))